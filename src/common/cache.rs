@@ -4,19 +4,30 @@
 //! This implemetation can be used either directly or
 //! be taken to create a more specific cache structure.
 //
+mod binary;
 mod bound;
 mod column;
+pub mod error;
+pub mod extrapolation;
+pub mod header;
+pub mod interpolation;
 mod table;
 #[cfg(test)]
 #[path = "../tests/common/cache_test.rs"]
 mod tests;
 //
+use binary::Mapped;
 use column::Column;
-use sal_sync::services::entity::{dbg_id::DbgId, error::str_err::StrErr};
+use error::CacheError;
+use extrapolation::{Approximation, Extrapolation};
+use header::Header;
+use interpolation::Interpolation;
+use sal_sync::services::entity::dbg_id::DbgId;
 use std::{
     fs::File,
-    io::{BufRead, BufReader},
+    io::{BufRead, BufReader, BufWriter, Write},
     num::ParseFloatError,
+    ops::Deref,
     path::{Path, PathBuf},
     str::FromStr,
     sync::OnceLock,
@@ -25,6 +36,9 @@ use table::Table;
 //
 type OwnedSet<T> = std::sync::Arc<[T]>;
 ///
+/// Result of reading [Cache] file: the dataset and the header (if present).
+type Loaded<T> = Result<(Table<T>, Option<Header>), CacheError>;
+///
 /// Cached dataset lazyly read from the file on the first access.
 ///
 /// # Examples
@@ -43,7 +57,16 @@ type OwnedSet<T> = std::sync::Arc<[T]>;
 pub struct Cache<T> {
     dbgid: DbgId,
     path: PathBuf,
-    table: OnceLock<Result<Table<T>, StrErr>>,
+    ///
+    /// Default method used by [Cache::get].
+    interpolation: Interpolation,
+    ///
+    /// Default policy used by [Cache::get].
+    extrapolation: Extrapolation,
+    ///
+    /// Header the file must match (see [Cache::with_expected_header]).
+    expected: Option<Header>,
+    table: OnceLock<Loaded<T>>,
 }
 //
 //
@@ -57,9 +80,39 @@ impl<T> Cache<T> {
         Self {
             dbgid: DbgId::with_parent(parent, "Cache"),
             path: path.as_ref().to_owned(),
+            interpolation: Interpolation::default(),
+            extrapolation: Extrapolation::default(),
+            expected: None,
             table: OnceLock::new(),
         }
     }
+    ///
+    /// Returns the instance using `interpolation` by default (see [Cache::get]).
+    pub fn with_interpolation(self, interpolation: Interpolation) -> Self {
+        Self {
+            interpolation,
+            ..self
+        }
+    }
+    ///
+    /// Returns the instance using `extrapolation` by default (see [Cache::get]).
+    pub fn with_extrapolation(self, extrapolation: Extrapolation) -> Self {
+        Self {
+            extrapolation,
+            ..self
+        }
+    }
+    ///
+    /// Returns the instance, which file must have a header matching `expected`.
+    ///
+    /// Only non-empty fields of `expected` are compared (see [Header] for details).
+    /// The file without header doesn't match any expectations.
+    pub fn with_expected_header(self, expected: Header) -> Self {
+        Self {
+            expected: Some(expected),
+            ..self
+        }
+    }
 }
 //
 //
@@ -67,74 +120,140 @@ impl<T: PartialOrd> Cache<T> {
     ///
     /// Initializes Table reading `self.path` file.
     ///
-    /// # Panics
-    /// Panic occurs if the reader produces a non-comparable value (e. g. _NaN_).
-    fn init(&self) -> Result<Table<T>, StrErr>
+    /// The file may start with a header (see [Header]), which is validated
+    /// against the dataset and the expected one (see [Cache::with_expected_header]).
+    /// The columns named in [Header::axes] are the grid axes of the table.
+    fn init(&self) -> Loaded<T>
     where
         T: FromStr<Err = ParseFloatError> + Clone + Default,
+        Mapped<T>: Deref<Target = [T]>,
     {
         let callee = "init";
-        let file = File::open(&self.path).map_err(|err| {
-            format!(
-                "{}.{} | Failed reading file='{}': {}",
-                self.dbgid,
-                callee,
-                self.path.display(),
-                err
-            )
+        let file = File::open(&self.path).map_err(|err| CacheError::IoError {
+            kind: err.kind(),
+            message: format!("Failed reading file='{}': {}", self.path.display(), err),
         })?;
         let reader = BufReader::new(file);
+        let mut header = None;
         let mut vals = None;
         for (try_line, line_id) in reader.lines().zip(1..) {
-            let line = try_line.map_err(|err| {
-                format!(
-                    "{}.{} | Failed reading line={}: {}",
-                    self.dbgid, callee, line_id, err
-                )
+            let line = try_line.map_err(|err| CacheError::IoError {
+                kind: err.kind(),
+                message: format!("Failed reading line={}: {}", line_id, err),
             })?;
+            if Header::is_header_line(&line) {
+                if vals.is_some() {
+                    return Err(CacheError::Header {
+                        line: line_id,
+                        message: "Header line after the dataset".to_owned(),
+                    });
+                }
+                Header::parse_line(&mut header, line_id, &line)?;
+                continue;
+            }
             let ss = line.split_ascii_whitespace();
             let ss_len = ss.clone().count();
             let vals_mut = match vals.as_mut() {
                 None => vals.insert(vec![vec![]; ss_len]),
                 Some(vals) if vals.len() != ss_len => {
-                    return Err(format!(
-                        "{}.{} | Inconsistent dataset at line={}",
-                        self.dbgid, callee, line_id
-                    )
-                    .into())
+                    return Err(CacheError::Inconsistent { line: line_id })
                 }
                 Some(vals) => vals,
             };
-            for (i, s) in ss.enumerate() {
+            for ((i, s), col_id) in ss.enumerate().zip(1..) {
                 let val = s.parse().map_err(|err| {
-                    format!(
-                        "{}.{} | Failed parsing value at line={}: {}",
-                        self.dbgid, callee, line_id, err
-                    )
+                    log::debug!(
+                        "{}.{} | Failed parsing value='{}': {}",
+                        self.dbgid,
+                        callee,
+                        s,
+                        err
+                    );
+                    CacheError::Parse {
+                        line: line_id,
+                        col: col_id,
+                    }
                 })?;
                 vals_mut[i].push(val);
             }
         }
-        let cols = vals
-            .map(|vals| {
-                let iter_over_cols = vals.into_iter().enumerate().map(|(id, vals)| {
-                    let dbgid = DbgId::with_parent(&self.dbgid, &format!("Column_{}", id));
-                    Column::new(dbgid, vals)
-                });
-                OwnedSet::from_iter(iter_over_cols)
-            })
-            .unwrap_or_default();
-        Ok(Table::new(&self.dbgid, cols))
+        let vals = vals.unwrap_or_default();
+        self.check_header(header.as_ref(), vals.len())?;
+        let iter_over_cols = vals.into_iter().enumerate().map(|(id, vals)| {
+            let dbgid = DbgId::with_parent(&self.dbgid, &format!("Column_{}", id));
+            let col = Column::try_new(dbgid, vals)
+                .map_err(|row| CacheError::NonComparable { column: id, row })?;
+            match header.as_ref().is_some_and(|header| header.is_axis(id)) {
+                true => Ok(col.into_grid_axis()),
+                false => Ok(col),
+            }
+        });
+        let cols = iter_over_cols.collect::<Result<OwnedSet<_>, _>>()?;
+        Ok((Table::new(&self.dbgid, cols), header))
+    }
+    ///
+    /// Returns the error if `header` doesn't match the dataset of `columns` width
+    /// or the expected header (see [Cache::with_expected_header]).
+    fn check_header(&self, header: Option<&Header>, columns: usize) -> Result<(), CacheError> {
+        if let (Some(header), true) = (header, columns > 0) {
+            header.validate(columns)?;
+        }
+        match (header, self.expected.as_ref()) {
+            (Some(header), Some(expected)) => header.check(expected),
+            (None, Some(_)) => Err(CacheError::Header {
+                line: 1,
+                message: "Header expected".to_owned(),
+            }),
+            _ => Ok(()),
+        }
     }
 }
 //
 //
 impl Cache<f64> {
+    ///
+    /// Creates a new instance and reads the file at once.
+    ///
+    /// In contrast to [Cache::new], the file is validated here,
+    /// so the following calls of [Cache::get] don't panic on the file errors.
+    ///
+    /// # Errors
+    /// The error returns if reading or parsing of the file fails (see [CacheError]).
+    ///
+    /// # Examples
+    /// ```
+    /// use sal_sync::services::entity::dbg_id::DbgId;
+    /// //
+    /// let dbgid = DbgId("cache creator".to_owned());
+    /// match Cache::try_new(&dbgid, "/path/to/cache/file") {
+    ///     Ok(cache) => { /* the dataset is ready */ }
+    ///     Err(CacheError::Parse { line, col }) => {
+    ///         log::warn!("Bad value at line={}, col={}", line, col);
+    ///     }
+    ///     Err(err) => log::warn!("Bad cache: {}", err),
+    /// }
+    /// ```
+    pub fn try_new(parent: &DbgId, path: impl AsRef<Path>) -> Result<Self, CacheError> {
+        let cache = Self::new(parent, path);
+        cache.try_table()?;
+        Ok(cache)
+    }
     ///
     /// Returns approximated values based on given set.
     ///
+    /// Values are interpolated by the method the instance is created with
+    /// (see [Cache::with_interpolation]), [Interpolation::Linear] by default.
+    /// Use [Cache::get_interpolated] to choose the method per query.
+    ///
+    /// Values out of the dataset range are handled by the policy the instance is created with
+    /// (see [Cache::with_extrapolation]). `None` returns for such values
+    /// if the policy is [Extrapolation::Error] (default one).
+    /// Use [Cache::get_approximation] to get the error or to know whether values have been clamped.
+    ///
     /// This is a safe method in terms of bounds: If `approx_vals` has more elements than [Cache] supports,
-    /// this method returns `None`. In contrast, the empty vector returns if no value found.
+    /// this method returns `None` as well. In contrast, the empty vector returns if no value found.
+    ///
+    /// Use [Cache::try_get] to get the error instead of panic.
     ///
     /// # Panics
     /// This method panics if at least one of the statements is true:
@@ -147,8 +266,8 @@ impl Cache<f64> {
     /// fn explaination(cache: Cache<f64>) {
     ///     // get all rows of the file behind `cache`
     ///     let _ = cache.get(&[]);
-    ///     // get approximated (or equal) rows, which values are calculated
-    ///     // as the average of each columns between top and low bounds
+    ///     // get approximated (or equal) rows, which values are linearly interpolated
+    ///     // between top and low bounds based on the distance of given value to each bound
     ///     // (the bounds are selected only for the first column):
     ///     // *cache file*
     ///     // |  ...     |
@@ -170,18 +289,295 @@ impl Cache<f64> {
     ///     // -----------------------
     ///     // * - any value of type f64
     ///     let _ = cache.get(&[None, Some(0.1), None, Some(0.2)]);
+    ///     // if the columns are grid axes declared in the header (see [Header::axes]),
+    ///     // e. g. heel, trim, and draught of the floating position cache, the rows are interpolated
+    ///     // multilinearly between the nearest grid nodes:
+    ///     let _ = cache.get(&[Some(0.1), Some(0.2), Some(1.5)]);
     /// }
     /// ```
     pub fn get(&self, approx_vals: &[Option<f64>]) -> Option<Vec<Vec<f64>>> {
+        self.get_interpolated(approx_vals, self.interpolation)
+    }
+    ///
+    /// Returns approximated values based on given set using given `interpolation` method.
+    ///
+    /// See [Cache::get] for details.
+    ///
+    /// # Panics
+    /// See [Cache::get] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(cache: Cache<f64>) {
+    ///     // take values of the nearest row
+    ///     let _ = cache.get_interpolated(&[Some(0.5)], Interpolation::Nearest);
+    ///     // approximate a smooth dataset by monotone cubic spline
+    ///     let _ = cache.get_interpolated(&[Some(0.5)], Interpolation::Spline);
+    /// }
+    /// ```
+    pub fn get_interpolated(
+        &self,
+        approx_vals: &[Option<f64>],
+        interpolation: Interpolation,
+    ) -> Option<Vec<Vec<f64>>> {
+        match self.get_approximation(approx_vals, interpolation, self.extrapolation) {
+            Ok(approximation) => Some(approximation.rows),
+            Err(CacheError::TooManyValues { .. } | CacheError::OutOfRange { .. }) => None,
+            Err(err) => panic!("{}.{} | {}", self.dbgid, "get_interpolated", err),
+        }
+    }
+    ///
+    /// Returns approximated values based on given set.
+    ///
+    /// It's a non-panicking version of [Cache::get].
+    ///
+    /// # Errors
+    /// See [Cache::get_approximation] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(cache: Cache<f64>, draught: f64) -> Result<(), CacheError> {
+    ///     // a broken file, NaN from a sensor, and so on are errors, not panics
+    ///     let _ = cache.try_get(&[Some(0.0), Some(0.0), Some(draught)])?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_get(&self, approx_vals: &[Option<f64>]) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.get_approximation(approx_vals, self.interpolation, self.extrapolation)
+            .map(|approximation| approximation.rows)
+    }
+    ///
+    /// Returns approximated values based on given set
+    /// using given `interpolation` method and `extrapolation` policy.
+    ///
+    /// In contrast to [Cache::get], the result tells the values have been clamped (see [Approximation]).
+    /// This method never panics.
+    ///
+    /// # Errors
+    /// This method returns the error if at least one of the statements is true:
+    /// - reading or parsing file at `self.path` failed (the same error returns for each call),
+    /// - `approx_vals` has more elements than [Cache] supports,
+    /// - `approx_vals` contains a non-comparable value (e. g. _NaN_),
+    /// - `approx_vals` contains a value out of the dataset range and `extrapolation` is [Extrapolation::Error].
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(cache: Cache<f64>) {
+    ///     // consider the draught is the 3rd column, which range is [0.0, 10.0]
+    ///     let query = [Some(0.0), Some(0.0), Some(12.0)];
+    ///     // fails as 12.0 is out of range
+    ///     assert!(matches!(
+    ///         cache.get_approximation(&query, Interpolation::Linear, Extrapolation::Error),
+    ///         Err(CacheError::OutOfRange { column: 2, .. }),
+    ///     ));
+    ///     // rows for draught = 10.0 marked as clamped ones
+    ///     let approximation = cache
+    ///         .get_approximation(&query, Interpolation::Linear, Extrapolation::Clamp)
+    ///         .unwrap();
+    ///     assert_eq!(approximation.clamped, vec![2]);
+    /// }
+    /// ```
+    pub fn get_approximation(
+        &self,
+        approx_vals: &[Option<f64>],
+        interpolation: Interpolation,
+        extrapolation: Extrapolation,
+    ) -> Result<Approximation, CacheError> {
+        self.try_table()?
+            .get(approx_vals, interpolation, extrapolation)
+    }
+    ///
+    /// Returns rows, which reach `fixed_vals`, solving for the column `unknown`.
+    ///
+    /// It's the inverse of [Cache::get]: given values of grid axis columns fix the curves,
+    /// which are parametrized by `unknown` column, and the rest given values are the targets
+    /// to be reached along the curves. Rows are approximated by the method and the policy
+    /// the instance is created with (see [Cache::with_interpolation] and [Cache::with_extrapolation]).
+    ///
+    /// Several rows return if the target is reached more than once
+    /// (non-monotonic dataset) or there are grid axis columns not given.
+    /// The solution is the value of `unknown` column of each row.
+    ///
+    /// # Errors
+    /// Besides the errors of [Cache::get_approximation], it returns
+    /// [CacheError::InvalidUnknown] if `unknown` is either given in `fixed_vals` or out of the columns.
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(cache: Cache<f64>) -> Result<(), CacheError> {
+    ///     // consider the floating position cache with columns:
+    ///     // heel, trim, draught, volume
+    ///     // what is the draught for volume 1500.0 at heel 5.0 and trim 0.5?
+    ///     let rows = cache.solve(&[Some(5.0), Some(0.5), None, Some(1500.0)], 2)?;
+    ///     let draughts = Vec::from_iter(rows.iter().map(|row| row[2]));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn solve(
+        &self,
+        fixed_vals: &[Option<f64>],
+        unknown: usize,
+    ) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.try_table()?
+            .solve(fixed_vals, unknown, self.interpolation, self.extrapolation)
+    }
+    ///
+    /// Returns the header of the file, [None] if the file has no header.
+    ///
+    /// # Errors
+    /// The error returns if reading or parsing of the file fails (see [CacheError]).
+    pub fn header(&self) -> Result<Option<&Header>, CacheError> {
+        self.try_init().map(|(_, header)| header.as_ref())
+    }
+    ///
+    /// Returns ID of the column named `name` in the header (see [Header::columns]).
+    ///
+    /// # Errors
+    /// Besides the errors of [Cache::header], [CacheError::UnknownColumn] returns
+    /// if there is no such column or the file has no header.
+    pub fn column_id(&self, name: &str) -> Result<usize, CacheError> {
+        self.header()?
+            .and_then(|header| header.column_id(name))
+            .ok_or_else(|| CacheError::UnknownColumn {
+                name: name.to_owned(),
+            })
+    }
+    ///
+    /// Returns approximated values based on given set of (column name, value).
+    ///
+    /// It's [Cache::try_get] with columns referred by names (see [Cache::column_id]).
+    ///
+    /// # Errors
+    /// See [Cache::try_get] and [Cache::column_id] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(cache: Cache<f64>) -> Result<(), CacheError> {
+    ///     // the order of the columns doesn't matter
+    ///     let _ = cache.try_get_named(&[("draught", 5.0), ("heel", 0.0), ("trim", 0.5)])?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_get_named(&self, named_vals: &[(&str, f64)]) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.try_get(&self.to_positional(named_vals)?)
+    }
+    ///
+    /// Returns rows, which reach given set of (column name, value), solving for the column `unknown`.
+    ///
+    /// It's [Cache::solve] with columns referred by names (see [Cache::column_id]).
+    ///
+    /// # Errors
+    /// See [Cache::solve] and [Cache::column_id] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(cache: Cache<f64>) -> Result<(), CacheError> {
+    ///     let draught = cache.column_id("draught")?;
+    ///     let rows = cache.solve_named(&[("heel", 5.0), ("trim", 0.5), ("volume", 1500.0)], "draught")?;
+    ///     let draughts = Vec::from_iter(rows.iter().map(|row| row[draught]));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn solve_named(
+        &self,
+        named_vals: &[(&str, f64)],
+        unknown: &str,
+    ) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.solve(&self.to_positional(named_vals)?, self.column_id(unknown)?)
+    }
+    ///
+    /// Converts given set of (column name, value) into the positional one.
+    fn to_positional(&self, named_vals: &[(&str, f64)]) -> Result<Vec<Option<f64>>, CacheError> {
+        let mut approx_vals = vec![];
+        for &(name, val) in named_vals {
+            let id = self.column_id(name)?;
+            if approx_vals.len() <= id {
+                approx_vals.resize(id + 1, None);
+            }
+            approx_vals[id] = Some(val);
+        }
+        Ok(approx_vals)
+    }
+    ///
+    /// Returns the table read from `self.path` on the first call.
+    ///
+    /// # Errors
+    /// The error of reading the file returns (see [Cache::init]).
+    fn try_table(&self) -> Result<&Table<f64>, CacheError> {
+        self.try_init().map(|(table, _)| table)
+    }
+    ///
+    /// Returns the table and the header read from `self.path` on the first call.
+    ///
+    /// # Errors
+    /// The error of reading the file returns (see [Cache::init]).
+    fn try_init(&self) -> Result<&(Table<f64>, Option<Header>), CacheError> {
         self.table
-            .get_or_init(|| self.init())
-            .as_ref()
-            .unwrap_or_else(|err| {
-                panic!(
-                    "{}.{} | Failed initializing Table: {}",
-                    self.dbgid, "get", err
-                )
+            .get_or_init(|| match binary::is_binary(&self.path) {
+                true => self.init_binary(),
+                false => self.init(),
             })
-            .get(approx_vals)
+            .as_ref()
+            .map_err(|err| err.clone())
+    }
+    ///
+    /// Initializes Table memory-mapping `self.path` file in binary format.
+    ///
+    /// The header is validated the same way [Cache::init] does.
+    fn init_binary(&self) -> Loaded<f64> {
+        let (cols, header) = binary::read(&self.dbgid, &self.path)?;
+        self.check_header(header.as_ref(), cols.len())?;
+        Ok((Table::new(&self.dbgid, cols), header))
+    }
+    ///
+    /// Writes the dataset and the header to `path` in binary format.
+    ///
+    /// The binary file is memory-mapped on reading, so it's loaded much faster than the text one.
+    /// [Cache] recognizes the format of the file by itself.
+    ///
+    /// # Errors
+    /// The error returns if reading of `self.path` or writing to `path` fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use sal_sync::services::entity::dbg_id::DbgId;
+    /// //
+    /// let dbgid = DbgId("cache converter".to_owned());
+    /// // convert the text file into binary one
+    /// Cache::new(&dbgid, "/path/to/text/cache").write_binary("/path/to/binary/cache")?;
+    /// // and back
+    /// Cache::new(&dbgid, "/path/to/binary/cache").write_text("/path/to/text/cache")?;
+    /// ```
+    pub fn write_binary(&self, path: impl AsRef<Path>) -> Result<(), CacheError> {
+        let (table, header) = self.try_init()?;
+        binary::write(path.as_ref(), table.columns(), header.as_ref())
+    }
+    ///
+    /// Writes the dataset and the header to `path` in text format.
+    ///
+    /// See [Cache::write_binary] for details.
+    ///
+    /// # Errors
+    /// The error returns if reading of `self.path` or writing to `path` fails.
+    pub fn write_text(&self, path: impl AsRef<Path>) -> Result<(), CacheError> {
+        let path = path.as_ref();
+        let (table, header) = self.try_init()?;
+        let cols = table.columns();
+        let rows = cols.first().map_or(0, |col| col.len());
+        let write = || -> std::io::Result<()> {
+            let mut out = BufWriter::new(File::create(path)?);
+            if let Some(header) = header {
+                write!(out, "{}", header)?;
+            }
+            for row_id in 0..rows {
+                let row = Vec::from_iter(cols.iter().map(|col| col[row_id].to_string()));
+                writeln!(out, "{}", row.join(" "))?;
+            }
+            out.flush()
+        };
+        write().map_err(|err| CacheError::IoError {
+            kind: err.kind(),
+            message: format!("Failed writing file='{}': {}", path.display(), err),
+        })
     }
 }
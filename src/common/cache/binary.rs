@@ -0,0 +1,302 @@
+//!
+//! Binary column-oriented format of [super::Cache] file.
+//!
+//! The file is memory-mapped on reading, so neither the dataset nor the inflection and node IDs
+//! are parsed or copied, and the columns are not analyzed again (see [Column::new]).
+//! The file written by [write] is flagged as checked, since it's made of the analyzed columns,
+//! so its values and IDs are used as is.
+//! Otherwise the values are checked to be comparable as the text format requires,
+//! and the IDs are checked to be within the dataset.
+//! All numbers are little-endian, sections are aligned to 8 bytes:
+//! ```text
+//! magic          8 bytes  "MDMTCBIN"
+//! version        u64
+//! flags          u64      bit 0 is set if the values and IDs are checked
+//! header_len     u64      length of the text header (see [Header]), 0 if there is no header
+//! header         header_len bytes, padded by zeros to 8 bytes
+//! columns        u64
+//! rows           u64
+//! for each column:
+//!   inflections  u64      number of inflection points
+//!   nodes        u64      number of grid nodes
+//!   data         f64 × rows
+//!   inflections  u64 × inflections
+//!   nodes        u64 × nodes
+//! ```
+//
+use super::{
+    column::{Column, Values},
+    error::CacheError,
+    header::Header,
+};
+use memmap2::Mmap;
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+    marker::PhantomData,
+    mem,
+    ops::Deref,
+    path::Path,
+    slice,
+    sync::Arc,
+};
+//
+//
+const MAGIC: &[u8; 8] = b"MDMTCBIN";
+const VERSION: u64 = 2;
+const CHECKED: u64 = 1;
+const WORD: usize = mem::size_of::<u64>();
+///
+/// Values of [Column] mapped from the binary file.
+///
+/// The values are accessible for _f64_ data and _usize_ IDs only,
+/// since any bit pattern is valid for these types.
+#[derive(Clone)]
+pub(super) struct Mapped<T> {
+    mmap: Arc<Mmap>,
+    offset: usize,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+//
+//
+impl<T> Mapped<T> {
+    ///
+    /// Returns `len` values placed at `offset` of `mmap`.
+    ///
+    /// # Errors
+    /// The error returns if the values are out of `mmap` or not aligned.
+    fn new(mmap: Arc<Mmap>, offset: usize, len: usize) -> Result<Self, CacheError> {
+        let end = len
+            .checked_mul(mem::size_of::<T>())
+            .and_then(|size| size.checked_add(offset));
+        if end.is_none_or(|end| end > mmap.len()) {
+            return Err(corrupted(offset, "Values out of the file"));
+        }
+        if !(mmap.as_ptr() as usize + offset).is_multiple_of(mem::align_of::<T>()) {
+            return Err(corrupted(offset, "Values are not aligned"));
+        }
+        Ok(Self {
+            mmap,
+            offset,
+            len,
+            _marker: PhantomData,
+        })
+    }
+    ///
+    /// Returns the mapped values.
+    ///
+    /// # Safety
+    /// Any bit pattern must be a valid value of `T`.
+    unsafe fn as_slice(&self) -> &[T] {
+        // SAFETY: bounds and alignment of the values are checked by `Mapped::new`.
+        unsafe { slice::from_raw_parts(self.mmap.as_ptr().add(self.offset) as *const T, self.len) }
+    }
+}
+//
+//
+impl Deref for Mapped<f64> {
+    type Target = [f64];
+    //
+    //
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: any bit pattern is a valid f64.
+        unsafe { self.as_slice() }
+    }
+}
+//
+//
+impl Deref for Mapped<usize> {
+    type Target = [usize];
+    //
+    //
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: any bit pattern is a valid usize,
+        // which is u64 as the file stores (see `read`).
+        unsafe { self.as_slice() }
+    }
+}
+//
+//
+impl<T> fmt::Debug for Mapped<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mapped")
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+///
+/// Returns _true_ if the file at `path` is in binary format.
+pub(super) fn is_binary(path: &Path) -> bool {
+    let mut magic = [0; MAGIC.len()];
+    File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .is_ok_and(|_| &magic == MAGIC)
+}
+///
+/// Returns columns and header memory-mapped from the file at `path`.
+///
+/// Returns [CacheError::NonComparable] if the column contains NaN, as the text format does,
+/// unless the file is flagged as checked (see the module docs).
+///
+/// Note that the file must not be modified while the columns are in use.
+pub(super) fn read(
+    dbgid: &DbgId,
+    path: &Path,
+) -> Result<(Vec<Column<f64>>, Option<Header>), CacheError> {
+    if cfg!(target_endian = "big") || mem::size_of::<usize>() != WORD {
+        return Err(corrupted(
+            0,
+            "Only 64-bit little-endian platforms are supported",
+        ));
+    }
+    let file = File::open(path).map_err(|err| io_error(path, err))?;
+    // SAFETY: the file is considered to be not modified while mapped
+    // (cache files are replaced, not modified in place).
+    let mmap = Arc::new(unsafe { Mmap::map(&file) }.map_err(|err| io_error(path, err))?);
+    let mut reader = Reader {
+        bytes: &mmap,
+        offset: 0,
+    };
+    if reader.bytes(MAGIC.len())? != MAGIC {
+        return Err(corrupted(0, "Invalid magic"));
+    }
+    if reader.u64()? != VERSION {
+        return Err(corrupted(MAGIC.len(), "Unsupported version"));
+    }
+    let is_checked = reader.u64()? & CHECKED != 0;
+    let header_len = reader.usize()?;
+    let header_text = std::str::from_utf8(reader.bytes(header_len)?)
+        .map_err(|_| corrupted(reader.offset, "Header is not UTF-8"))?;
+    reader.align();
+    let header = Header::parse(header_text)?;
+    let columns_len = reader.usize()?;
+    let rows = reader.usize()?;
+    let mut columns = Vec::with_capacity(columns_len.min(mmap.len()));
+    for id in 0..columns_len {
+        let inflections_len = reader.usize()?;
+        let nodes_len = reader.usize()?;
+        let data: Mapped<f64> = reader.mapped(&mmap, rows)?;
+        let inflections: Mapped<usize> = reader.mapped(&mmap, inflections_len)?;
+        let nodes: Mapped<usize> = reader.mapped(&mmap, nodes_len)?;
+        if !is_checked {
+            if let Some(row) = data.iter().position(|val| val.is_nan()) {
+                return Err(CacheError::NonComparable { column: id, row });
+            }
+            for ids in [&inflections, &nodes] {
+                if let Some(pos) = ids.iter().position(|&row| row >= rows) {
+                    return Err(corrupted(
+                        ids.offset + pos * WORD,
+                        "Row ID out of the dataset",
+                    ));
+                }
+            }
+        }
+        columns.push(Column::from_parts(
+            DbgId::with_parent(dbgid, &format!("Column_{}", id)),
+            Values::Mapped(data),
+            Values::Mapped(inflections),
+            Values::Mapped(nodes),
+        ));
+    }
+    Ok((columns, header))
+}
+///
+/// Writes `columns` and `header` to the file at `path` in binary format.
+pub(super) fn write(
+    path: &Path,
+    columns: &[Column<f64>],
+    header: Option<&Header>,
+) -> Result<(), CacheError> {
+    let file = File::create(path).map_err(|err| io_error(path, err))?;
+    let mut out = BufWriter::new(file);
+    let header = header.map(|header| header.to_string()).unwrap_or_default();
+    let padding = (WORD - header.len() % WORD) % WORD;
+    let rows = columns.first().map_or(0, |col| col.len());
+    let mut write = || -> io::Result<()> {
+        out.write_all(MAGIC)?;
+        out.write_all(&VERSION.to_le_bytes())?;
+        out.write_all(&CHECKED.to_le_bytes())?;
+        out.write_all(&(header.len() as u64).to_le_bytes())?;
+        out.write_all(header.as_bytes())?;
+        out.write_all(&vec![0; padding])?;
+        out.write_all(&(columns.len() as u64).to_le_bytes())?;
+        out.write_all(&(rows as u64).to_le_bytes())?;
+        for col in columns {
+            out.write_all(&(col.inflections().len() as u64).to_le_bytes())?;
+            out.write_all(&(col.nodes().len() as u64).to_le_bytes())?;
+            for val in col.iter() {
+                out.write_all(&val.to_le_bytes())?;
+            }
+            for &id in col.inflections().iter().chain(col.nodes()) {
+                out.write_all(&(id as u64).to_le_bytes())?;
+            }
+        }
+        out.flush()
+    };
+    write().map_err(|err| io_error(path, err))
+}
+///
+/// Sequential reader of the mapped bytes.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+//
+//
+impl<'a> Reader<'a> {
+    ///
+    /// Returns next `len` bytes.
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], CacheError> {
+        let bytes = self
+            .offset
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.offset..end))
+            .ok_or_else(|| corrupted(self.offset, "Unexpected end of file"))?;
+        self.offset += len;
+        Ok(bytes)
+    }
+    ///
+    /// Returns next u64.
+    fn u64(&mut self) -> Result<u64, CacheError> {
+        let bytes = self.bytes(WORD)?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap_or_default()))
+    }
+    ///
+    /// Returns next u64 as usize.
+    fn usize(&mut self) -> Result<usize, CacheError> {
+        let offset = self.offset;
+        usize::try_from(self.u64()?).map_err(|_| corrupted(offset, "Value overflows usize"))
+    }
+    ///
+    /// Returns next `len` words mapped from `mmap`, which the bytes are taken from.
+    fn mapped<T>(&mut self, mmap: &Arc<Mmap>, len: usize) -> Result<Mapped<T>, CacheError> {
+        let mapped = Mapped::new(mmap.clone(), self.offset, len)?;
+        self.bytes(len * WORD)?;
+        Ok(mapped)
+    }
+    ///
+    /// Skips padding up to the next 8 bytes boundary.
+    fn align(&mut self) {
+        self.offset = self.offset.div_ceil(WORD) * WORD;
+    }
+}
+///
+/// Returns [CacheError::Corrupted] at `offset`.
+fn corrupted(offset: usize, message: &str) -> CacheError {
+    CacheError::Corrupted {
+        offset,
+        message: message.to_owned(),
+    }
+}
+///
+/// Returns [CacheError::IoError] of the file at `path`.
+fn io_error(path: &Path, err: io::Error) -> CacheError {
+    CacheError::IoError {
+        kind: err.kind(),
+        message: format!("File='{}': {}", path.display(), err),
+    }
+}
@@ -2,25 +2,39 @@
 #[path = "../../tests/common/cache/column_test.rs"]
 mod tests;
 //
+use super::binary::Mapped;
 use super::bound::Bound;
 use super::OwnedSet;
 use sal_sync::services::entity::dbg_id::DbgId;
 use std::{cmp::Ordering, ops::Deref};
 ///
+/// Values of [Column], either owned or mapped from the binary file (see [Mapped]).
+#[derive(Clone, Debug)]
+pub(super) enum Values<T> {
+    Owned(OwnedSet<T>),
+    Mapped(Mapped<T>),
+}
+///
 /// Analyzed dataset, column of a [super::Table] instance.
 ///
 /// A dataset is _analyzed_ if all its inflection points are defined.
+/// In addition, if the dataset is a grid axis, its IDs sorted by value are defined too
+/// (see [Column::into_grid_axis]).
 #[derive(Clone, Debug)]
 pub(super) struct Column<T> {
-    inflections: OwnedSet<usize>,
-    data: OwnedSet<T>,
+    inflections: Values<usize>,
+    nodes: Values<usize>,
+    data: Values<T>,
     dbgid: DbgId,
 }
 //
 //
-impl<T: PartialOrd> Column<T> {
+impl<T: PartialOrd> Column<T>
+where
+    Mapped<T>: Deref<Target = [T]>,
+{
     ///
-    /// Returns an analyzed instance (see [Column] for details).
+    /// Returns an analyzed instance (see [Column] for details), which is not a grid axis.
     ///
     /// # Panics
     /// Panic occurs if `values` contains a non-comparable value (e. g. _NaN_).
@@ -29,12 +43,65 @@ impl<T: PartialOrd> Column<T> {
         S: Into<OwnedSet<T>> + Deref<Target = [T]>,
     {
         Self {
-            inflections: Self::get_inflections(&dbgid, &values),
-            data: values.into(),
+            inflections: Values::Owned(Self::get_inflections(&dbgid, &values)),
+            nodes: Values::Owned(OwnedSet::from([])),
+            data: Values::Owned(values.into()),
             dbgid,
         }
     }
     ///
+    /// Returns the instance being a grid axis, e. g. heel steps of heel×trim×draught grid.
+    ///
+    /// Each distinct value of the axis is a grid _node_ (see [Column::get_nodes]).
+    pub(super) fn into_grid_axis(self) -> Self {
+        Self {
+            nodes: Values::Owned(Self::get_nodes(&self.data)),
+            ..self
+        }
+    }
+    ///
+    /// Returns an instance made of already analyzed `data`.
+    ///
+    /// Caller must garantee that `inflections` and `nodes` are the ones
+    /// [Column::new] gets for `data` (e. g. taken by [Column::inflections] and [Column::nodes]).
+    pub(super) fn from_parts(
+        dbgid: DbgId,
+        data: Values<T>,
+        inflections: Values<usize>,
+        nodes: Values<usize>,
+    ) -> Self {
+        Self {
+            inflections,
+            nodes,
+            data,
+            dbgid,
+        }
+    }
+    ///
+    /// Returns inflection point IDs (see [Column::get_inflections]).
+    pub(super) fn inflections(&self) -> &[usize] {
+        &self.inflections
+    }
+    ///
+    /// Returns grid node IDs (see [Column::get_nodes]).
+    pub(super) fn nodes(&self) -> &[usize] {
+        &self.nodes
+    }
+    ///
+    /// Returns an analyzed instance (see [Column] for details).
+    ///
+    /// In contrast to [Column::new], it doesn't panic,
+    /// but returns ID of the first non-comparable value (e. g. _NaN_) of `values` as the error.
+    pub(super) fn try_new<S>(dbgid: DbgId, values: S) -> Result<Self, usize>
+    where
+        S: Into<OwnedSet<T>> + Deref<Target = [T]>,
+    {
+        match values.iter().position(|val| val.partial_cmp(val).is_none()) {
+            Some(row) => Err(row),
+            None => Ok(Self::new(dbgid, values)),
+        }
+    }
+    ///
     /// Returns inflection point IDs based on given values.
     ///
     /// # Panics
@@ -103,6 +170,19 @@ impl<T: PartialOrd> Column<T> {
         ids.into()
     }
     ///
+    /// Returns all IDs sorted by value, so the IDs of each distinct value (_node_) follow each other.
+    ///
+    /// Note that non-comparable values are caught by [Column::get_inflections].
+    fn get_nodes(values: &[T]) -> OwnedSet<usize> {
+        let mut ids = Vec::from_iter(0..values.len());
+        ids.sort_by(|&l_id, &r_id| {
+            values[l_id]
+                .partial_cmp(&values[r_id])
+                .unwrap_or(Ordering::Equal)
+        });
+        ids.into()
+    }
+    ///
     /// Returns bounds of given value within internal dataset.
     ///
     /// # Panics
@@ -140,6 +220,135 @@ impl<T: PartialOrd> Column<T> {
         bounds
     }
     ///
+    /// Returns _true_ if the column is a grid axis (see [Column::into_grid_axis]).
+    pub(super) fn is_grid_axis(&self) -> bool {
+        !self.nodes.is_empty()
+    }
+    ///
+    /// Returns bounds of given value within grid nodes (see [Column::get_nodes]).
+    ///
+    /// The result is [None] if the column is not a grid axis. Otherwise it's either
+    /// - [Bound::Single] with ID of the node equal to `val`,
+    /// - [Bound::Range] with IDs of two nearest nodes `val` is placed in between,
+    /// - [Bound::None] if `val` is out of the nodes range.
+    ///
+    /// # Panics
+    /// Panic occurs if `val` is a non-comparable value (e. g. _NaN_).
+    pub(super) fn get_node_bounds(&self, val: &T) -> Option<Bound>
+    where
+        T: std::fmt::Display,
+    {
+        let callee = "get_node_bounds";
+        if self.nodes.is_empty() {
+            return None;
+        }
+        if val.partial_cmp(val).is_none() {
+            panic!(
+                "{}.{} | `val`={} is a non-comparable value",
+                self.dbgid, callee, val
+            );
+        }
+        let insert_id = self.nodes.partition_point(|&id| self.data[id] < *val);
+        Some(match self.nodes.get(insert_id) {
+            Some(&id) if self.data[id] == *val => Bound::Single(id),
+            Some(&id) if insert_id > 0 => Bound::Range(self.nodes[insert_id - 1], id),
+            _ => Bound::None,
+        })
+    }
+    ///
+    /// Returns sorted IDs of all values equal to `node`.
+    ///
+    /// The result is empty if the column is not a grid axis (see [Column::get_nodes]).
+    pub(super) fn get_node_ids(&self, node: &T) -> &[usize] {
+        let start = self.nodes.partition_point(|&id| self.data[id] < *node);
+        let end = self.nodes.partition_point(|&id| self.data[id] <= *node);
+        &self.nodes[start..end]
+    }
+    ///
+    /// Returns IDs of two outermost nodes on the side of the range `val` is placed out of.
+    ///
+    /// The result is [None] if the column is not a grid axis,
+    /// it has less than two nodes, or `val` is within the nodes range.
+    pub(super) fn get_edge_node_bounds(&self, val: &T) -> Option<Bound> {
+        let first = *self.nodes.first()?;
+        let last = *self.nodes.last()?;
+        if *val < self.data[first] {
+            let (_, next) = self.get_node_neighbors(first);
+            next.map(|next| Bound::Range(first, next))
+        } else if *val > self.data[last] {
+            let (prev, _) = self.get_node_neighbors(last);
+            prev.map(|prev| Bound::Range(prev, last))
+        } else {
+            None
+        }
+    }
+    ///
+    /// Returns the minimum and the maximum values of the column.
+    ///
+    /// The result is [None] if the column is empty.
+    pub(super) fn get_range(&self) -> Option<(&T, &T)> {
+        let mut extremums = self.inflections.iter().map(|&id| &self.data[id]);
+        let first = extremums.next()?;
+        Some(extremums.fold((first, first), |(min, max), val| {
+            (
+                if val < min { val } else { min },
+                if val > max { val } else { max },
+            )
+        }))
+    }
+    ///
+    /// Returns bounds of the segments ends, which are the closest to `val` placed out of the column range.
+    ///
+    /// Each [Bound::Range] consists of the end row of the segment and its nearest row with different value,
+    /// so it can be used for linear extrapolation. The result is empty if `val` is within the column range.
+    pub(super) fn get_edge_bounds(&self, val: &T) -> Vec<Bound> {
+        let Some((min, max)) = self.get_range() else {
+            return vec![];
+        };
+        let edge = if val > max {
+            max
+        } else if val < min {
+            min
+        } else {
+            return vec![];
+        };
+        let mut bounds = vec![];
+        for win in self.inflections.windows(2) {
+            let (start, end) = (win[0], win[1]);
+            if self.data[start] == *edge {
+                if let Some(id) = (start + 1..=end).find(|&id| self.data[id] != *edge) {
+                    bounds.push(Bound::Range(start, id));
+                }
+            }
+            if self.data[end] == *edge {
+                if let Some(id) = (start..end).rev().find(|&id| self.data[id] != *edge) {
+                    bounds.push(Bound::Range(id, end));
+                }
+            }
+        }
+        bounds.dedup();
+        bounds
+    }
+    ///
+    /// Returns IDs of the nodes previous to and next to the node of given row ID (see [Column::get_nodes]).
+    pub(super) fn get_node_neighbors(&self, row_id: usize) -> (Option<usize>, Option<usize>) {
+        let node = &self.data[row_id];
+        let start = self.nodes.partition_point(|&id| self.data[id] < *node);
+        let end = self.nodes.partition_point(|&id| self.data[id] <= *node);
+        (
+            start.checked_sub(1).map(|id| self.nodes[id]),
+            self.nodes.get(end).copied(),
+        )
+    }
+    ///
+    /// Returns the monotonic segment, pair of neighbor inflection points, containing rows from `start` to `end`.
+    pub(super) fn get_monotonic_segment(&self, start: usize, end: usize) -> Option<(usize, usize)> {
+        self.inflections
+            .windows(2)
+            .find(|win| win[0] <= start && end <= win[1])
+            .map(|win| (win[0], win[1]))
+    }
+    ///
     /// Returns bounds of given element (`val`) placing in between elemnts of `vals`,
     /// where `offset` represents the actual start index of `vals`. For internal use.
     ///
@@ -179,7 +388,10 @@ impl<T: PartialOrd> Column<T> {
 }
 //
 //
-impl<T> Deref for Column<T> {
+impl<T> Deref for Column<T>
+where
+    Mapped<T>: Deref<Target = [T]>,
+{
     type Target = [T];
     //
     //
@@ -187,3 +399,19 @@ impl<T> Deref for Column<T> {
         self.data.deref()
     }
 }
+//
+//
+impl<T> Deref for Values<T>
+where
+    Mapped<T>: Deref<Target = [T]>,
+{
+    type Target = [T];
+    //
+    //
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Values::Owned(values) => values,
+            Values::Mapped(values) => values,
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use sal_sync::services::entity::error::str_err::StrErr;
+use std::{fmt, io};
+///
+/// Reason of [super::Cache] failure.
+///
+/// Positions in the file (`line`, `col`) start from 1,
+/// whereas positions in the dataset (`column`, `row`) start from 0.
+#[derive(Clone, PartialEq, Debug)]
+pub enum CacheError {
+    ///
+    /// Failed reading the file.
+    IoError {
+        kind: io::ErrorKind,
+        message: String,
+    },
+    ///
+    /// Invalid header line (see [super::header::Header]).
+    Header { line: usize, message: String },
+    ///
+    /// Header entry `key` is `found` whereas `expected` one required.
+    Mismatch {
+        key: String,
+        expected: String,
+        found: String,
+    },
+    ///
+    /// No column named `name` (see [super::header::Header::columns]).
+    UnknownColumn { name: String },
+    ///
+    /// Invalid binary file at `offset` bytes.
+    Corrupted { offset: usize, message: String },
+    ///
+    /// Number of values at `line` differs from the one at the first line.
+    Inconsistent { line: usize },
+    ///
+    /// Failed parsing value at `line` and `col`.
+    Parse { line: usize, col: usize },
+    ///
+    /// Dataset contains a non-comparable value (e. g. _NaN_) at `column` and `row`.
+    NonComparable { column: usize, row: usize },
+    ///
+    /// Query contains a non-comparable value (e. g. _NaN_) for `column`.
+    NonComparableQuery { column: usize },
+    ///
+    /// Query has more values than the dataset has columns.
+    TooManyValues { given: usize, columns: usize },
+    ///
+    /// Column to solve for is either given in the query or out of the dataset.
+    InvalidUnknown { column: usize },
+    ///
+    /// Query `value` for `column` is out of the column range [`min`, `max`].
+    OutOfRange {
+        column: usize,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+}
+//
+//
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError { kind, message } => write!(f, "IO error ({:?}): {}", kind, message),
+            Self::Header { line, message } => {
+                write!(f, "Invalid header at line={}: {}", line, message)
+            }
+            Self::Mismatch {
+                key,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Header mismatch of '{}': expected='{}', found='{}'",
+                key, expected, found
+            ),
+            Self::UnknownColumn { name } => write!(f, "No column named '{}'", name),
+            Self::Corrupted { offset, message } => {
+                write!(f, "Corrupted binary file at offset={}: {}", offset, message)
+            }
+            Self::Inconsistent { line } => write!(f, "Inconsistent dataset at line={}", line),
+            Self::Parse { line, col } => {
+                write!(f, "Failed parsing value at line={}, col={}", line, col)
+            }
+            Self::NonComparable { column, row } => {
+                write!(f, "Non-comparable value at column={}, row={}", column, row)
+            }
+            Self::NonComparableQuery { column } => {
+                write!(f, "Non-comparable value given for column={}", column)
+            }
+            Self::TooManyValues { given, columns } => write!(
+                f,
+                "Too many values: {} given, {} columns available",
+                given, columns
+            ),
+            Self::InvalidUnknown { column } => {
+                write!(f, "Invalid column={} to solve for", column)
+            }
+            Self::OutOfRange {
+                column,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "Value={} of column={} is out of range [{}, {}]",
+                value, column, min, max
+            ),
+        }
+    }
+}
+//
+//
+impl std::error::Error for CacheError {}
+//
+//
+impl From<CacheError> for StrErr {
+    fn from(err: CacheError) -> Self {
+        StrErr(err.to_string())
+    }
+}
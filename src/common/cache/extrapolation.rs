@@ -0,0 +1,30 @@
+///
+/// Policy used by [super::Cache] for values out of the dataset range.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Extrapolation {
+    ///
+    /// Out of range value is an error.
+    #[default]
+    Error,
+    ///
+    /// Out of range value is replaced by the nearest bound of the range.
+    ///
+    /// The result tells which values have been clamped (see [Approximation::clamped]).
+    Clamp,
+    ///
+    /// Linear extrapolation from the last segment of the dataset.
+    Linear,
+}
+///
+/// Rows approximated by [super::Cache] with details on how they are got.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Approximation {
+    ///
+    /// Approximated rows.
+    pub rows: Vec<Vec<f64>>,
+    ///
+    /// IDs of columns, which given values have been clamped to the column range.
+    ///
+    /// It's always empty unless [Extrapolation::Clamp] is used.
+    pub clamped: Vec<usize>,
+}
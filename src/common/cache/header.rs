@@ -0,0 +1,293 @@
+#[cfg(test)]
+#[path = "../../tests/common/cache/header_test.rs"]
+mod tests;
+//
+use super::error::CacheError;
+use indexmap::IndexMap;
+use sha2::{Digest, Sha256};
+use std::{
+    fmt,
+    fs::File,
+    io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+///
+/// Metadata of the [super::Cache] file.
+///
+/// The header precedes the dataset, each its line starts with '#':
+/// ```text
+/// # mdmt-cache v1
+/// # columns: heel trim draught volume
+/// # axes: heel trim draught
+/// # units: deg deg m m3
+/// # generator: mdmt-server 0.1.0
+/// # created: 1729152000
+/// # model_path: /path/to/model.step
+/// # model_hash: 9f86d081884c7d65...
+/// # heel_steps: -10 0 10
+/// 0 0 0 0
+/// ...
+/// ```
+/// The first line is required, the others are optional.
+/// Entries with unknown keys are kept in [Header::meta] (e. g. configuration steps).
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Header {
+    ///
+    /// Column names in the order of the dataset.
+    pub columns: Vec<String>,
+    ///
+    /// Names of the columns being grid axes of the dataset,
+    /// e. g. heel, trim, and draught of heel×trim×draught grid.
+    ///
+    /// Given values of the axes are interpolated between the grid nodes (see [super::Cache::get]).
+    pub axes: Vec<String>,
+    ///
+    /// Column units in the order of the dataset.
+    pub units: Vec<String>,
+    ///
+    /// Name and version of the application produced the file.
+    pub generator: String,
+    ///
+    /// Creation time in seconds since UNIX epoch.
+    pub created: u64,
+    ///
+    /// Source model the dataset is calculated for.
+    pub model_path: String,
+    ///
+    /// Hash of the source model file (see [Header::hash_file]).
+    pub model_hash: String,
+    ///
+    /// The rest entries, e. g. configuration the dataset is calculated with.
+    pub meta: IndexMap<String, String>,
+}
+//
+//
+impl Header {
+    ///
+    /// Current version of the file format.
+    pub const VERSION: u32 = 1;
+    //
+    //
+    const MAGIC: &'static str = "mdmt-cache";
+    ///
+    /// Creates a new instance generated by the current application at the current time.
+    pub fn new(columns: &[&str], units: &[&str]) -> Self {
+        Self {
+            columns: Vec::from_iter(columns.iter().map(|s| s.to_string())),
+            units: Vec::from_iter(units.iter().map(|s| s.to_string())),
+            generator: format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+            created: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default(),
+            ..Self::default()
+        }
+    }
+    ///
+    /// Returns the instance pointing to the source model.
+    pub fn with_model(self, path: impl AsRef<Path>, hash: impl Into<String>) -> Self {
+        Self {
+            model_path: path.as_ref().display().to_string(),
+            model_hash: hash.into(),
+            ..self
+        }
+    }
+    ///
+    /// Returns the instance, which grid axes are the columns named `axes` (see [Header::axes]).
+    pub fn with_axes(self, axes: &[&str]) -> Self {
+        Self {
+            axes: Vec::from_iter(axes.iter().map(|s| s.to_string())),
+            ..self
+        }
+    }
+    ///
+    /// Returns _true_ if the column `id` is a grid axis (see [Header::axes]).
+    pub fn is_axis(&self, id: usize) -> bool {
+        self.columns
+            .get(id)
+            .is_some_and(|column| self.axes.contains(column))
+    }
+    ///
+    /// Returns the instance with additional entry.
+    ///
+    /// Note that `value` must be a single line.
+    pub fn with_meta(mut self, key: impl Into<String>, value: impl fmt::Display) -> Self {
+        self.meta.insert(key.into(), value.to_string());
+        self
+    }
+    ///
+    /// Returns hex encoded SHA-256 hash of the file content.
+    pub fn hash_file(path: impl AsRef<Path>) -> io::Result<String> {
+        let mut hasher = Sha256::new();
+        io::copy(&mut File::open(path)?, &mut hasher)?;
+        Ok(hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect())
+    }
+    ///
+    /// Returns ID of the column named `name`.
+    pub fn column_id(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|column| column == name)
+    }
+    ///
+    /// Returns the header parsed from the leading lines of `text`, [None] if there is no header.
+    pub fn parse(text: &str) -> Result<Option<Self>, CacheError> {
+        let mut header = None;
+        let lines = text.lines().take_while(|line| Self::is_header_line(line));
+        for (line, line_id) in lines.zip(1..) {
+            Self::parse_line(&mut header, line_id, line)?;
+        }
+        Ok(header)
+    }
+    ///
+    /// Returns _true_ if `line` is a part of the header.
+    pub fn is_header_line(line: &str) -> bool {
+        line.starts_with('#')
+    }
+    ///
+    /// Parses `line` placed at `line_id` of the file (counting from 1) and updates `header`.
+    ///
+    /// The first line must be the version one, otherwise the error returns.
+    /// The `header` is created on the first line.
+    pub(super) fn parse_line(
+        header: &mut Option<Self>,
+        line_id: usize,
+        line: &str,
+    ) -> Result<(), CacheError> {
+        let err = |message: String| CacheError::Header {
+            line: line_id,
+            message,
+        };
+        let line = line.trim_start_matches('#').trim();
+        let Some(header) = header.as_mut() else {
+            let version = match line.split_once(' ') {
+                Some((Self::MAGIC, version)) => version.trim().trim_start_matches('v'),
+                _ => {
+                    return Err(err(format!(
+                        "Expected '{} v{}'",
+                        Self::MAGIC,
+                        Self::VERSION
+                    )))
+                }
+            };
+            return match version.parse::<u32>() {
+                Ok(Self::VERSION) => {
+                    header.replace(Self::default());
+                    Ok(())
+                }
+                _ => Err(err(format!("Unsupported version='{}'", version))),
+            };
+        };
+        let Some((key, value)) = line.split_once(':') else {
+            return Err(err(format!("Expected 'key: value', found '{}'", line)));
+        };
+        let value = value.trim();
+        let words = || Vec::from_iter(value.split_ascii_whitespace().map(str::to_owned));
+        match key.trim() {
+            "columns" => header.columns = words(),
+            "axes" => header.axes = words(),
+            "units" => header.units = words(),
+            "generator" => header.generator = value.to_owned(),
+            "created" => {
+                header.created = value
+                    .parse()
+                    .map_err(|_| err(format!("Invalid created='{}'", value)))?
+            }
+            "model_path" => header.model_path = value.to_owned(),
+            "model_hash" => header.model_hash = value.to_owned(),
+            key => {
+                header.meta.insert(key.to_owned(), value.to_owned());
+            }
+        }
+        Ok(())
+    }
+    ///
+    /// Returns the error if the header doesn't describe a dataset of `columns` width
+    /// or some of its axes isn't a column ([CacheError::UnknownColumn]).
+    pub(super) fn validate(&self, columns: usize) -> Result<(), CacheError> {
+        if let Some(name) = self.axes.iter().find(|name| !self.columns.contains(name)) {
+            return Err(CacheError::UnknownColumn { name: name.clone() });
+        }
+        let check = |key: &str, len: usize| match len == 0 || len == columns {
+            true => Ok(()),
+            false => Err(CacheError::Mismatch {
+                key: key.to_owned(),
+                expected: columns.to_string(),
+                found: len.to_string(),
+            }),
+        };
+        check("columns", self.columns.len())?;
+        check("units", self.units.len())
+    }
+    ///
+    /// Returns the error if the header differs from `expected` one.
+    ///
+    /// Only those fields of `expected` are compared, which are not empty.
+    /// [Header::generator] and [Header::created] are never compared.
+    pub fn check(&self, expected: &Self) -> Result<(), CacheError> {
+        let check = |key: &str, expected: String, found: String| {
+            if expected.is_empty() || expected == found {
+                return Ok(());
+            }
+            Err(CacheError::Mismatch {
+                key: key.to_owned(),
+                expected,
+                found,
+            })
+        };
+        let join = |vals: &[String]| vals.join(" ");
+        check("columns", join(&expected.columns), join(&self.columns))?;
+        check("axes", join(&expected.axes), join(&self.axes))?;
+        check("units", join(&expected.units), join(&self.units))?;
+        check(
+            "model_path",
+            expected.model_path.clone(),
+            self.model_path.clone(),
+        )?;
+        check(
+            "model_hash",
+            expected.model_hash.clone(),
+            self.model_hash.clone(),
+        )?;
+        for (key, expected) in &expected.meta {
+            let found = self.meta.get(key).cloned().unwrap_or_default();
+            check(key, expected.clone(), found)?;
+        }
+        Ok(())
+    }
+}
+//
+//
+impl fmt::Display for Header {
+    ///
+    /// Writes the header lines (see [Header] for the format).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# {} v{}", Self::MAGIC, Self::VERSION)?;
+        if !self.columns.is_empty() {
+            writeln!(f, "# columns: {}", self.columns.join(" "))?;
+        }
+        if !self.axes.is_empty() {
+            writeln!(f, "# axes: {}", self.axes.join(" "))?;
+        }
+        if !self.units.is_empty() {
+            writeln!(f, "# units: {}", self.units.join(" "))?;
+        }
+        if !self.generator.is_empty() {
+            writeln!(f, "# generator: {}", self.generator)?;
+        }
+        writeln!(f, "# created: {}", self.created)?;
+        if !self.model_path.is_empty() {
+            writeln!(f, "# model_path: {}", self.model_path)?;
+        }
+        if !self.model_hash.is_empty() {
+            writeln!(f, "# model_hash: {}", self.model_hash)?;
+        }
+        for (key, value) in &self.meta {
+            writeln!(f, "# {}: {}", key, value)?;
+        }
+        Ok(())
+    }
+}
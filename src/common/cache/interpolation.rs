@@ -0,0 +1,112 @@
+#[cfg(test)]
+#[path = "../../tests/common/cache/interpolation_test.rs"]
+mod tests;
+///
+/// Method used by [super::Cache] to approximate values in between the dataset rows.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Interpolation {
+    ///
+    /// Value of the nearest row.
+    Nearest,
+    ///
+    /// Piecewise linear interpolation between two neighbor rows.
+    #[default]
+    Linear,
+    ///
+    /// Monotone cubic (Fritsch–Carlson) spline.
+    ///
+    /// Besides two neighbor rows, it takes one more row on each side (if present)
+    /// to estimate the slopes. The spline preserves monotonicity of the dataset,
+    /// so there is no overshooting between the rows.
+    Spline,
+}
+//
+//
+impl Interpolation {
+    ///
+    /// Returns the number of points taken on each side of the approximated value.
+    pub(super) fn support(&self) -> usize {
+        match self {
+            Interpolation::Nearest | Interpolation::Linear => 1,
+            Interpolation::Spline => 2,
+        }
+    }
+    ///
+    /// Returns value at `x` approximated by `xs` and `ys` points.
+    ///
+    /// `xs` must be strictly monotonic and have the same length as `ys`.
+    /// If `x` is out of `xs` range, the nearest segment is used.
+    pub(super) fn apply(&self, xs: &[f64], ys: &[f64], x: f64) -> f64 {
+        match xs.len() {
+            0 => return f64::NAN,
+            1 => return ys[0],
+            _ => {}
+        }
+        // segment containing `x`
+        let id = (0..xs.len() - 1)
+            .find(|&id| (xs[id] - x) * (xs[id + 1] - x) <= 0.0)
+            .unwrap_or(if (xs[0] - x).abs() < (xs[xs.len() - 1] - x).abs() {
+                0
+            } else {
+                xs.len() - 2
+            });
+        let (x0, x1) = (xs[id], xs[id + 1]);
+        let (y0, y1) = (ys[id], ys[id + 1]);
+        match self {
+            Interpolation::Nearest => match (x - x0).abs() <= (x1 - x).abs() {
+                true => y0,
+                false => y1,
+            },
+            Interpolation::Linear => y0 + (x - x0) / (x1 - x0) * (y1 - y0),
+            Interpolation::Spline => {
+                let slopes = Self::monotone_slopes(xs, ys);
+                let h = x1 - x0;
+                let t = (x - x0) / h;
+                let t2 = t * t;
+                let t3 = t2 * t;
+                // cubic Hermite basis
+                let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+                let h10 = t3 - 2.0 * t2 + t;
+                let h01 = -2.0 * t3 + 3.0 * t2;
+                let h11 = t3 - t2;
+                h00 * y0 + h10 * h * slopes[id] + h01 * y1 + h11 * h * slopes[id + 1]
+            }
+        }
+    }
+    ///
+    /// Returns tangents of `ys` at each of `xs` according to Fritsch–Carlson method.
+    fn monotone_slopes(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+        let secants = Vec::from_iter(
+            xs.windows(2)
+                .zip(ys.windows(2))
+                .map(|(x, y)| (y[1] - y[0]) / (x[1] - x[0])),
+        );
+        let last = secants.len() - 1;
+        let mut slopes = Vec::with_capacity(xs.len());
+        slopes.push(secants[0]);
+        for win in secants.windows(2) {
+            slopes.push(match win[0] * win[1] > 0.0 {
+                true => (win[0] + win[1]) / 2.0,
+                false => 0.0,
+            });
+        }
+        slopes.push(secants[last]);
+        // limit slopes to keep the spline monotonic
+        for (id, &secant) in secants.iter().enumerate() {
+            if secant == 0.0 {
+                slopes[id] = 0.0;
+                slopes[id + 1] = 0.0;
+                continue;
+            }
+            let alpha = slopes[id] / secant;
+            let beta = slopes[id + 1] / secant;
+            let norm = alpha * alpha + beta * beta;
+            if norm > 9.0 {
+                let tau = 3.0 / norm.sqrt();
+                slopes[id] = tau * alpha * secant;
+                slopes[id + 1] = tau * beta * secant;
+            }
+        }
+        slopes
+    }
+}
@@ -2,17 +2,42 @@
 #[path = "../../tests/common/cache/table_test.rs"]
 mod tests;
 //
-use super::{bound::Bound, column::Column, OwnedSet};
+use super::{
+    binary::Mapped,
+    bound::Bound,
+    column::Column,
+    error::CacheError,
+    extrapolation::{Approximation, Extrapolation},
+    interpolation::Interpolation,
+    OwnedSet,
+};
 use sal_sync::services::entity::dbg_id::DbgId;
+use std::ops::Deref;
 ///
 /// Set of [Column]s.
 pub(super) struct Table<T> {
     dbgid: DbgId,
     columns: OwnedSet<Column<T>>,
 }
+///
+/// Settings and values shared by the internal steps of [Table::get_unchecked].
+struct Request {
+    ///
+    /// Given values (column id, value) of the columns, which are not grid axes.
+    free_vals: Vec<(usize, f64)>,
+    ///
+    /// IDs of grid axis columns, which values are not given,
+    /// so they are used to match rows of different nodes.
+    key_ids: Vec<usize>,
+    interpolation: Interpolation,
+    extrapolation: Extrapolation,
+}
 //
 //
-impl<T> Table<T> {
+impl<T: PartialOrd> Table<T>
+where
+    Mapped<T>: Deref<Target = [T]>,
+{
     ///
     /// Creates a new instance.
     pub(super) fn new(parent: &DbgId, cols: impl Into<OwnedSet<Column<T>>>) -> Self {
@@ -20,6 +45,16 @@ impl<T> Table<T> {
         let columns = cols.into();
         Self { dbgid, columns }
     }
+    ///
+    /// Returns _true_ if the column `id` is a grid axis of the table (see [Column::into_grid_axis]).
+    fn is_axis(&self, id: usize) -> bool {
+        self.columns.get(id).is_some_and(Column::is_grid_axis)
+    }
+    ///
+    /// Returns the columns.
+    pub(super) fn columns(&self) -> &[Column<T>] {
+        &self.columns
+    }
 }
 //
 //
@@ -27,25 +62,159 @@ impl Table<f64> {
     ///
     /// Returns approximated values from table.
     ///
-    /// This is a safe method in terms if bounds: If `approx_vals` has more elements than [Table] row provides,
-    /// this method returns [None]. In contrast, the empty vector returns if no value found.
+    /// This is a safe method: it neither panics on values [Table] can't handle, nor on bounds.
+    /// The empty vector returns if no value found.
     ///
-    /// # Panics
-    /// Panic occurs if `approx_vals` contains a non-comparable value (e. g. _NaN_).
-    pub(super) fn get(&self, approx_vals: &[Option<f64>]) -> Option<Vec<Vec<f64>>> {
-        (approx_vals.len() <= self.columns.len()).then(|| self.get_unchecked(approx_vals))
+    /// # Errors
+    /// This method returns the error if at least one of the statements is true:
+    /// - `approx_vals` has more elements than [Table] row provides ([CacheError::TooManyValues]),
+    /// - `approx_vals` contains a non-comparable value, e. g. _NaN_ ([CacheError::NonComparableQuery]),
+    /// - `approx_vals` contains a value out of the column range
+    ///   and `extrapolation` is [Extrapolation::Error] ([CacheError::OutOfRange]).
+    pub(super) fn get(
+        &self,
+        approx_vals: &[Option<f64>],
+        interpolation: Interpolation,
+        extrapolation: Extrapolation,
+    ) -> Result<Approximation, CacheError> {
+        self.check(approx_vals)?;
+        self.get_unchecked(approx_vals, interpolation, extrapolation)
+    }
+    ///
+    /// Returns the error if [Table] can't handle `approx_vals` (see [Table::get]).
+    fn check(&self, approx_vals: &[Option<f64>]) -> Result<(), CacheError> {
+        if approx_vals.len() > self.columns.len() {
+            return Err(CacheError::TooManyValues {
+                given: approx_vals.len(),
+                columns: self.columns.len(),
+            });
+        }
+        match approx_vals
+            .iter()
+            .position(|val| val.is_some_and(|val| val.is_nan()))
+        {
+            Some(column) => Err(CacheError::NonComparableQuery { column }),
+            None => Ok(()),
+        }
+    }
+    ///
+    /// Returns rows reaching given `fixed_vals` along the column `unknown`.
+    ///
+    /// Given values of grid axis columns are interpolated first (see [Table::get]).
+    /// The rows taken this way form curves parametrized by the `unknown` column,
+    /// one curve for each combination of nodes of the rest grid axes.
+    /// Then each curve is solved for the rest given values (see [Column::get_bounds]),
+    /// so a non-monotonic curve may result several rows.
+    ///
+    /// # Errors
+    /// Besides the errors of [Table::get], [CacheError::InvalidUnknown] returns
+    /// if `unknown` is either given in `fixed_vals` or out of the columns.
+    /// [CacheError::OutOfRange] returns only if no curve reaches the values.
+    pub(super) fn solve(
+        &self,
+        fixed_vals: &[Option<f64>],
+        unknown: usize,
+        interpolation: Interpolation,
+        extrapolation: Extrapolation,
+    ) -> Result<Vec<Vec<f64>>, CacheError> {
+        let callee = "solve";
+        self.check(fixed_vals)?;
+        if unknown >= self.columns.len() || matches!(fixed_vals.get(unknown), Some(Some(_))) {
+            return Err(CacheError::InvalidUnknown { column: unknown });
+        }
+        // split given values into values of grid axis columns and the targets
+        let mut axis_vals = vec![None; fixed_vals.len()];
+        let mut target_vals = vec![None; fixed_vals.len()];
+        for (id, val) in fixed_vals.iter().enumerate() {
+            match self.is_axis(id) {
+                true => axis_vals[id] = *val,
+                false => target_vals[id] = *val,
+            }
+        }
+        let mut rows = match axis_vals.iter().any(Option::is_some) {
+            true => {
+                self.get_unchecked(&axis_vals, interpolation, extrapolation)?
+                    .rows
+            }
+            false => {
+                let len = self.columns.first().map_or(0, |col| col.len());
+                Vec::from_iter(
+                    (0..len)
+                        .map(|row_id| Vec::from_iter(self.columns.iter().map(|col| col[row_id]))),
+                )
+            }
+        };
+        if target_vals.iter().all(Option::is_none) {
+            return Ok(rows);
+        }
+        // split rows into curves by the nodes of the rest grid axes
+        let key_ids = Vec::from_iter((0..self.columns.len()).filter(|&id| {
+            id != unknown && self.is_axis(id) && axis_vals.get(id).is_none_or(Option::is_none)
+        }));
+        let cmp_keys = |l_row: &Vec<f64>, r_row: &Vec<f64>| {
+            key_ids
+                .iter()
+                .map(|&id| l_row[id].total_cmp(&r_row[id]))
+                .find(|ord| ord.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        };
+        rows.sort_by(|l_row, r_row| {
+            cmp_keys(l_row, r_row).then_with(|| match self.is_axis(unknown) {
+                true => l_row[unknown].total_cmp(&r_row[unknown]),
+                false => std::cmp::Ordering::Equal,
+            })
+        });
+        let mut solutions = vec![];
+        let mut out_of_range = None;
+        for curve in rows.chunk_by(|l_row, r_row| cmp_keys(l_row, r_row).is_eq()) {
+            let columns = Vec::from_iter((0..self.columns.len()).map(|col_id| {
+                let dbgid = DbgId::with_parent(&self.dbgid, &format!("Curve_Column_{}", col_id));
+                Column::new(dbgid, Vec::from_iter(curve.iter().map(|row| row[col_id])))
+            }));
+            match Table::new(&self.dbgid, columns).get(&target_vals, interpolation, extrapolation) {
+                Ok(approximation) => solutions.extend(approximation.rows),
+                Err(err @ CacheError::OutOfRange { .. }) => {
+                    log::debug!("{}.{} | Curve skipped: {}", self.dbgid, callee, err);
+                    out_of_range = Some(err);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        match (solutions.is_empty(), out_of_range) {
+            (true, Some(err)) => Err(err),
+            _ => Ok(solutions),
+        }
     }
     ///
     /// Returns approximated values from table.
     ///
+    /// Values of grid axis columns (see [Column::get_node_bounds]) are interpolated axis by axis:
+    /// rows are found for the nearest nodes of the axis (see [Interpolation::support])
+    /// and then interpolated at given value. Rows of different nodes are matched
+    /// by values of the rest grid axis columns.
+    ///
+    /// Values of other columns are approximated along the dataset (see [Column::get_bounds])
+    /// and interpolated between neighbor rows within the monotonic segment.
+    ///
+    /// Values out of the column range are handled according to `extrapolation`.
+    /// Note that extrapolation is always linear whatever `interpolation` is.
+    ///
     /// Note that this is an unsafe version for internal use.
     /// Caller must garantee that `approx_vals.len()` is less or equal to `self.columns.len()`.
     ///
+    /// # Errors
+    /// See [Table::get] for details.
+    ///
     /// # Panics
     /// This method panics if at least one of the statements is true:
     /// - `approx_vals.len()` is greter than `self.columns.len()`,
     /// - `approx_vals` contains a non-comparable value (e. g. _NaN_) (see [Column::get_bounds]).
-    fn get_unchecked(&self, approx_vals: &[Option<f64>]) -> Vec<Vec<f64>> {
+    fn get_unchecked(
+        &self,
+        approx_vals: &[Option<f64>],
+        interpolation: Interpolation,
+        extrapolation: Extrapolation,
+    ) -> Result<Approximation, CacheError> {
         let callee = "get_unchecked";
         assert!(
             self.columns.len() >= approx_vals.len(),
@@ -55,14 +224,195 @@ impl Table<f64> {
             self.columns.len(),
             approx_vals.len()
         );
-        let bounds = {
+        let mut clamped = vec![];
+        let mut vals = vec![];
+        for (id, val) in approx_vals
+            .iter()
+            .enumerate()
+            .filter_map(|(id, val)| val.map(|val| (id, val)))
+        {
+            let col = &self.columns[id];
+            let Some((&min, &max)) = col.get_range() else {
+                return Ok(Approximation::default());
+            };
+            if val.partial_cmp(&min).is_none() || (min..=max).contains(&val) {
+                vals.push((id, val));
+                continue;
+            }
+            match extrapolation {
+                Extrapolation::Error => {
+                    return Err(CacheError::OutOfRange {
+                        column: id,
+                        value: val,
+                        min,
+                        max,
+                    })
+                }
+                Extrapolation::Clamp => {
+                    log::debug!(
+                        "{}.{} | Clamping value={} of column={} to range [{}, {}]",
+                        self.dbgid,
+                        callee,
+                        val,
+                        id,
+                        min,
+                        max
+                    );
+                    clamped.push(id);
+                    vals.push((id, val.clamp(min, max)));
+                }
+                Extrapolation::Linear => vals.push((id, val)),
+            }
+        }
+        // split given values into values of grid axis columns and the others
+        let (axis_vals, free_vals): (Vec<_>, Vec<_>) =
+            vals.into_iter().partition(|&(id, _)| self.is_axis(id));
+        log::debug!(
+            "{}.{} | Axis values: {:?}, free values: {:?}",
+            self.dbgid,
+            callee,
+            axis_vals,
+            free_vals
+        );
+        let key_ids = Vec::from_iter(
+            (0..self.columns.len())
+                .filter(|&id| self.is_axis(id) && !matches!(approx_vals.get(id), Some(Some(_)))),
+        );
+        let request = Request {
+            free_vals,
+            key_ids,
+            interpolation,
+            extrapolation,
+        };
+        Ok(Approximation {
+            rows: self.interpolate_axes(&axis_vals, &mut vec![], &request),
+            clamped,
+        })
+    }
+    ///
+    /// Returns rows interpolated over the grid axes given as a set of (column id, value).
+    ///
+    /// It takes the first axis, gets rows for each of its nearest nodes
+    /// recursively calling itself for the rest axes,
+    /// and then interpolates matched rows at the axis value.
+    /// The recursion ends by [Table::get_rows] once all axes are split into `nodes`.
+    fn interpolate_axes(
+        &self,
+        axis_vals: &[(usize, f64)],
+        nodes: &mut Vec<(usize, f64)>,
+        request: &Request,
+    ) -> Vec<Vec<f64>> {
+        let callee = "interpolate_axes";
+        let Some((&(id, val), axis_vals)) = axis_vals.split_first() else {
+            return self.get_rows(nodes, request);
+        };
+        let col = &self.columns[id];
+        let mut interpolation = request.interpolation;
+        // nodes the value is interpolated by
+        let support = match col.get_node_bounds(&val) {
+            Some(Bound::Single(row_id)) => vec![col[row_id]],
+            Some(Bound::Range(low_id, high_id)) => {
+                let mut support = vec![col[low_id], col[high_id]];
+                let (mut low_id, mut high_id) = (low_id, high_id);
+                for _ in 1..interpolation.support() {
+                    if let (Some(prev_id), _) = col.get_node_neighbors(low_id) {
+                        support.insert(0, col[prev_id]);
+                        low_id = prev_id;
+                    }
+                    if let (_, Some(next_id)) = col.get_node_neighbors(high_id) {
+                        support.push(col[next_id]);
+                        high_id = next_id;
+                    }
+                }
+                support
+            }
+            Some(Bound::None) if request.extrapolation == Extrapolation::Linear => {
+                match col.get_edge_node_bounds(&val) {
+                    Some(Bound::Range(low_id, high_id)) => {
+                        interpolation = Interpolation::Linear;
+                        vec![col[low_id], col[high_id]]
+                    }
+                    _ => return vec![],
+                }
+            }
+            _ => return vec![],
+        };
+        let mut rows_by_node = support.iter().map(|&node| {
+            nodes.push((id, node));
+            let rows = self.interpolate_axes(axis_vals, nodes, request);
+            nodes.pop();
+            rows
+        });
+        let base_rows = rows_by_node.next().unwrap_or_default();
+        // rows are taken out once matched
+        let mut rows_by_node =
+            Vec::from_iter(rows_by_node.map(|rows| Vec::from_iter(rows.into_iter().map(Some))));
+        if rows_by_node.is_empty() {
+            return base_rows;
+        }
+        let key = |row: &[f64]| Vec::from_iter(request.key_ids.iter().map(|&id| row[id]));
+        base_rows
+            .into_iter()
+            .filter_map(|row| {
+                let row_key = key(&row);
+                let mut matched = vec![row];
+                for rows in rows_by_node.iter_mut() {
+                    let other = rows
+                        .iter_mut()
+                        .find(|other| other.as_ref().is_some_and(|other| key(other) == row_key))?
+                        .take()?;
+                    matched.push(other);
+                }
+                let vals = Vec::from_iter((0..self.columns.len()).map(|col_id| {
+                    let ys = Vec::from_iter(matched.iter().map(|row| row[col_id]));
+                    interpolation.apply(&support, &ys, val)
+                }));
+                log::trace!(
+                    "{}.{} | Interpolation: col_id={} nodes={:?} key={:?} result={:?}",
+                    self.dbgid,
+                    callee,
+                    id,
+                    support,
+                    row_key,
+                    vals
+                );
+                Some(vals)
+            })
+            .collect()
+    }
+    ///
+    /// Returns rows matching exactly grid `nodes` and approximated to the free values of `request`,
+    /// both are given as a set of (column id, value).
+    ///
+    /// Rows found in between two neighbors are interpolated according to the `request`.
+    /// Such rows are only taken if both neighbors match the `nodes`.
+    fn get_rows(&self, nodes: &[(usize, f64)], request: &Request) -> Vec<Vec<f64>> {
+        let callee = "get_rows";
+        let free_vals = &request.free_vals;
+        // sorted IDs of rows matching all nodes
+        let node_ids = nodes
+            .iter()
+            .fold(None, |ids: Option<Vec<usize>>, (id, node)| {
+                let node_ids = self.columns[*id].get_node_ids(node);
+                Some(match ids {
+                    None => node_ids.to_vec(),
+                    Some(ids) => Vec::from_iter(
+                        ids.into_iter()
+                            .filter(|row_id| node_ids.binary_search(row_id).is_ok()),
+                    ),
+                })
+            });
+        let free_bounds = (!free_vals.is_empty()).then(|| {
             let mut val_bounds = vec![];
-            for (id, val) in approx_vals
-                .iter()
-                .enumerate()
-                .filter_map(|(id, val)| val.as_ref().map(|val| (id, val)))
-            {
-                let bounds = self.columns[id].get_bounds(val);
+            for (id, val) in free_vals {
+                let col = &self.columns[*id];
+                let bounds = match request.extrapolation {
+                    Extrapolation::Linear => match col.get_edge_bounds(val) {
+                        bounds if bounds.is_empty() => col.get_bounds(val),
+                        bounds => bounds,
+                    },
+                    _ => col.get_bounds(val),
+                };
                 val_bounds.push(bounds);
             }
             log::debug!(
@@ -73,7 +423,7 @@ impl Table<f64> {
             );
             loop {
                 match (val_bounds.pop(), val_bounds.last_mut()) {
-                    (None, _) => return vec![],
+                    (None, _) => break vec![],
                     (Some(bounds), Some(last_bounds)) => {
                         //
                         // NOTE: switch between last_bounds and bounds may increase perf
@@ -98,6 +448,26 @@ impl Table<f64> {
                     }
                 }
             }
+        });
+        // rows matching all nodes, any row if there are no nodes
+        let is_node_row = |row_id: &usize| {
+            node_ids
+                .as_ref()
+                .is_none_or(|ids| ids.binary_search(row_id).is_ok())
+        };
+        // keep bounds, which rows all match the nodes
+        let bounds = match (node_ids.as_deref(), free_bounds) {
+            (None, None) => return vec![],
+            (Some(ids), None) => Vec::from_iter(ids.iter().copied().map(Bound::Single)),
+            (None, Some(bounds)) => bounds,
+            (Some(ids), Some(bounds)) => Vec::from_iter(bounds.into_iter().filter(|bound| {
+                let contains = |row_id| ids.binary_search(row_id).is_ok();
+                match bound {
+                    Bound::None => false,
+                    Bound::Single(row_id) => contains(row_id),
+                    Bound::Range(start, end) => contains(start) && contains(end),
+                }
+            })),
         };
         log::debug!("{}.{} | Merged bounds: {:?}", self.dbgid, callee, bounds);
         bounds
@@ -113,11 +483,36 @@ impl Table<f64> {
                     Some(vals)
                 }
                 Bound::Range(start, end) => {
+                    // the range is produced by a free value placed in between
+                    let (x_id, x) = free_vals
+                        .iter()
+                        .copied()
+                        .find(|&(id, _)| self.columns[id][start] != self.columns[id][end])?;
+                    let x_col = &self.columns[x_id];
+                    // extrapolation is always linear
+                    let (x_start, x_end) = (x_col[start], x_col[end]);
+                    let is_within = (x_start.min(x_end)..=x_start.max(x_end)).contains(&x);
+                    let interpolation = match is_within {
+                        true => request.interpolation,
+                        false => Interpolation::Linear,
+                    };
+                    // extend the range within the monotonic segment by the rows of the same nodes
+                    let (first, last) = x_col.get_monotonic_segment(start, end)?;
+                    let extra = interpolation.support() - 1;
+                    let mut row_ids =
+                        Vec::from_iter((first..start).rev().filter(is_node_row).take(extra));
+                    row_ids.reverse();
+                    row_ids.extend((start..=end).filter(is_node_row));
+                    row_ids.extend((end + 1..=last).filter(is_node_row).take(extra));
+                    row_ids.retain(|&row_id| {
+                        (row_id == start || x_col[row_id] != x_start)
+                            && (row_id == end || x_col[row_id] != x_end)
+                    });
+                    let xs = Vec::from_iter(row_ids.iter().map(|&row_id| x_col[row_id]));
                     let mut vals = Vec::with_capacity(self.columns.len());
-                    let len = end - start + 1;
                     for (col_id, col) in self.columns.iter().enumerate() {
-                        let sum = (start..=end).map(|row_id| col[row_id]).sum::<f64>();
-                        let val = sum / len as f64;
+                        let ys = Vec::from_iter(row_ids.iter().map(|&row_id| col[row_id]));
+                        let val = interpolation.apply(&xs, &ys, x);
                         vals.push(val);
                         log::trace!(
                             "{}.{} | Interpolation: col_id={} from row_id={} to row_id={} with result={}",
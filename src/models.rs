@@ -2,4 +2,8 @@
 //! Complete 3D model of the ship.
 //! Consists of simple and complex 3D objects.
 //
+pub mod loading_condition;
+pub mod seakeeping;
 pub mod ship_model;
+pub mod stability;
+pub mod strength;
@@ -0,0 +1,441 @@
+//!
+//! Weights the ship carries: the lightship and the deadweight items.
+//
+#[cfg(test)]
+#[path = "../tests/models/loading_condition_test.rs"]
+mod tests;
+//
+use super::ship_model::local_cache::tank_cache::Gauge;
+use sal_sync::services::entity::error::str_err::StrErr;
+use std::{fmt, fs, path::Path, str::FromStr};
+///
+/// Mass in tons and its center of gravity (LCG, TCG, and VCG) in the model coordinate system.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Weight {
+    pub mass: f64,
+    pub center_of_gravity: [f64; 3],
+}
+//
+//
+impl Weight {
+    ///
+    /// Creates a new instance.
+    pub fn new(mass: f64, center_of_gravity: [f64; 3]) -> Self {
+        Self {
+            mass,
+            center_of_gravity,
+        }
+    }
+    ///
+    /// Returns the moment of the mass about the origin along each axis, t·m.
+    pub fn moment(&self) -> [f64; 3] {
+        self.center_of_gravity.map(|coord| coord * self.mass)
+    }
+}
+///
+/// Cargo, stores, ballast, or liquid in a tank loaded on the ship.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DeadweightItem {
+    ///
+    /// Human readable name, e. g. "Fuel oil tank 1P".
+    pub name: String,
+    pub weight: Weight,
+    ///
+    /// Moment of inertia of the free surface multiplied by the liquid density, t·m.
+    /// [None] for solid items and full tanks.
+    pub free_surface_moment: Option<f64>,
+    ///
+    /// Longitudinal coordinates [aft, fore] the mass is spread between,
+    /// [None] for the point weight at LCG.
+    pub extent: Option<[f64; 2]>,
+}
+//
+//
+impl DeadweightItem {
+    ///
+    /// Creates a new instance of the point weight without free surface.
+    pub fn new(name: impl Into<String>, weight: Weight) -> Self {
+        Self {
+            name: name.into(),
+            weight,
+            free_surface_moment: None,
+            extent: None,
+        }
+    }
+    ///
+    /// Returns the instance with the free surface moment, t·m.
+    pub fn with_free_surface(mut self, free_surface_moment: f64) -> Self {
+        self.free_surface_moment = Some(free_surface_moment);
+        self
+    }
+    ///
+    /// Returns the instance spread between `aft` and `fore` longitudinal coordinates.
+    pub fn with_extent(mut self, aft: f64, fore: f64) -> Self {
+        self.extent = Some([aft.min(fore), aft.max(fore)]);
+        self
+    }
+}
+///
+/// Liquid in the tank given by its level measured by the crew.
+///
+/// It's turned into [DeadweightItem] by the tank calibration (see [ShipModel::resolve_tanks]).
+///
+/// [ShipModel::resolve_tanks]: super::ship_model::ShipModel::resolve_tanks
+#[derive(Clone, PartialEq, Debug)]
+pub struct TankLoad {
+    ///
+    /// Key of the model element making the tank (see [TankCacheConf::tank]).
+    ///
+    /// [TankCacheConf::tank]: super::ship_model::local_cache::tank_cache::tank_cache_conf::TankCacheConf::tank
+    pub tank: String,
+    pub gauge: Gauge,
+    ///
+    /// Density of the liquid, t/m3.
+    pub density: f64,
+}
+//
+//
+impl TankLoad {
+    ///
+    /// Creates a new instance.
+    pub fn new(tank: impl Into<String>, gauge: Gauge, density: f64) -> Self {
+        Self {
+            tank: tank.into(),
+            gauge,
+            density,
+        }
+    }
+}
+///
+/// All the weights of the ship in some loading condition.
+///
+/// It gives the displacement and the center of gravity used to find the floating position
+/// (see [ShipModel::floating_position]) and check the stability.
+///
+/// The condition is stored in the text file, the lines started with '#' are the header:
+/// ```text
+/// # mdmt-loading v1
+/// # name: Full load departure
+/// # columns: kind mass lcg tcg vcg fsm aft fore name
+/// # units: - t m m m t*m m m -
+/// lightship 1200 45.2 0 6.1
+/// item 350 40 0 2.5 120 35 45 Fuel oil tank 1P
+/// item 15 70 0 8 - - - Stores
+/// tank sounding 1.2 0.95 /tanks/do_2s
+/// ```
+/// The first line and exactly one `lightship` row are required.
+/// `-` stands for no free surface and for the point weight (no `aft` and `fore` extent),
+/// the rest of the `item` row is the item name.
+/// The `tank` row is the gauge (`sounding` or `ullage`), its value in m,
+/// the liquid density in t/m3, and the rest is the tank key.
+///
+/// [ShipModel::floating_position]: super::ship_model::ShipModel::floating_position
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct LoadingCondition {
+    ///
+    /// Human readable name, e. g. "Full load departure".
+    pub name: String,
+    pub lightship: Weight,
+    pub items: Vec<DeadweightItem>,
+    ///
+    /// Tanks, which weights aren't known until they are resolved (see [ShipModel::resolve_tanks]).
+    /// They aren't taken into account by the sums below.
+    ///
+    /// [ShipModel::resolve_tanks]: super::ship_model::ShipModel::resolve_tanks
+    pub tanks: Vec<TankLoad>,
+}
+//
+//
+impl LoadingCondition {
+    ///
+    /// Current version of the file format.
+    pub const VERSION: u32 = 1;
+    //
+    //
+    const MAGIC: &'static str = "mdmt-loading";
+    ///
+    /// Creates a new instance without deadweight items.
+    pub fn new(name: impl Into<String>, lightship: Weight) -> Self {
+        Self {
+            name: name.into(),
+            lightship,
+            items: vec![],
+            tanks: vec![],
+        }
+    }
+    ///
+    /// Returns the instance with `item` appended.
+    pub fn with_item(mut self, item: DeadweightItem) -> Self {
+        self.items.push(item);
+        self
+    }
+    ///
+    /// Returns the instance with `tank` appended.
+    pub fn with_tank(mut self, tank: TankLoad) -> Self {
+        self.tanks.push(tank);
+        self
+    }
+    ///
+    /// Returns the total mass of the deadweight items, t.
+    pub fn deadweight(&self) -> f64 {
+        self.items.iter().map(|item| item.weight.mass).sum()
+    }
+    ///
+    /// Returns the lightship mass plus the deadweight, t.
+    pub fn displacement(&self) -> f64 {
+        self.lightship.mass + self.deadweight()
+    }
+    ///
+    /// Returns the center of gravity of all the weights (LCG, TCG, and VCG),
+    /// [None] if the displacement isn't positive.
+    pub fn center_of_gravity(&self) -> Option<[f64; 3]> {
+        let displacement = self.displacement();
+        if displacement <= 0.0 || displacement.is_nan() {
+            return None;
+        }
+        let moment = self
+            .items
+            .iter()
+            .map(|item| item.weight.moment())
+            .fold(self.lightship.moment(), |sum, moment| {
+                [0, 1, 2].map(|i| sum[i] + moment[i])
+            });
+        Some(moment.map(|moment| moment / displacement))
+    }
+    ///
+    /// Returns the total free surface moment of the deadweight items, t·m.
+    pub fn free_surface_moment(&self) -> f64 {
+        self.items
+            .iter()
+            .filter_map(|item| item.free_surface_moment)
+            .sum()
+    }
+    ///
+    /// Returns the virtual rise of the center of gravity due to the free surfaces, m,
+    /// i. e. the free surface moment divided by the displacement.
+    ///
+    /// It's zero if the displacement isn't positive.
+    pub fn free_surface_correction(&self) -> f64 {
+        let displacement = self.displacement();
+        match displacement > 0.0 {
+            true => self.free_surface_moment() / displacement,
+            false => 0.0,
+        }
+    }
+    ///
+    /// Reads the instance from the file at `path` (see [LoadingCondition] for the format).
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, StrErr> {
+        let path = path.as_ref();
+        fs::read_to_string(path)
+            .map_err(|err| StrErr(format!("Failed reading path='{}': {}", path.display(), err)))?
+            .parse()
+            .map_err(|err: StrErr| {
+                StrErr(format!("Failed parsing path='{}': {}", path.display(), err))
+            })
+    }
+    ///
+    /// Writes the instance to the file at `path` (see [LoadingCondition] for the format).
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), StrErr> {
+        let path = path.as_ref();
+        fs::write(path, self.to_string())
+            .map_err(|err| StrErr(format!("Failed writing path='{}': {}", path.display(), err)))
+    }
+    ///
+    /// Returns `count` values of the row at `line` parsed from `vals` starting at `col`,
+    /// which must be finite, the first one (e. g. mass) must be non-negative.
+    fn parse_values<'a>(
+        line: usize,
+        col: usize,
+        vals: &mut impl Iterator<Item = &'a str>,
+        count: usize,
+    ) -> Result<Vec<f64>, StrErr> {
+        let mut parsed = Vec::with_capacity(count);
+        for offset in 0..count {
+            let val = vals
+                .next()
+                .ok_or_else(|| StrErr(format!("Too few values at line={}", line)))?;
+            match val.parse::<f64>() {
+                Ok(val) if val.is_finite() && (offset > 0 || val >= 0.0) => parsed.push(val),
+                _ => {
+                    return Err(StrErr(format!(
+                        "Invalid value='{}' at line={}, col={}",
+                        val,
+                        line,
+                        col + offset
+                    )))
+                }
+            }
+        }
+        Ok(parsed)
+    }
+    ///
+    /// Returns [aft, fore] extent of the `item` row at `line` parsed from `vals`,
+    /// [None] if both are `-`.
+    fn parse_extent<'a>(
+        line: usize,
+        vals: &mut impl Iterator<Item = &'a str>,
+    ) -> Result<Option<[f64; 2]>, StrErr> {
+        let (aft, fore) = (vals.next(), vals.next());
+        match (aft, fore) {
+            (Some("-"), Some("-")) => Ok(None),
+            (Some(aft), Some(fore)) => match (aft.parse::<f64>(), fore.parse::<f64>()) {
+                (Ok(aft), Ok(fore)) if aft.is_finite() && fore.is_finite() && aft <= fore => {
+                    Ok(Some([aft, fore]))
+                }
+                _ => Err(StrErr(format!(
+                    "Invalid extent='{} {}' at line={}, col=7",
+                    aft, fore, line
+                ))),
+            },
+            _ => Err(StrErr(format!("Too few values at line={}", line))),
+        }
+    }
+}
+//
+//
+impl FromStr for LoadingCondition {
+    type Err = StrErr;
+    //
+    //
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()));
+        let magic = format!("# {} v{}", Self::MAGIC, Self::VERSION);
+        match lines.next() {
+            Some((_, line)) if line == magic => {}
+            _ => return Err(StrErr(format!("Expected '{}' at line=1", magic))),
+        }
+        let mut name = String::new();
+        let mut lightship = None;
+        let mut items = vec![];
+        let mut tanks = vec![];
+        for (line, text) in lines {
+            if text.is_empty() {
+                continue;
+            }
+            if let Some(entry) = text.strip_prefix('#') {
+                if let Some((key, val)) = entry.split_once(':') {
+                    if key.trim() == "name" {
+                        name = val.trim().to_owned();
+                    }
+                }
+                continue;
+            }
+            let mut vals = text.split_whitespace();
+            match vals.next() {
+                Some("lightship") => {
+                    if lightship.is_some() {
+                        return Err(StrErr(format!("Duplicated lightship at line={}", line)));
+                    }
+                    let vals = Self::parse_values(line, 2, &mut vals, 4)?;
+                    lightship = Some(Weight::new(vals[0], [vals[1], vals[2], vals[3]]));
+                }
+                Some("item") => {
+                    let weight = Self::parse_values(line, 2, &mut vals, 4)?;
+                    let free_surface_moment = match vals.next() {
+                        Some("-") => None,
+                        Some(val) => match val.parse::<f64>() {
+                            Ok(val) if val.is_finite() && val >= 0.0 => Some(val),
+                            _ => {
+                                return Err(StrErr(format!(
+                                    "Invalid value='{}' at line={}, col=6",
+                                    val, line
+                                )))
+                            }
+                        },
+                        None => return Err(StrErr(format!("Too few values at line={}", line))),
+                    };
+                    let extent = Self::parse_extent(line, &mut vals)?;
+                    items.push(DeadweightItem {
+                        name: Vec::from_iter(vals).join(" "),
+                        weight: Weight::new(weight[0], [weight[1], weight[2], weight[3]]),
+                        free_surface_moment,
+                        extent,
+                    });
+                }
+                Some("tank") => {
+                    let gauge = vals.next();
+                    let level = Self::parse_values(line, 3, &mut vals, 2)?;
+                    if level[1] <= 0.0 {
+                        return Err(StrErr(format!(
+                            "Expected positive density at line={}, col=4",
+                            line
+                        )));
+                    }
+                    let gauge = match gauge {
+                        Some("sounding") => Gauge::Sounding(level[0]),
+                        Some("ullage") => Gauge::Ullage(level[0]),
+                        _ => {
+                            return Err(StrErr(format!(
+                                "Invalid gauge='{}' at line={}, col=2",
+                                gauge.unwrap_or_default(),
+                                line
+                            )))
+                        }
+                    };
+                    let tank = Vec::from_iter(vals).join(" ");
+                    if tank.is_empty() {
+                        return Err(StrErr(format!("No tank key at line={}", line)));
+                    }
+                    tanks.push(TankLoad::new(tank, gauge, level[1]));
+                }
+                Some(kind) => {
+                    return Err(StrErr(format!("Unknown kind='{}' at line={}", kind, line)))
+                }
+                None => {}
+            }
+        }
+        Ok(Self {
+            name,
+            lightship: lightship.ok_or_else(|| StrErr("No lightship found".to_owned()))?,
+            items,
+            tanks,
+        })
+    }
+}
+//
+//
+impl fmt::Display for LoadingCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# {} v{}", Self::MAGIC, Self::VERSION)?;
+        if !self.name.is_empty() {
+            writeln!(f, "# name: {}", self.name)?;
+        }
+        writeln!(f, "# columns: kind mass lcg tcg vcg fsm aft fore name")?;
+        writeln!(f, "# units: - t m m m t*m m m -")?;
+        let Weight {
+            mass,
+            center_of_gravity: [x, y, z],
+        } = self.lightship;
+        writeln!(f, "lightship {} {} {} {}", mass, x, y, z)?;
+        for item in &self.items {
+            let Weight {
+                mass,
+                center_of_gravity: [x, y, z],
+            } = item.weight;
+            let fsm = match item.free_surface_moment {
+                Some(fsm) => fsm.to_string(),
+                None => "-".to_owned(),
+            };
+            let extent = match item.extent {
+                Some([aft, fore]) => format!("{} {}", aft, fore),
+                None => "- -".to_owned(),
+            };
+            writeln!(
+                f,
+                "item {} {} {} {} {} {} {}",
+                mass, x, y, z, fsm, extent, item.name
+            )?;
+        }
+        for tank in &self.tanks {
+            let (gauge, level) = match tank.gauge {
+                Gauge::Sounding(sounding) => ("sounding", sounding),
+                Gauge::Ullage(ullage) => ("ullage", ullage),
+            };
+            writeln!(f, "tank {} {} {} {}", gauge, level, tank.density, tank.tank)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+//!
+//! Seakeeping: irregular waves, statistics of the waves and the ship motions.
+//
+pub mod irregular_wave;
+pub mod motion_statistics;
+pub mod spectrum;
+mod time_series;
+pub mod wave_statistics;
@@ -0,0 +1,164 @@
+#[cfg(test)]
+#[path = "../../tests/models/seakeeping/irregular_wave_test.rs"]
+mod tests;
+//
+use super::spectrum::Spectrum;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use sal_sync::services::entity::error::str_err::StrErr;
+use std::f64::consts::PI;
+///
+/// Number of the steps the frequency range is split into
+/// to find the maximum density and to integrate the spectrum.
+const GRID_STEPS: usize = 1000;
+///
+/// Maximum number of the random points tried per the frequency to be chosen.
+const MAX_TRIES: usize = 10000;
+///
+/// Harmonic component of the irregular wave: r * cos(σ * t + φ).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct WaveComponent {
+    ///
+    /// Orbit radius on the free surface r = √(2 * A), where A is the area
+    /// under the spectrum corresponding to the component, m.
+    pub amplitude: f64,
+    ///
+    /// Circular frequency σ, rad/s.
+    pub frequency: f64,
+    ///
+    /// Phase φ in [0, 2π), rad.
+    pub phase: f64,
+}
+///
+/// Surface elevation of the two-dimensional irregular wave of the given spectrum
+/// as the sum of the harmonic components.
+///
+/// The frequency range is split into the random intervals by the values chosen
+/// with the Neumann (rejection) method, so the intervals are shorter where the density is higher.
+/// The component of each interval takes the area under the spectrum
+/// and the frequency of the area centroid, both integrated by trapezoids.
+#[derive(Clone, PartialEq, Debug)]
+pub struct IrregularWave {
+    components: Vec<WaveComponent>,
+}
+//
+//
+impl IrregularWave {
+    ///
+    /// Creates a new instance of `count` components of `spectrum`
+    /// over the frequency `range` [min, max], rad/s.
+    ///
+    /// The random values are generated from `seed`, so the same seed gives the same wave.
+    ///
+    /// Returns an error if `count` is zero, `range` is invalid,
+    /// or the spectrum has no positive density within `range`.
+    pub fn new(
+        spectrum: &impl Spectrum,
+        [min, max]: [f64; 2],
+        count: usize,
+        seed: u64,
+    ) -> Result<Self, StrErr> {
+        if count == 0 {
+            return Err(StrErr("Zero number of the components".to_owned()));
+        }
+        if !(min.is_finite() && max.is_finite() && min < max) {
+            return Err(StrErr(format!(
+                "Invalid frequency range=[{}, {}]",
+                min, max
+            )));
+        }
+        let step = (max - min) / GRID_STEPS as f64;
+        let max_density = (0..=GRID_STEPS)
+            .map(|id| spectrum.density(min + step * id as f64))
+            .fold(0.0, f64::max);
+        if !(max_density.is_finite() && max_density > 0.0) {
+            return Err(StrErr(format!(
+                "Invalid maximum density={} within range=[{}, {}]",
+                max_density, min, max
+            )));
+        }
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut bounds = Vec::with_capacity(count + 1);
+        bounds.push(min);
+        let mut tries = 0;
+        while bounds.len() < count {
+            if tries >= MAX_TRIES * count {
+                return Err(StrErr(format!(
+                    "Only {} of {} frequencies are chosen in {} tries",
+                    bounds.len() - 1,
+                    count - 1,
+                    tries
+                )));
+            }
+            tries += 1;
+            let frequency = rng.gen_range(min..max);
+            if rng.gen::<f64>() * max_density < spectrum.density(frequency) {
+                bounds.push(frequency);
+            }
+        }
+        bounds.push(max);
+        bounds.sort_by(f64::total_cmp);
+        let components = bounds
+            .windows(2)
+            .map(|pair| {
+                let (area, frequency) = area_centroid(spectrum, pair[0], pair[1], step);
+                WaveComponent {
+                    amplitude: (2.0 * area).sqrt(),
+                    frequency,
+                    phase: rng.gen_range(0.0..2.0 * PI),
+                }
+            })
+            .collect();
+        Ok(Self { components })
+    }
+    ///
+    /// Returns the harmonic components sorted by frequency.
+    pub fn components(&self) -> &[WaveComponent] {
+        &self.components
+    }
+    ///
+    /// Returns the surface elevation at `time`, s, m.
+    pub fn elevation(&self, time: f64) -> f64 {
+        self.components
+            .iter()
+            .map(|component| {
+                component.amplitude * (component.frequency * time + component.phase).cos()
+            })
+            .sum()
+    }
+    ///
+    /// Returns `count` (time, elevation) samples starting at `start` with the time `step`, s.
+    pub fn series(&self, start: f64, step: f64, count: usize) -> Vec<(f64, f64)> {
+        Vec::from_iter((0..count).map(|id| {
+            let time = start + step * id as f64;
+            (time, self.elevation(time))
+        }))
+    }
+}
+///
+/// Returns the area under `spectrum` between `from` and `to` frequencies
+/// and the frequency of its centroid integrated by trapezoids not longer than `step`.
+///
+/// The centroid of zero area is the middle of the interval.
+fn area_centroid(spectrum: &impl Spectrum, from: f64, to: f64, step: f64) -> (f64, f64) {
+    let steps = ((to - from) / step).ceil().max(1.0) as usize;
+    let step = (to - from) / steps as f64;
+    let (mut area, mut moment) = (0.0, 0.0);
+    let mut l_density = spectrum.density(from);
+    for id in 0..steps {
+        let l_frequency = from + step * id as f64;
+        let r_density = spectrum.density(l_frequency + step);
+        let trapezoid = step * (l_density + r_density) / 2.0;
+        if trapezoid > 0.0 {
+            let centroid = l_frequency
+                + step * (l_density + 2.0 * r_density) / (3.0 * (l_density + r_density));
+            area += trapezoid;
+            moment += trapezoid * centroid;
+        }
+        l_density = r_density;
+    }
+    match area > 0.0 {
+        true => (area, moment / area),
+        false => (0.0, (from + to) / 2.0),
+    }
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+#[path = "../../tests/models/seakeeping/motion_statistics_test.rs"]
+mod tests;
+//
+use super::time_series::{self, Swings};
+use sal_sync::services::entity::error::str_err::StrErr;
+use std::fmt;
+///
+/// Statistical characteristics of the ship motion parameter
+/// (heel, trim, course, displacements or their derivatives) by its time series.
+///
+/// The amplitudes are the halves of the ranges between the neighbouring local maxima and minima,
+/// the half-periods are the time differences between them.
+/// The values are in the units of the parameter.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MotionStatistics {
+    ///
+    /// Amplitudes and half-periods.
+    amplitudes: Swings,
+    ///
+    /// Minimum value.
+    pub y_min: f64,
+    ///
+    /// Maximum value.
+    pub y_max: f64,
+    ///
+    /// Mean value.
+    pub y_av: f64,
+    ///
+    /// Maximum amplitude.
+    pub a_max: f64,
+    ///
+    /// Mean amplitude.
+    pub a_av: f64,
+    ///
+    /// Root-mean-square amplitude.
+    pub a_rms: f64,
+    ///
+    /// Average of the highest third amplitudes.
+    pub a_1_3: f64,
+    ///
+    /// Four standard deviations of the parameter.
+    pub a_m0: f64,
+    ///
+    /// Significant amplitude, equals to [MotionStatistics::a_1_3].
+    pub a_s: f64,
+    ///
+    /// Average period, s.
+    pub t_mean: f64,
+    ///
+    /// Significant period, average over the highest third amplitudes, s.
+    pub t_s: f64,
+    ///
+    /// Mean zero up-crossing period, s.
+    pub t_z: f64,
+}
+//
+//
+impl MotionStatistics {
+    ///
+    /// Calculates the statistics of (time, value) `samples` sorted by time.
+    ///
+    /// The mean value is taken as the zero for the up-crossings,
+    /// so the constant list or the course doesn't affect the period.
+    ///
+    /// Returns an error if the samples aren't finite or the times aren't strictly increasing,
+    /// or if the series contains no amplitude or less than two zero up-crossings.
+    pub fn new(samples: &[(f64, f64)]) -> Result<Self, StrErr> {
+        time_series::validate(samples)?;
+        let amplitudes = Swings::new(samples, 0.5)?;
+        let (mean, variance) = time_series::mean_variance(samples);
+        let (y_min, y_max) = samples
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(min, max), &(_, value)| {
+                (min.min(value), max.max(value))
+            });
+        Ok(Self {
+            y_min,
+            y_max,
+            y_av: mean,
+            a_max: amplitudes.max,
+            a_av: amplitudes.mean,
+            a_rms: amplitudes.rms,
+            a_1_3: amplitudes.highest_third,
+            a_m0: 4.0 * variance.sqrt(),
+            a_s: amplitudes.highest_third,
+            t_mean: amplitudes.t_mean,
+            t_s: amplitudes.t_s,
+            t_z: time_series::zero_up_crossing_period(samples, mean)?,
+            amplitudes,
+        })
+    }
+    ///
+    /// Returns the number of the amplitudes.
+    pub fn count(&self) -> usize {
+        self.amplitudes.values.len()
+    }
+    ///
+    /// Returns the amplitudes in the time order.
+    pub fn amplitudes(&self) -> &[f64] {
+        &self.amplitudes.values
+    }
+    ///
+    /// Returns the half-periods in the time order, s.
+    pub fn half_periods(&self) -> &[f64] {
+        &self.amplitudes.half_periods
+    }
+    ///
+    /// Returns the amplitude of `n`-% probability,
+    /// i. e. (100 - `n`)-th percentile of the amplitudes,
+    /// [None] if `n` is out of 0..=100.
+    pub fn amplitude_of_probability(&self, n: f64) -> Option<f64> {
+        self.amplitudes.of_probability(n)
+    }
+}
+//
+//
+impl fmt::Display for MotionStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "N_A={}", self.count())?;
+        for (name, value) in [
+            ("y_min", self.y_min),
+            ("y_max", self.y_max),
+            ("y_av", self.y_av),
+            ("A_max", self.a_max),
+            ("A_av", self.a_av),
+            ("A_rms", self.a_rms),
+            ("A_1/3", self.a_1_3),
+            ("A_m0", self.a_m0),
+            ("A_s", self.a_s),
+        ] {
+            writeln!(f, "{}={:.3}", name, value)?;
+        }
+        writeln!(f, "T_mean={:.3} s", self.t_mean)?;
+        writeln!(f, "T_s={:.3} s", self.t_s)?;
+        write!(f, "T_z={:.3} s", self.t_z)
+    }
+}
@@ -0,0 +1,144 @@
+pub mod wave_spectrum;
+pub mod welch;
+//
+use sal_sync::services::entity::error::str_err::StrErr;
+use std::f64::consts::PI;
+///
+/// Number of the steps the frequency range is split into to integrate the spectral moments.
+const MOMENT_STEPS: usize = 4000;
+///
+/// Spectral density of the waves S(σ), m²·s, over the circular frequency σ, rad/s.
+pub trait Spectrum {
+    ///
+    /// Returns the spectral density at the circular `frequency`.
+    fn density(&self, frequency: f64) -> f64;
+}
+//
+//
+impl<F: Fn(f64) -> f64> Spectrum for F {
+    fn density(&self, frequency: f64) -> f64 {
+        self(frequency)
+    }
+}
+///
+/// Spectrum given by the table of (frequency, density) points,
+/// linearly interpolated between them and zero outside.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TabulatedSpectrum {
+    points: Vec<(f64, f64)>,
+}
+//
+//
+impl TabulatedSpectrum {
+    ///
+    /// Creates a new instance from (frequency, density) `points` sorted by frequency.
+    ///
+    /// Returns an error if there are less than two points, the values aren't finite,
+    /// the frequencies aren't strictly increasing or the densities are negative.
+    pub fn new(points: impl IntoIterator<Item = (f64, f64)>) -> Result<Self, StrErr> {
+        let points = Vec::from_iter(points);
+        if points.len() < 2 {
+            return Err(StrErr(format!(
+                "At least two points required, got {}",
+                points.len()
+            )));
+        }
+        if let Some(point) = points.iter().find(|(frequency, density)| {
+            !frequency.is_finite() || !density.is_finite() || *density < 0.0
+        }) {
+            return Err(StrErr(format!("Invalid point={:?}", point)));
+        }
+        if let Some(pair) = points.windows(2).find(|pair| pair[1].0 <= pair[0].0) {
+            return Err(StrErr(format!(
+                "Frequencies aren't increasing: {} after {}",
+                pair[1].0, pair[0].0
+            )));
+        }
+        Ok(Self { points })
+    }
+    ///
+    /// Returns (frequency, density) points sorted by frequency.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+    ///
+    /// Returns [min, max] frequencies of the table.
+    pub fn range(&self) -> [f64; 2] {
+        [self.points[0].0, self.points[self.points.len() - 1].0]
+    }
+}
+//
+//
+impl Spectrum for TabulatedSpectrum {
+    fn density(&self, frequency: f64) -> f64 {
+        let id = self
+            .points
+            .partition_point(|(point_frequency, _)| *point_frequency < frequency);
+        match (
+            id.checked_sub(1).map(|id| self.points[id]),
+            self.points.get(id),
+        ) {
+            (_, Some(&(r_frequency, r_density))) if r_frequency == frequency => r_density,
+            (Some((l_frequency, l_density)), Some(&(r_frequency, r_density))) => {
+                l_density
+                    + (r_density - l_density) * (frequency - l_frequency)
+                        / (r_frequency - l_frequency)
+            }
+            _ => 0.0,
+        }
+    }
+}
+///
+/// Spectral moments m_n = ∫ σ^n * S(σ) dσ, m²·(rad/s)^n.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SpectralMoments {
+    pub m0: f64,
+    pub m1: f64,
+    pub m2: f64,
+    pub m4: f64,
+}
+//
+//
+impl SpectralMoments {
+    ///
+    /// Calculates the moments of `spectrum` over the frequency `range` [min, max], rad/s,
+    /// by Simpson's rule.
+    ///
+    /// The moments of the spectra with the σ⁻⁵ tail depend on the upper frequency,
+    /// m4 grows unbounded with it.
+    pub fn new(spectrum: &impl Spectrum, [min, max]: [f64; 2]) -> Self {
+        let step = (max - min) / MOMENT_STEPS as f64;
+        let mut moments = [0.0; 4];
+        for id in 0..=MOMENT_STEPS {
+            let frequency = min + step * id as f64;
+            let weight = match id {
+                0 => 1.0,
+                _ if id == MOMENT_STEPS => 1.0,
+                _ if id % 2 == 1 => 4.0,
+                _ => 2.0,
+            } * step
+                / 3.0;
+            let density = spectrum.density(frequency);
+            for (moment, order) in moments.iter_mut().zip([0, 1, 2, 4]) {
+                *moment += weight * frequency.powi(order) * density;
+            }
+        }
+        let [m0, m1, m2, m4] = moments;
+        Self { m0, m1, m2, m4 }
+    }
+    ///
+    /// Returns the significant wave height estimated from the spectrum 4 * √m0, m.
+    pub fn hm0(&self) -> f64 {
+        4.0 * self.m0.sqrt()
+    }
+    ///
+    /// Returns the mean zero up-crossing period 2π * √(m0 / m2), s.
+    pub fn tz(&self) -> f64 {
+        2.0 * PI * (self.m0 / self.m2).sqrt()
+    }
+    ///
+    /// Returns the mean period 2π * m0 / m1, s.
+    pub fn tm01(&self) -> f64 {
+        2.0 * PI * self.m0 / self.m1
+    }
+}
@@ -0,0 +1,174 @@
+#[cfg(test)]
+#[path = "../../../tests/models/seakeeping/spectrum/wave_spectrum_test.rs"]
+mod tests;
+//
+use super::Spectrum;
+use std::f64::consts::PI;
+///
+/// Ratio of the peak period to the zero up-crossing period of Pierson–Moskowitz spectrum.
+const PM_TP_TZ: f64 = 1.408;
+///
+/// Parametric spectrum of the wind waves.
+///
+/// The heights are in meters, the periods are in seconds.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum WaveSpectrum {
+    ///
+    /// Pierson–Moskowitz spectrum of the fully developed sea:
+    /// S(σ) = 5/16 * Hs² * σp⁴ / σ⁵ * exp(-5/4 * (σp / σ)⁴), σp = 2π / Tp.
+    PiersonMoskowitz {
+        significant_height: f64,
+        peak_period: f64,
+    },
+    ///
+    /// Bretschneider spectrum by the significant wave period Ts:
+    /// S(σ) = 0.1687 * Hs² * σs⁴ / σ⁵ * exp(-0.675 * (σs / σ)⁴), σs = 2π / Ts.
+    Bretschneider {
+        significant_height: f64,
+        significant_period: f64,
+    },
+    ///
+    /// ITTC two-parameter spectrum by the mean period T1:
+    /// S(σ) = 173 * Hs² / (T1⁴ * σ⁵) * exp(-691 / (T1⁴ * σ⁴)).
+    Ittc {
+        significant_height: f64,
+        mean_period: f64,
+    },
+    ///
+    /// JONSWAP spectrum, Pierson–Moskowitz spectrum of the same Hs and Tp multiplied by
+    /// (1 - 0.287 * ln(γ)) * γ^exp(-(σ - σp)² / (2 * s² * σp²)),
+    /// where s is `sigma_a` for σ ≤ σp and `sigma_b` for σ > σp.
+    Jonswap {
+        significant_height: f64,
+        peak_period: f64,
+        gamma: f64,
+        sigma_a: f64,
+        sigma_b: f64,
+    },
+}
+//
+//
+impl WaveSpectrum {
+    ///
+    /// Returns Pierson–Moskowitz spectrum by the significant height and the peak period.
+    pub fn pierson_moskowitz(significant_height: f64, peak_period: f64) -> Self {
+        Self::PiersonMoskowitz {
+            significant_height,
+            peak_period,
+        }
+    }
+    ///
+    /// Returns Pierson–Moskowitz spectrum by the significant height
+    /// and the zero up-crossing period, Tp = 1.408 * Tz.
+    pub fn pierson_moskowitz_tz(significant_height: f64, zero_crossing_period: f64) -> Self {
+        Self::pierson_moskowitz(significant_height, PM_TP_TZ * zero_crossing_period)
+    }
+    ///
+    /// Returns Bretschneider spectrum by the significant height and the significant period.
+    pub fn bretschneider(significant_height: f64, significant_period: f64) -> Self {
+        Self::Bretschneider {
+            significant_height,
+            significant_period,
+        }
+    }
+    ///
+    /// Returns ITTC two-parameter spectrum by the significant height and the mean period.
+    pub fn ittc(significant_height: f64, mean_period: f64) -> Self {
+        Self::Ittc {
+            significant_height,
+            mean_period,
+        }
+    }
+    ///
+    /// Returns JONSWAP spectrum by the significant height, the peak period
+    /// and the peak enhancement factor `gamma` with the standard widths 0.07 and 0.09.
+    pub fn jonswap(significant_height: f64, peak_period: f64, gamma: f64) -> Self {
+        Self::Jonswap {
+            significant_height,
+            peak_period,
+            gamma,
+            sigma_a: 0.07,
+            sigma_b: 0.09,
+        }
+    }
+    ///
+    /// Returns the circular frequency of the spectrum peak, rad/s.
+    pub fn peak_frequency(&self) -> f64 {
+        let (_, b) = self.coefficients();
+        (0.8 * b).powf(0.25)
+    }
+    ///
+    /// Returns the frequency range [0.5, 10] * σp, rad/s, which holds 99.9% of the wave energy.
+    pub fn range(&self) -> [f64; 2] {
+        let peak_frequency = self.peak_frequency();
+        [0.5 * peak_frequency, 10.0 * peak_frequency]
+    }
+    ///
+    /// Returns the coefficients (A, B) of Pierson–Moskowitz form A / σ⁵ * exp(-B / σ⁴).
+    fn coefficients(&self) -> (f64, f64) {
+        match *self {
+            Self::PiersonMoskowitz {
+                significant_height,
+                peak_period,
+            }
+            | Self::Jonswap {
+                significant_height,
+                peak_period,
+                ..
+            } => {
+                let frequency = (2.0 * PI / peak_period).powi(4);
+                (
+                    5.0 / 16.0 * significant_height.powi(2) * frequency,
+                    1.25 * frequency,
+                )
+            }
+            Self::Bretschneider {
+                significant_height,
+                significant_period,
+            } => {
+                let frequency = (2.0 * PI / significant_period).powi(4);
+                (
+                    0.1687 * significant_height.powi(2) * frequency,
+                    0.675 * frequency,
+                )
+            }
+            Self::Ittc {
+                significant_height,
+                mean_period,
+            } => (
+                173.0 * significant_height.powi(2) / mean_period.powi(4),
+                691.0 / mean_period.powi(4),
+            ),
+        }
+    }
+}
+//
+//
+impl Spectrum for WaveSpectrum {
+    fn density(&self, frequency: f64) -> f64 {
+        if frequency <= 0.0 {
+            return 0.0;
+        }
+        let (a, b) = self.coefficients();
+        let density = a / frequency.powi(5) * (-b / frequency.powi(4)).exp();
+        match *self {
+            Self::Jonswap {
+                gamma,
+                sigma_a,
+                sigma_b,
+                ..
+            } => {
+                let peak_frequency = self.peak_frequency();
+                let sigma = match frequency <= peak_frequency {
+                    true => sigma_a,
+                    false => sigma_b,
+                };
+                let exponent = (-(frequency - peak_frequency).powi(2)
+                    / (2.0 * sigma.powi(2) * peak_frequency.powi(2)))
+                .exp();
+                (1.0 - 0.287 * gamma.ln()) * density * gamma.powf(exponent)
+            }
+            _ => density,
+        }
+    }
+}
@@ -0,0 +1,268 @@
+#[cfg(test)]
+#[path = "../../../tests/models/seakeeping/spectrum/welch_test.rs"]
+mod tests;
+//
+use super::{SpectralMoments, TabulatedSpectrum};
+use sal_sync::services::entity::error::str_err::StrErr;
+use std::f64::consts::PI;
+///
+/// Window applied to each segment before the transform.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Window {
+    Rectangular,
+    Hann,
+    Hamming,
+}
+//
+//
+impl Window {
+    ///
+    /// Returns the weight of the sample `id` of the segment of `len` samples.
+    fn weight(&self, id: usize, len: usize) -> f64 {
+        let phase = 2.0 * PI * id as f64 / len as f64;
+        match self {
+            Self::Rectangular => 1.0,
+            Self::Hann => 0.5 - 0.5 * phase.cos(),
+            Self::Hamming => 0.54 - 0.46 * phase.cos(),
+        }
+    }
+}
+///
+/// Trend removed from each segment before the transform.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Detrend {
+    None,
+    ///
+    /// The mean value is removed.
+    Mean,
+    ///
+    /// The least squares line is removed.
+    Linear,
+}
+///
+/// Spectrum estimated from the time series.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SpectralEstimate {
+    ///
+    /// One-sided spectral density over the circular frequency, (units)²·s,
+    /// from zero up to the Nyquist frequency.
+    pub spectrum: TabulatedSpectrum,
+    ///
+    /// Moments of the spectrum over its whole range.
+    pub moments: SpectralMoments,
+    ///
+    /// Period of the maximum density, s.
+    pub peak_period: f64,
+}
+///
+/// Welch's estimation of the power spectral density of the uniformly sampled time series:
+/// the series is split into the overlapping segments, the periodograms of the windowed
+/// and detrended segments are averaged.
+///
+/// The periodogram is the estimation with the only segment of the whole series.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Welch {
+    segment: usize,
+    overlap: usize,
+    window: Window,
+    detrend: Detrend,
+}
+//
+//
+impl Welch {
+    ///
+    /// Creates a new instance with the segments of `segment` samples
+    /// overlapping by a half, Hann window and the mean removed.
+    ///
+    /// The transform is fast if `segment` is a power of two.
+    pub fn new(segment: usize) -> Self {
+        Self {
+            segment,
+            overlap: segment / 2,
+            window: Window::Hann,
+            detrend: Detrend::Mean,
+        }
+    }
+    ///
+    /// Returns the estimation with the segments overlapping by `overlap` samples.
+    pub fn with_overlap(self, overlap: usize) -> Self {
+        Self { overlap, ..self }
+    }
+    ///
+    /// Returns the estimation with the segments weighted by `window`.
+    pub fn with_window(self, window: Window) -> Self {
+        Self { window, ..self }
+    }
+    ///
+    /// Returns the estimation with `detrend` removed from the segments.
+    pub fn with_detrend(self, detrend: Detrend) -> Self {
+        Self { detrend, ..self }
+    }
+    ///
+    /// Estimates the spectrum of (time, value) `samples` taken with the constant time step.
+    ///
+    /// Returns an error if the segment is shorter than two samples or isn't longer than
+    /// the overlap, the series is shorter than the segment, the time step isn't constant,
+    /// or the values aren't finite.
+    pub fn estimate(&self, samples: &[(f64, f64)]) -> Result<SpectralEstimate, StrErr> {
+        if self.segment < 2 || self.overlap >= self.segment {
+            return Err(StrErr(format!(
+                "Invalid segment={} with overlap={}",
+                self.segment, self.overlap
+            )));
+        }
+        if samples.len() < self.segment {
+            return Err(StrErr(format!(
+                "Series of {} samples is shorter than segment={}",
+                samples.len(),
+                self.segment
+            )));
+        }
+        let step = time_step(samples)?;
+        let weights =
+            Vec::from_iter((0..self.segment).map(|id| self.window.weight(id, self.segment)));
+        let weights_power: f64 = weights.iter().map(|weight| weight * weight).sum();
+        let mut density = vec![0.0; self.segment / 2 + 1];
+        let mut segments = 0;
+        for start in (0..=samples.len() - self.segment).step_by(self.segment - self.overlap) {
+            let values = detrend(&samples[start..start + self.segment], self.detrend);
+            let values = Vec::from_iter(
+                values
+                    .iter()
+                    .zip(&weights)
+                    .map(|(value, weight)| value * weight),
+            );
+            for (density, power) in density.iter_mut().zip(power(&values)) {
+                *density += power;
+            }
+            segments += 1;
+        }
+        // one-sided density over the circular frequency:
+        // the bins but zero and Nyquist get the power of the negative frequencies
+        let scale = step / (weights_power * segments as f64 * 2.0 * PI);
+        let frequency_step = 2.0 * PI / (self.segment as f64 * step);
+        let nyquist = match self.segment % 2 {
+            0 => Some(self.segment / 2),
+            _ => None,
+        };
+        let points = Vec::from_iter(density.iter().enumerate().map(|(id, power)| {
+            let sides = match id == 0 || Some(id) == nyquist {
+                true => 1.0,
+                false => 2.0,
+            };
+            (id as f64 * frequency_step, sides * scale * power)
+        }));
+        let (peak_frequency, peak_density) =
+            points
+                .iter()
+                .skip(1)
+                .copied()
+                .fold((0.0, 0.0), |peak, point| match point.1 > peak.1 {
+                    true => point,
+                    false => peak,
+                });
+        if peak_density <= 0.0 {
+            return Err(StrErr(format!(
+                "No power in the series of {} samples",
+                samples.len()
+            )));
+        }
+        let spectrum = TabulatedSpectrum::new(points)?;
+        Ok(SpectralEstimate {
+            moments: SpectralMoments::new(&spectrum, spectrum.range()),
+            peak_period: 2.0 * PI / peak_frequency,
+            spectrum,
+        })
+    }
+}
+///
+/// Returns the constant time step of `samples`,
+/// an error if it isn't constant or the samples aren't finite.
+fn time_step(samples: &[(f64, f64)]) -> Result<f64, StrErr> {
+    let (first, last) = (samples[0].0, samples[samples.len() - 1].0);
+    let step = (last - first) / (samples.len() - 1) as f64;
+    if !(step.is_finite() && step > 0.0) {
+        return Err(StrErr(format!("Invalid time step={}", step)));
+    }
+    for (id, (time, value)) in samples.iter().enumerate() {
+        if !value.is_finite() || (time - first - step * id as f64).abs() > 1e-6 * step {
+            return Err(StrErr(format!(
+                "Invalid sample=({}, {}) at id={} with time step={}",
+                time, value, id, step
+            )));
+        }
+    }
+    Ok(step)
+}
+///
+/// Returns the values of `samples` with `detrend` removed.
+fn detrend(samples: &[(f64, f64)], detrend: Detrend) -> Vec<f64> {
+    let values = samples.iter().map(|(_, value)| *value);
+    let count = samples.len() as f64;
+    let mean = samples.iter().map(|(_, value)| value).sum::<f64>() / count;
+    match detrend {
+        Detrend::None => values.collect(),
+        Detrend::Mean => values.map(|value| value - mean).collect(),
+        Detrend::Linear => {
+            // the line over the sample ids, which mean is (count - 1) / 2
+            let id_mean = (count - 1.0) / 2.0;
+            let (covariance, variance) = samples.iter().enumerate().fold(
+                (0.0, 0.0),
+                |(covariance, variance), (id, (_, value))| {
+                    let id = id as f64 - id_mean;
+                    (covariance + id * (value - mean), variance + id * id)
+                },
+            );
+            let slope = covariance / variance;
+            values
+                .enumerate()
+                .map(|(id, value)| value - mean - slope * (id as f64 - id_mean))
+                .collect()
+        }
+    }
+}
+///
+/// Returns the squared magnitudes of the discrete Fourier transform of `values`
+/// for the frequencies from zero up to the Nyquist one.
+///
+/// The transform is fast if the length of `values` is a power of two.
+fn power(values: &[f64]) -> Vec<f64> {
+    let len = values.len();
+    let (mut re, mut im) = (values.to_vec(), vec![0.0; len]);
+    if len.is_power_of_two() && len > 1 {
+        let shift = usize::BITS - len.trailing_zeros();
+        for id in 0..len {
+            let reversed = id.reverse_bits() >> shift;
+            if id < reversed {
+                re.swap(id, reversed);
+            }
+        }
+        let mut size = 2;
+        while size <= len {
+            let angle = -2.0 * PI / size as f64;
+            for start in (0..len).step_by(size) {
+                for id in 0..size / 2 {
+                    let (sin, cos) = (angle * id as f64).sin_cos();
+                    let (l, r) = (start + id, start + id + size / 2);
+                    let (r_re, r_im) = (re[r] * cos - im[r] * sin, re[r] * sin + im[r] * cos);
+                    (re[r], im[r]) = (re[l] - r_re, im[l] - r_im);
+                    (re[l], im[l]) = (re[l] + r_re, im[l] + r_im);
+                }
+            }
+            size *= 2;
+        }
+    } else {
+        for frequency in 0..=len / 2 {
+            (re[frequency], im[frequency]) =
+                values
+                    .iter()
+                    .enumerate()
+                    .fold((0.0, 0.0), |(re, im), (id, value)| {
+                        let (sin, cos) =
+                            (-2.0 * PI * (frequency * id % len) as f64 / len as f64).sin_cos();
+                        (re + value * cos, im + value * sin)
+                    });
+        }
+    }
+    Vec::from_iter((0..=len / 2).map(|id| re[id] * re[id] + im[id] * im[id]))
+}
@@ -0,0 +1,178 @@
+#[cfg(test)]
+#[path = "../../tests/models/seakeeping/time_series_test.rs"]
+mod tests;
+//
+use sal_sync::services::entity::error::str_err::StrErr;
+///
+/// Swings between the neighbouring local maxima and minima of the time series
+/// and their statistics.
+#[derive(Clone, PartialEq, Debug)]
+pub(super) struct Swings {
+    ///
+    /// Scaled ranges between the neighbouring extrema in the time order.
+    pub(super) values: Vec<f64>,
+    ///
+    /// Time differences between the neighbouring extrema in the time order.
+    pub(super) half_periods: Vec<f64>,
+    pub(super) max: f64,
+    pub(super) mean: f64,
+    pub(super) rms: f64,
+    ///
+    /// Average of the highest third values.
+    pub(super) highest_third: f64,
+    ///
+    /// Average period.
+    pub(super) t_mean: f64,
+    ///
+    /// Average period of the highest third values.
+    pub(super) t_s: f64,
+}
+//
+//
+impl Swings {
+    ///
+    /// Creates a new instance from (time, value) `samples`,
+    /// the ranges between the extrema are multiplied by `scale`.
+    ///
+    /// Returns an error if there are less than two extrema.
+    pub(super) fn new(samples: &[(f64, f64)], scale: f64) -> Result<Self, StrErr> {
+        let (values, half_periods): (Vec<_>, Vec<_>) = extrema(samples)
+            .windows(2)
+            .map(|pair| {
+                let [(l_time, l_value), (r_time, r_value)] = [pair[0], pair[1]];
+                (scale * (r_value - l_value).abs(), r_time - l_time)
+            })
+            .unzip();
+        if values.is_empty() {
+            return Err(StrErr(format!(
+                "No swing between the extrema in {} samples",
+                samples.len()
+            )));
+        }
+        let count = values.len() as f64;
+        let highest = highest_third(&values);
+        let highest_count = highest.len() as f64;
+        Ok(Self {
+            max: values.iter().copied().fold(f64::MIN, f64::max),
+            mean: values.iter().sum::<f64>() / count,
+            rms: (values.iter().map(|value| value * value).sum::<f64>() / count).sqrt(),
+            highest_third: highest.iter().map(|&id| values[id]).sum::<f64>() / highest_count,
+            t_mean: 2.0 * half_periods.iter().sum::<f64>() / count,
+            t_s: 2.0 * highest.iter().map(|&id| half_periods[id]).sum::<f64>() / highest_count,
+            values,
+            half_periods,
+        })
+    }
+    ///
+    /// Returns the value of `n`-% probability, i. e. (100 - `n`)-th percentile of the values,
+    /// [None] if `n` is out of 0..=100.
+    pub(super) fn of_probability(&self, n: f64) -> Option<f64> {
+        percentile(&self.values, 100.0 - n)
+    }
+}
+///
+/// Returns an error if (time, value) `samples` aren't finite
+/// or the times aren't strictly increasing.
+pub(super) fn validate(samples: &[(f64, f64)]) -> Result<(), StrErr> {
+    if let Some(id) = samples
+        .iter()
+        .position(|(time, value)| !time.is_finite() || !value.is_finite())
+    {
+        return Err(StrErr(format!(
+            "Invalid sample={:?} at id={}",
+            samples[id], id
+        )));
+    }
+    if let Some(id) = samples.windows(2).position(|pair| pair[1].0 <= pair[0].0) {
+        return Err(StrErr(format!(
+            "Times aren't increasing at id={}: {} after {}",
+            id + 1,
+            samples[id + 1].0,
+            samples[id].0
+        )));
+    }
+    Ok(())
+}
+///
+/// Returns (time, value) of the local maxima and minima of `samples` in the time order,
+/// so the maxima and the minima alternate.
+///
+/// The time of the flat extremum is the middle of the flat part.
+/// The first and the last samples aren't taken as the extrema.
+pub(super) fn extrema(samples: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut extrema = vec![];
+    // sign of the last non-zero difference and the first sample after it
+    let mut direction = 0.0;
+    let mut start = 0;
+    for id in 1..samples.len() {
+        let diff = samples[id].1 - samples[id - 1].1;
+        if diff == 0.0 {
+            continue;
+        }
+        if direction != 0.0 && diff.signum() != direction {
+            let time = (samples[start].0 + samples[id - 1].0) / 2.0;
+            extrema.push((time, samples[start].1));
+        }
+        direction = diff.signum();
+        start = id;
+    }
+    extrema
+}
+///
+/// Returns (mean, variance) of the values of `samples`.
+pub(super) fn mean_variance(samples: &[(f64, f64)]) -> (f64, f64) {
+    let count = samples.len() as f64;
+    let mean = samples.iter().map(|(_, value)| value).sum::<f64>() / count;
+    let variance = samples
+        .iter()
+        .map(|(_, value)| (value - mean).powi(2))
+        .sum::<f64>()
+        / count;
+    (mean, variance)
+}
+///
+/// Returns the mean period between the up-crossings of the `level` by `samples`.
+///
+/// The time of the up-crossing is linearly interpolated between the samples.
+pub(super) fn zero_up_crossing_period(samples: &[(f64, f64)], level: f64) -> Result<f64, StrErr> {
+    let crossings = Vec::from_iter(samples.windows(2).filter_map(|pair| {
+        let [(l_time, l_value), (r_time, r_value)] = [pair[0], pair[1]];
+        (l_value < level && r_value >= level)
+            .then(|| l_time + (r_time - l_time) * (level - l_value) / (r_value - l_value))
+    }));
+    match (crossings.first(), crossings.last()) {
+        (Some(first), Some(last)) if crossings.len() > 1 => {
+            Ok((last - first) / (crossings.len() - 1) as f64)
+        }
+        _ => Err(StrErr(format!(
+            "Less than two up-crossings of level={} in {} samples",
+            level,
+            samples.len()
+        ))),
+    }
+}
+///
+/// Returns the ids of the highest third of `values`,
+/// at least one id if `values` isn't empty.
+fn highest_third(values: &[f64]) -> Vec<usize> {
+    let mut ids = Vec::from_iter(0..values.len());
+    ids.sort_by(|&l_id, &r_id| values[r_id].total_cmp(&values[l_id]));
+    ids.truncate((values.len() / 3).max(1));
+    ids
+}
+///
+/// Returns `p`-th percentile of `values` linearly interpolated between the closest ranks,
+/// [None] if `values` is empty or `p` is out of 0..=100.
+fn percentile(values: &[f64], p: f64) -> Option<f64> {
+    if values.is_empty() || !(0.0..=100.0).contains(&p) {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let (id, fract) = (rank.floor() as usize, rank.fract());
+    match sorted.get(id + 1) {
+        Some(next) => Some(sorted[id] + (next - sorted[id]) * fract),
+        None => Some(sorted[id]),
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+#[path = "../../tests/models/seakeeping/wave_statistics_test.rs"]
+mod tests;
+//
+use super::time_series::{self, Swings};
+use sal_sync::services::entity::error::str_err::StrErr;
+use std::fmt;
+///
+/// Statistical characteristics of the waves by the time series
+/// of the surface elevation at the point.
+///
+/// The wave heights are the elevation differences between the neighbouring crest and trough,
+/// the half-periods are the time differences between them.
+#[derive(Clone, PartialEq, Debug)]
+pub struct WaveStatistics {
+    ///
+    /// Wave heights and half-periods.
+    heights: Swings,
+    ///
+    /// Largest wave height in the sample, m.
+    pub h_max: f64,
+    ///
+    /// Mean wave height, m.
+    pub h_av: f64,
+    ///
+    /// Root-mean-square wave height, m.
+    pub h_rms: f64,
+    ///
+    /// Average of the highest third waves, m.
+    pub h_1_3: f64,
+    ///
+    /// Four standard deviations of the surface elevation, m.
+    pub h_m0: f64,
+    ///
+    /// Significant wave height, equals to [WaveStatistics::h_1_3], m.
+    pub h_s: f64,
+    ///
+    /// Average period, s.
+    pub t_mean: f64,
+    ///
+    /// Significant wave period, average over the highest third waves, s.
+    pub t_s: f64,
+    ///
+    /// Mean zero up-crossing period, s.
+    pub t_z: f64,
+}
+//
+//
+impl WaveStatistics {
+    ///
+    /// Calculates the statistics of (time, elevation) `samples` sorted by time.
+    ///
+    /// The mean elevation is taken as the undisturbed surface for the zero up-crossings.
+    ///
+    /// Returns an error if the samples aren't finite or the times aren't strictly increasing,
+    /// or if the series contains no wave height or less than two zero up-crossings.
+    pub fn new(samples: &[(f64, f64)]) -> Result<Self, StrErr> {
+        time_series::validate(samples)?;
+        let heights = Swings::new(samples, 1.0)?;
+        let (mean, variance) = time_series::mean_variance(samples);
+        Ok(Self {
+            h_max: heights.max,
+            h_av: heights.mean,
+            h_rms: heights.rms,
+            h_1_3: heights.highest_third,
+            h_m0: 4.0 * variance.sqrt(),
+            h_s: heights.highest_third,
+            t_mean: heights.t_mean,
+            t_s: heights.t_s,
+            t_z: time_series::zero_up_crossing_period(samples, mean)?,
+            heights,
+        })
+    }
+    ///
+    /// Returns the number of the wave heights.
+    pub fn count(&self) -> usize {
+        self.heights.values.len()
+    }
+    ///
+    /// Returns the wave heights in the time order, m.
+    pub fn heights(&self) -> &[f64] {
+        &self.heights.values
+    }
+    ///
+    /// Returns the half-periods of the waves in the time order, s.
+    pub fn half_periods(&self) -> &[f64] {
+        &self.heights.half_periods
+    }
+    ///
+    /// Returns the wave height of `n`-% probability, m,
+    /// i. e. (100 - `n`)-th percentile of the wave heights,
+    /// [None] if `n` is out of 0..=100.
+    pub fn height_of_probability(&self, n: f64) -> Option<f64> {
+        self.heights.of_probability(n)
+    }
+}
+//
+//
+impl fmt::Display for WaveStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "N_H={}", self.count())?;
+        for (name, value, unit) in [
+            ("H_max", self.h_max, "m"),
+            ("H_av", self.h_av, "m"),
+            ("H_rms", self.h_rms, "m"),
+            ("H_1/3", self.h_1_3, "m"),
+            ("H_m0", self.h_m0, "m"),
+            ("H_s", self.h_s, "m"),
+            ("T_mean", self.t_mean, "s"),
+            ("T_s", self.t_s, "s"),
+        ] {
+            writeln!(f, "{}={:.3} {}", name, value, unit)?;
+        }
+        write!(f, "T_z={:.3} s", self.t_z)
+    }
+}
@@ -1,14 +1,39 @@
 //!
 //! The representation of the ship in terms of its 3D elements.
 //
+pub mod damage;
+pub mod equilibrium;
 pub mod local_cache;
 mod model_tree;
 pub mod relative_position;
 pub mod ship_model_conf;
+#[cfg(test)]
+#[path = "../tests/models/ship_model_test.rs"]
+mod tests;
+mod waterline;
 //
+use crate::{
+    common::cache::error::CacheError,
+    models::{
+        loading_condition::{DeadweightItem, LoadingCondition, Weight},
+        stability::gz_curve::GzCurve,
+        strength::longitudinal_strength::{
+            longitudinal_strength_conf::LongitudinalStrengthConf, LongitudinalStrength,
+            StrengthReport,
+        },
+    },
+};
+use damage::{damage_conf::DamageConf, DamageCase, DamageStability, LostBuoyancy};
+use equilibrium::{equilibrium_conf::EquilibriumConf, Equilibrium, FloatingPosition};
 use indexmap::{IndexMap, IndexSet};
 use local_cache::{
-    cache_key::CacheKey, floating_position_cache::FloatingPositionCache, LocalCache,
+    cache_key::CacheKey,
+    cross_curves_cache::CrossCurvesCache,
+    floating_position_cache::FloatingPositionCache,
+    hydrostatics_cache::HydrostaticsCache,
+    progress::Progress,
+    tank_cache::{Gauge, TankCache, TankFilling},
+    LocalCache,
 };
 use model_tree::ModelTree;
 use relative_position::RelativePostion;
@@ -21,8 +46,9 @@ use sal_3dlib::{
     },
 };
 use sal_sync::services::entity::{dbg_id::DbgId, error::str_err::StrErr};
-use ship_model_conf::ShipModelConf;
+use ship_model_conf::{ShipModelConf, StaleCachePolicy};
 use std::sync::Arc;
+use waterline::{Submerged, Waterline};
 ///
 /// Ship object represented as a collection of its 3D elements all with attributes of type `A`.
 ///
@@ -35,31 +61,130 @@ pub struct ShipModel<A> {
     ///
     /// Provides a number of calculations:
     /// - Floating position (see [FloatingPositionCache]).
+    /// - Hydrostatics (see [HydrostaticsCache]).
+    /// - Cross curves of stability (see [CrossCurvesCache]).
+    /// - Calibration of each tank (see [TankCache]).
     caches: IndexMap<CacheKey, Box<dyn LocalCache>>,
+    ///
+    /// Progress of the last calculation of each cache (see [ShipModel::progress]).
+    progress: IndexMap<CacheKey, Arc<Progress>>,
+    ///
+    /// Applied to stale caches (see [ShipModel::check_caches]).
+    stale_cache_policy: StaleCachePolicy,
+    ///
+    /// Keys of the model elements making the hull, all elements if empty,
+    /// used to refine the equilibrium as the floating position cache is calculated for them
+    /// (see [ShipModelConf::floating_position_cache_conf]).
+    hull_keys: Vec<String>,
+    ///
+    /// Waterline initial position of the floating position cache,
+    /// used to refine the equilibrium (see [ShipModel::equilibrium]).
+    waterline_position: [f64; 3],
+    ///
+    /// Used to find the floating position (see [ShipModel::equilibrium]).
+    equilibrium_conf: EquilibriumConf,
+    ///
+    /// Ullage reference and longitudinal extent of each tank by its key,
+    /// the index is the one of [CacheKey::Tank].
+    tanks: IndexMap<String, (f64, Option<[f64; 2]>)>,
+    ///
+    /// Point the KN levers of the cross curves are measured from,
+    /// used to get KG of the loading condition (see [ShipModel::loading_gz_curve]).
+    reference_point: [f64; 3],
+    ///
+    /// Damage cases and steps of the residual GZ curve (see [ShipModel::damage_stability]).
+    damage_conf: DamageConf,
+    ///
+    /// Stations, lightship distribution, and limits (see [ShipModel::longitudinal_strength]).
+    strength_conf: LongitudinalStrengthConf,
 }
 //
 //
 impl<A: Clone + Send + 'static> ShipModel<A> {
     ///
     /// Creates a new instance.
-    pub fn new(parent: &DbgId, conf: ShipModelConf) -> Self {
+    ///
+    /// # Errors
+    /// The error returns if some tank is configured more than once.
+    pub fn new(parent: &DbgId, conf: ShipModelConf) -> Result<Self, StrErr> {
         let dbgid = DbgId::with_parent(parent, "ShipModel");
         let model_tree = ModelTree::new(&dbgid, conf.model_path);
+        //
+        // the model file is hashed once for all the caches
+        let model_hash = model_tree.hash();
         let mut ship_model = Self {
             caches: IndexMap::new(),
+            progress: IndexMap::new(),
             model_tree: model_tree.clone(),
             dbgid: dbgid.clone(),
+            stale_cache_policy: conf.stale_cache_policy,
+            hull_keys: conf.floating_position_cache_conf.model_keys.clone(),
+            waterline_position: conf.floating_position_cache_conf.waterline_position,
+            equilibrium_conf: conf.equilibrium_conf,
+            tanks: IndexMap::new(),
+            reference_point: conf.cross_curves_cache_conf.reference_point,
+            damage_conf: conf.damage_conf,
+            strength_conf: conf.strength_conf,
         };
         ship_model.caches.insert(
             CacheKey::FloatingPostion,
             Box::new(FloatingPositionCache::new(
                 &dbgid,
-                model_tree,
-                conf.cache_dir,
+                model_tree.clone(),
+                model_hash.clone(),
+                &conf.cache_dir,
                 conf.floating_position_cache_conf,
             )),
         );
-        ship_model
+        ship_model.caches.insert(
+            CacheKey::Hydrostatics,
+            Box::new(HydrostaticsCache::new(
+                &dbgid,
+                model_tree.clone(),
+                model_hash.clone(),
+                &conf.cache_dir,
+                conf.hydrostatics_cache_conf,
+            )),
+        );
+        ship_model.caches.insert(
+            CacheKey::CrossCurves,
+            Box::new(CrossCurvesCache::new(
+                &dbgid,
+                model_tree.clone(),
+                model_hash.clone(),
+                &conf.cache_dir,
+                conf.cross_curves_cache_conf,
+            )),
+        );
+        for tank_cache_conf in conf.tank_cache_confs {
+            if ship_model.tanks.contains_key(&tank_cache_conf.tank) {
+                return Err(StrErr(format!(
+                    "{}.new | Duplicated tank='{}'",
+                    dbgid, tank_cache_conf.tank
+                )));
+            }
+            let (index, _) = ship_model.tanks.insert_full(
+                tank_cache_conf.tank.clone(),
+                (tank_cache_conf.ullage_reference, tank_cache_conf.extent),
+            );
+            ship_model.caches.insert(
+                CacheKey::Tank(index),
+                Box::new(TankCache::new(
+                    &dbgid,
+                    model_tree.clone(),
+                    model_hash.clone(),
+                    &conf.cache_dir,
+                    tank_cache_conf,
+                )),
+            );
+        }
+        ship_model.progress = IndexMap::from_iter(
+            ship_model
+                .caches
+                .keys()
+                .map(|cache_key| (*cache_key, Arc::default())),
+        );
+        Ok(ship_model)
     }
     ///
     /// Returns model elements touched by `waterline` and filtered by [RelativePostion].
@@ -136,6 +261,34 @@ impl<A: Clone + Send + 'static> ShipModel<A> {
             })
     }
     ///
+    /// Returns progress handles of the cache calculations (see [ShipModel::update_caches]).
+    ///
+    /// The handles are shared with the workers, so they keep updating after the call.
+    ///
+    /// # Examples
+    /// ```
+    /// fn watch(ship_model: &mut ShipModel<()>) {
+    ///     let progress = ship_model.progress();
+    ///     let observer = std::thread::spawn(move || loop {
+    ///         let reports = Vec::from_iter(progress.values().map(|progress| progress.report()));
+    ///         for report in &reports {
+    ///             log::info!("Cache calculation: {}", report);
+    ///         }
+    ///         if reports.iter().all(|report| report.is_finished() || report.last_error.is_some()) {
+    ///             break;
+    ///         }
+    ///         std::thread::sleep(std::time::Duration::from_secs(10));
+    ///     });
+    ///     if let Err(why) = ship_model.update_caches(&[]) {
+    ///         log::error!("Failed to update ship model caches: {}", why);
+    ///     }
+    ///     observer.join().unwrap();
+    /// }
+    /// ```
+    pub fn progress(&self) -> IndexMap<CacheKey, Arc<Progress>> {
+        self.progress.clone()
+    }
+    ///
     /// Generates and reload the internal caches.
     ///
     /// The field `caches` contains cache keys to update.
@@ -143,6 +296,8 @@ impl<A: Clone + Send + 'static> ShipModel<A> {
     ///
     /// Note that it may take some time to complete
     /// due to the size of datasets and algorithm complexity.
+    /// Meanwhile, the progress of each cache can be observed from another thread
+    /// by the handles taken from [ShipModel::progress] beforehand.
     ///
     /// # Errors
     /// Internally it creates worker threads while building.
@@ -169,7 +324,7 @@ impl<A: Clone + Send + 'static> ShipModel<A> {
             for (cache_key, cache) in &self.caches {
                 if calculate_all || caches.contains(&cache_key) {
                     cache
-                        .calculate(Arc::default())
+                        .calculate(Arc::default(), self.progress[cache_key].clone())
                         .map(|workers| handlers.push((*cache_key, workers)))?;
                 }
             }
@@ -211,4 +366,705 @@ impl<A: Clone + Send + 'static> ShipModel<A> {
         }
         Ok(())
     }
+    ///
+    /// Returns keys of the caches, which are not ready for use, with the reason.
+    ///
+    /// A cache is not ready if its dataset is missing, broken, or stale,
+    /// i. e. calculated for another model or configuration (see [LocalCache::check]).
+    pub fn stale_caches(&self) -> Vec<(CacheKey, CacheError)> {
+        Vec::from_iter(
+            self.caches
+                .iter()
+                .filter_map(|(cache_key, cache)| cache.check().err().map(|err| (*cache_key, err))),
+        )
+    }
+    ///
+    /// Checks all caches are ready for use and handles the stale ones according to [StaleCachePolicy].
+    ///
+    /// It's supposed to be called at startup, before the caches are queried.
+    ///
+    /// # Errors
+    /// If the policy is [StaleCachePolicy::Report], the error lists all stale caches.
+    /// Otherwise, the error of rebuilding returns (see [ShipModel::update_caches]).
+    ///
+    /// # Examples
+    /// ```
+    /// fn startup(ship_model: &mut ShipModel<()>) {
+    ///     if let Err(why) = ship_model.check_caches() {
+    ///         log::error!("Ship model caches are not ready: {}", why);
+    ///     }
+    /// }
+    /// ```
+    pub fn check_caches(&mut self) -> Result<(), StrErr> {
+        let dbgid = DbgId(format!("{}.check_caches", self.dbgid));
+        let stale = self.stale_caches();
+        if stale.is_empty() {
+            return Ok(());
+        }
+        for (cache_key, err) in &stale {
+            log::warn!("{} | Stale cache={:?}: {}", dbgid, cache_key, err);
+        }
+        match self.stale_cache_policy {
+            StaleCachePolicy::Report => Err(StrErr(format!(
+                "{} | Stale caches: {}",
+                dbgid,
+                Vec::from_iter(
+                    stale
+                        .iter()
+                        .map(|(cache_key, err)| format!("{:?} ({})", cache_key, err))
+                )
+                .join(", ")
+            ))),
+            StaleCachePolicy::Rebuild => {
+                let cache_keys = Vec::from_iter(stale.iter().map(|(cache_key, _)| cache_key));
+                log::info!("{} | Rebuilding caches: {:?}", dbgid, cache_keys);
+                self.update_caches(&cache_keys)
+            }
+        }
+    }
+    ///
+    /// Returns the floating position of the ship of `mass` (in tons) with the center of gravity
+    /// at `center_of_gravity` (LCG, TCG, and VCG in the model coordinate system)
+    /// floating in water of `water_density` (in t/m3).
+    ///
+    /// The position is the heel, trim, and draught (see [FloatingPositionCache]),
+    /// at which the submerged volume balances the mass and the center of buoyancy
+    /// lies on the normal to the waterplane passing through the center of gravity.
+    ///
+    /// It's solved by the floating position cache starting from the upright position
+    /// at the draught of the required volume (see [Equilibrium] for details).
+    /// If [EquilibriumConf::refine] is set, the solution is refined
+    /// by calculating the submerged part of the hull directly, which is much slower.
+    ///
+    /// # Errors
+    /// The error returns if `mass` or `water_density` isn't positive,
+    /// the cache fails to approximate the values (e. g. the position is out of the cache range),
+    /// or the solution isn't found (see [EquilibriumConf::max_iterations]).
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(ship_model: &ShipModel<()>) -> Result<(), StrErr> {
+    ///     // 1000 t in sea water, the center of gravity is a bit off the center plane
+    ///     let position = ship_model.equilibrium(1000.0, 1.025, [50.0, 0.1, 4.5])?;
+    ///     log::info!("heel={}, trim={}, draught={}", position.heel, position.trim, position.draught);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn equilibrium(
+        &self,
+        mass: f64,
+        water_density: f64,
+        center_of_gravity: [f64; 3],
+    ) -> Result<FloatingPosition, StrErr> {
+        let dbgid = DbgId(format!("{}.equilibrium", self.dbgid));
+        if !(mass > 0.0 && water_density > 0.0) {
+            return Err(StrErr(format!(
+                "{} | Expected positive mass={} and water_density={}",
+                dbgid, mass, water_density
+            )));
+        }
+        let volume = mass / water_density;
+        let (cache, draught) = self.upright_draught(&dbgid, volume)?;
+        let equilibrium = Equilibrium::new(
+            &dbgid,
+            self.equilibrium_conf.clone(),
+            volume,
+            center_of_gravity,
+        );
+        let position = equilibrium.solve([0.0, 0.0, draught], |position| {
+            Self::cached_submerged(cache, position)
+        })?;
+        log::debug!("{} | Cached position: {:?}", dbgid, position);
+        if !self.equilibrium_conf.refine {
+            return Ok(position);
+        }
+        let waterline = Waterline::new(
+            self.model_tree.elements(&self.hull_keys),
+            Waterline::create_face(&dbgid, self.waterline_position)?,
+        );
+        equilibrium.solve(
+            [position.heel, position.trim, position.draught],
+            |position| waterline.submerged(position, None),
+        )
+    }
+    ///
+    /// Returns the floating position of the ship in `loading_condition`
+    /// floating in water of `water_density` (in t/m3).
+    ///
+    /// The tanks of the condition are resolved at the upright position (see [ShipModel::resolve_tanks]),
+    /// then the displacement and the center of gravity of all the weights are used
+    /// (see [ShipModel::equilibrium] for details).
+    ///
+    /// # Errors
+    /// The error returns if some tank can't be resolved, the displacement isn't positive,
+    /// or the position isn't found (see [ShipModel::equilibrium]).
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(ship_model: &ShipModel<()>) -> Result<(), StrErr> {
+    ///     let loading_condition = LoadingCondition::read("full_load_departure")?;
+    ///     let position = ship_model.floating_position(&loading_condition, 1.025)?;
+    ///     log::info!("heel={}, trim={}, draught={}", position.heel, position.trim, position.draught);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn floating_position(
+        &self,
+        loading_condition: &LoadingCondition,
+        water_density: f64,
+    ) -> Result<FloatingPosition, StrErr> {
+        let dbgid = DbgId(format!("{}.floating_position", self.dbgid));
+        let loading_condition = self.resolve_tanks(loading_condition, 0.0, 0.0)?;
+        let center_of_gravity = Self::center_of_gravity(&dbgid, &loading_condition)?;
+        self.equilibrium(
+            loading_condition.displacement(),
+            water_density,
+            center_of_gravity,
+        )
+    }
+    ///
+    /// Returns `loading_condition`, which tanks are replaced by the deadweight items
+    /// got from the tank calibration at `heel` and `trim` (in degrees).
+    ///
+    /// The item of the tank is named by the tank key, its mass is the volume times the liquid density,
+    /// and its free surface moment is the transverse moment of inertia of the free surface
+    /// times the liquid density (see [ShipModel::tank_filling]).
+    /// The item is spread between the tank ends if they are configured
+    /// (see [TankCacheConf::extent]).
+    ///
+    /// # Errors
+    /// The error returns if some tank isn't configured
+    /// or its level is out of the calibration range.
+    ///
+    /// [TankCacheConf::extent]: local_cache::tank_cache::tank_cache_conf::TankCacheConf::extent
+    pub fn resolve_tanks(
+        &self,
+        loading_condition: &LoadingCondition,
+        heel: f64,
+        trim: f64,
+    ) -> Result<LoadingCondition, StrErr> {
+        let dbgid = DbgId(format!("{}.resolve_tanks", self.dbgid));
+        let mut resolved = LoadingCondition {
+            tanks: vec![],
+            ..loading_condition.clone()
+        };
+        for tank in &loading_condition.tanks {
+            let filling = self
+                .tank_filling(&tank.tank, heel, trim, tank.gauge)
+                .map_err(|err| StrErr(format!("{} | {}", dbgid, err)))?;
+            let item = DeadweightItem::new(
+                tank.tank.clone(),
+                Weight::new(filling.volume * tank.density, filling.centroid),
+            )
+            .with_free_surface(filling.free_surface_inertia[0] * tank.density);
+            resolved.items.push(match self.tanks.get(&tank.tank) {
+                Some((_, Some([aft, fore]))) => item.with_extent(*aft, *fore),
+                _ => item,
+            });
+        }
+        Ok(resolved)
+    }
+    ///
+    /// Returns the transverse metacentric height of the ship in `loading_condition`
+    /// floating in water of `water_density` (in t/m3), corrected for the free surfaces.
+    ///
+    /// The metacenter is taken from the hydrostatics cache (see [CacheKey::Hydrostatics])
+    /// at the floating position (see [ShipModel::floating_position]),
+    /// so both the floating position and the hydrostatics caches
+    /// are supposed to share the waterline initial position.
+    /// GM is measured along the normal to the waterplane,
+    /// then the virtual rise of the center of gravity is subtracted
+    /// (see [LoadingCondition::free_surface_correction]).
+    ///
+    /// # Errors
+    /// The error returns if the floating position isn't found
+    /// or the cache fails to approximate the values.
+    pub fn metacentric_height(
+        &self,
+        loading_condition: &LoadingCondition,
+        water_density: f64,
+    ) -> Result<f64, StrErr> {
+        let dbgid = DbgId(format!("{}.metacentric_height", self.dbgid));
+        let loading_condition = self.resolve_tanks(loading_condition, 0.0, 0.0)?;
+        let center_of_gravity = Self::center_of_gravity(&dbgid, &loading_condition)?;
+        let position = self.equilibrium(
+            loading_condition.displacement(),
+            water_density,
+            center_of_gravity,
+        )?;
+        let rows = self.get_cached(
+            CacheKey::Hydrostatics,
+            &[
+                Some(position.heel),
+                Some(position.trim),
+                Some(position.draught),
+            ],
+        )?;
+        let bmt = match rows.first().map(Vec::as_slice) {
+            Some([.., bmt, _]) => *bmt,
+            _ => {
+                return Err(StrErr(format!(
+                    "{} | No hydrostatics found at position={:?}",
+                    dbgid, position
+                )))
+            }
+        };
+        let [.., normal] = waterline::axes(position.heel, position.trim);
+        let metacenter = [0, 1, 2].map(|i| position.center_of_buoyancy[i] + bmt * normal[i]);
+        let gm = waterline::dot(
+            [0, 1, 2].map(|i| metacenter[i] - center_of_gravity[i]),
+            normal,
+        );
+        Ok(gm - loading_condition.free_surface_correction())
+    }
+    ///
+    /// Returns the GZ curve of the ship in `loading_condition` corrected for the free surfaces.
+    ///
+    /// The tanks are resolved at the upright position (see [ShipModel::resolve_tanks]),
+    /// LCG and KG are measured from the reference point of the cross curves,
+    /// and the curve is corrected by the virtual rise of the center of gravity
+    /// (see [ShipModel::gz_curve] and [GzCurve::with_free_surface]).
+    ///
+    /// # Errors
+    /// The error returns if some tank can't be resolved, the displacement isn't positive,
+    /// LCG doesn't suit the free trim cross curves (see [ShipModel::gz_curve]),
+    /// or the cache fails to approximate the values.
+    pub fn loading_gz_curve(
+        &self,
+        loading_condition: &LoadingCondition,
+        free_trim: bool,
+    ) -> Result<GzCurve, StrErr> {
+        let dbgid = DbgId(format!("{}.loading_gz_curve", self.dbgid));
+        let loading_condition = self.resolve_tanks(loading_condition, 0.0, 0.0)?;
+        let [lcg, _, vcg] = Self::center_of_gravity(&dbgid, &loading_condition)?;
+        Ok(self
+            .gz_curve(
+                loading_condition.displacement(),
+                lcg - self.reference_point[0],
+                vcg - self.reference_point[2],
+                free_trim,
+            )?
+            .with_free_surface(loading_condition.free_surface_correction()))
+    }
+    ///
+    /// Returns the center of gravity of `loading_condition`.
+    fn center_of_gravity(
+        dbgid: &DbgId,
+        loading_condition: &LoadingCondition,
+    ) -> Result<[f64; 3], StrErr> {
+        loading_condition.center_of_gravity().ok_or_else(|| {
+            StrErr(format!(
+                "{} | Expected positive displacement of loading condition='{}'",
+                dbgid, loading_condition.name
+            ))
+        })
+    }
+    ///
+    /// Returns the GZ curve of the ship of `displacement` (in tons),
+    /// which center of gravity is `lcg` meters forward of and `kg` meters above
+    /// the reference point of the cross curves (see [CrossCurvesCacheConf::reference_point]).
+    ///
+    /// The curve has a point per heel step of the cross curves cache (see [CacheKey::CrossCurves]).
+    /// KN at the free trim is used if `free_trim` is set, otherwise KN at the fixed trim.
+    /// The free trim cross curves are balanced at LCG of the reference point,
+    /// so `lcg` must be within [EquilibriumConf::lever_tolerance] in this case.
+    ///
+    /// # Errors
+    /// The error returns if `lcg` is out of the tolerance for the free trim,
+    /// or the cache fails to approximate the values (e. g. `displacement` is out of the cache range).
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(ship_model: &ShipModel<()>) -> Result<(), StrErr> {
+    ///     let gz_curve = ship_model.gz_curve(1000.0, 0.0, 4.5, true)?;
+    ///     log::info!("GZ at 30 deg: {:?}", gz_curve.gz(30.0));
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [CrossCurvesCacheConf::reference_point]: local_cache::cross_curves_cache::cross_curves_cache_conf::CrossCurvesCacheConf::reference_point
+    pub fn gz_curve(
+        &self,
+        displacement: f64,
+        lcg: f64,
+        kg: f64,
+        free_trim: bool,
+    ) -> Result<GzCurve, StrErr> {
+        let dbgid = DbgId(format!("{}.gz_curve", self.dbgid));
+        let lever_tolerance = self.equilibrium_conf.lever_tolerance;
+        if free_trim && lcg.abs() > lever_tolerance {
+            return Err(StrErr(format!(
+                "{} | Free trim cross curves are balanced at LCG of the reference point, \
+                 but lcg={} is out of lever_tolerance={}",
+                dbgid, lcg, lever_tolerance
+            )));
+        }
+        let rows = self.get_cached(CacheKey::CrossCurves, &[None, None, Some(displacement)])?;
+        let kn = Vec::from_iter(rows.iter().filter_map(|row| match row.as_slice() {
+            [heel, _, _, kn_fixed, kn_free, ..] => Some((
+                *heel,
+                match free_trim {
+                    true => *kn_free,
+                    false => *kn_fixed,
+                },
+            )),
+            _ => None,
+        }));
+        if kn.is_empty() {
+            return Err(StrErr(format!(
+                "{} | No cross curves found for displacement={}",
+                dbgid, displacement
+            )));
+        }
+        Ok(GzCurve::from_cross_curves(kn, kg))
+    }
+    ///
+    /// Returns the liquid in `tank` (the model key, see [TankCacheConf::tank])
+    /// at `heel` and `trim` (in degrees) measured by `gauge`.
+    ///
+    /// # Errors
+    /// The error returns if there is no cache for `tank`
+    /// or the cache fails to approximate the values (e. g. the sounding is out of the cache range).
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(ship_model: &ShipModel<()>) -> Result<(), StrErr> {
+    ///     let filling = ship_model.tank_filling("/tanks/fo_1p", 2.0, 0.5, Gauge::Sounding(1.2))?;
+    ///     log::info!("volume={}, ullage={}", filling.volume, filling.ullage);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [TankCacheConf::tank]: local_cache::tank_cache::tank_cache_conf::TankCacheConf::tank
+    pub fn tank_filling(
+        &self,
+        tank: &str,
+        heel: f64,
+        trim: f64,
+        gauge: Gauge,
+    ) -> Result<TankFilling, StrErr> {
+        let dbgid = DbgId(format!("{}.tank_filling", self.dbgid));
+        let (index, ullage_reference) = self.tank(&dbgid, tank)?;
+        let sounding = match gauge {
+            Gauge::Sounding(sounding) => sounding,
+            Gauge::Ullage(ullage) => ullage_reference - ullage,
+        };
+        let rows = self.get_cached(
+            CacheKey::Tank(index),
+            &[Some(heel), Some(trim), Some(sounding)],
+        )?;
+        rows.first()
+            .and_then(|row| TankFilling::from_row(row, ullage_reference))
+            .ok_or_else(|| {
+                StrErr(format!(
+                    "{} | No cached row for tank='{}', heel={}, trim={}, sounding={}",
+                    dbgid, tank, heel, trim, sounding
+                ))
+            })
+    }
+    ///
+    /// Returns the liquid in `tank` (the model key, see [TankCacheConf::tank])
+    /// at `heel` and `trim` (in degrees), which `volume` is given.
+    ///
+    /// It's the inverse of [ShipModel::tank_filling], the sounding and ullage are solved.
+    ///
+    /// # Errors
+    /// The error returns if there is no cache for `tank`
+    /// or the cache fails to solve the values (e. g. the volume is more than the tank can hold).
+    ///
+    /// [TankCacheConf::tank]: local_cache::tank_cache::tank_cache_conf::TankCacheConf::tank
+    pub fn tank_filling_of_volume(
+        &self,
+        tank: &str,
+        heel: f64,
+        trim: f64,
+        volume: f64,
+    ) -> Result<TankFilling, StrErr> {
+        let dbgid = DbgId(format!("{}.tank_filling_of_volume", self.dbgid));
+        let (index, ullage_reference) = self.tank(&dbgid, tank)?;
+        let cache = self
+            .caches
+            .get(&CacheKey::Tank(index))
+            .ok_or_else(|| StrErr(format!("{} | No cache found for tank='{}'", dbgid, tank)))?;
+        cache
+            .solve(&[Some(heel), Some(trim), None, Some(volume)], 2)
+            .map_err(|err| StrErr(format!("{} | {}", dbgid, err)))?
+            .first()
+            .and_then(|row| TankFilling::from_row(row, ullage_reference))
+            .ok_or_else(|| {
+                StrErr(format!(
+                    "{} | No sounding found for tank='{}', heel={}, trim={}, volume={}",
+                    dbgid, tank, heel, trim, volume
+                ))
+            })
+    }
+    ///
+    /// Returns the index and the ullage reference of `tank`.
+    fn tank(&self, dbgid: &DbgId, tank: &str) -> Result<(usize, f64), StrErr> {
+        self.tanks
+            .get_full(tank)
+            .map(|(index, _, (ullage_reference, _))| (index, *ullage_reference))
+            .ok_or_else(|| StrErr(format!("{} | No tank='{}' configured", dbgid, tank)))
+    }
+    ///
+    /// Returns the damage cases of the configuration (see [DamageConf::cases]).
+    pub fn damage_cases(&self) -> &[DamageCase] {
+        &self.damage_conf.cases
+    }
+    ///
+    /// Returns the floating position and the residual GZ curve of the ship in `loading_condition`
+    /// floating in water of `water_density` (in t/m3) with the compartments of `case` flooded.
+    ///
+    /// The lost buoyancy method is used: the submerged volume of each flooded compartment
+    /// multiplied by its permeability is taken out of the hull submerged volume,
+    /// whereas the displacement and the center of gravity are kept intact (see [LostBuoyancy]).
+    /// The hull is taken from the floating position cache, and the compartments are
+    /// cut by the waterline directly, so it's as slow as [EquilibriumConf::refine].
+    ///
+    /// # Errors
+    /// The error returns if some compartment isn't found or its permeability is out of [0, 1],
+    /// the tanks can't be resolved (see [ShipModel::resolve_tanks]),
+    /// or the floating position isn't found (e. g. the ship sinks).
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(ship_model: &ShipModel<()>, loading_condition: &LoadingCondition) -> Result<(), StrErr> {
+    ///     let case = DamageCase::new("Engine room").with_compartment("/compartments/er", 0.85);
+    ///     let damage = ship_model.damage_stability(loading_condition, 1.025, &case)?;
+    ///     log::info!("heel={}, GZ max={:?}", damage.position.heel, damage.gz_curve.max(0.0, 90.0));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn damage_stability(
+        &self,
+        loading_condition: &LoadingCondition,
+        water_density: f64,
+        case: &DamageCase,
+    ) -> Result<DamageStability, StrErr> {
+        let dbgid = DbgId(format!("{}.damage_stability", self.dbgid));
+        let loading_condition = self.resolve_tanks(loading_condition, 0.0, 0.0)?;
+        let center_of_gravity = Self::center_of_gravity(&dbgid, &loading_condition)?;
+        let mass = loading_condition.displacement();
+        if water_density <= 0.0 || water_density.is_nan() {
+            return Err(StrErr(format!(
+                "{} | Expected positive water_density={}",
+                dbgid, water_density
+            )));
+        }
+        let volume = mass / water_density;
+        let mut compartments = vec![];
+        for compartment in &case.compartments {
+            if !(0.0..=1.0).contains(&compartment.permeability) {
+                return Err(StrErr(format!(
+                    "{} | case='{}': Expected permeability={} of compartment='{}' within [0, 1]",
+                    dbgid, case.name, compartment.permeability, compartment.key
+                )));
+            }
+            let shape = self.model_tree.get(&compartment.key).ok_or_else(|| {
+                StrErr(format!(
+                    "{} | case='{}': No element found for compartment='{}'",
+                    dbgid, case.name, compartment.key
+                ))
+            })?;
+            compartments.push((
+                Waterline::new(
+                    vec![shape.clone()],
+                    Waterline::create_face(&dbgid, self.waterline_position)?,
+                ),
+                compartment.permeability,
+            ));
+        }
+        let (cache, draught) = self.upright_draught(&dbgid, volume)?;
+        LostBuoyancy::new(
+            &dbgid,
+            self.equilibrium_conf.clone(),
+            volume,
+            center_of_gravity,
+        )
+        .solve(
+            &case.name,
+            [0.0, 0.0, draught],
+            &self.damage_conf.heel_steps,
+            |position| {
+                let flooded = compartments
+                    .iter()
+                    .map(|(waterline, permeability)| {
+                        waterline
+                            .submerged(position, None)
+                            .map(|flooded| (flooded, *permeability))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(damage::lost_buoyancy(
+                    Self::cached_submerged(cache, position)?,
+                    flooded,
+                ))
+            },
+        )
+    }
+    ///
+    /// Returns the result of [ShipModel::damage_stability] for each of `cases` in their order,
+    /// a failed case doesn't stop the rest.
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(ship_model: &ShipModel<()>, loading_condition: &LoadingCondition) {
+    ///     let cases = ship_model.damage_cases();
+    ///     for (case, result) in cases.iter().zip(ship_model.evaluate_damage_cases(loading_condition, 1.025, cases)) {
+    ///         match result {
+    ///             Ok(damage) => log::info!("{}: heel={}", case.name, damage.position.heel),
+    ///             Err(err) => log::warn!("{}: {}", case.name, err),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn evaluate_damage_cases(
+        &self,
+        loading_condition: &LoadingCondition,
+        water_density: f64,
+        cases: &[DamageCase],
+    ) -> Vec<Result<DamageStability, StrErr>> {
+        Vec::from_iter(
+            cases
+                .iter()
+                .map(|case| self.damage_stability(loading_condition, water_density, case)),
+        )
+    }
+    ///
+    /// Returns the still-water shear force and bending moment of the hull girder
+    /// of the ship in `loading_condition` floating in water of `water_density` (in t/m3),
+    /// checked against the permissible limits (see [LongitudinalStrength]).
+    ///
+    /// The buoyancy distribution is the hull part under the waterline at the floating position
+    /// (see [ShipModel::floating_position]) sliced at the stations,
+    /// so the hull is cut directly, which is as slow as [EquilibriumConf::refine].
+    /// The hull is made of the elements the floating position cache is calculated for,
+    /// so the tanks and the compartments don't give buoyancy.
+    ///
+    /// # Errors
+    /// The error returns if there are less than two stations,
+    /// the floating position isn't found, or the model fails to be sliced.
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(ship_model: &ShipModel<()>, loading_condition: &LoadingCondition) -> Result<(), StrErr> {
+    ///     let report = ship_model.longitudinal_strength(loading_condition, 1.025)?;
+    ///     for station in report.exceedances() {
+    ///         log::warn!("Limits exceeded at x={}", station.x);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn longitudinal_strength(
+        &self,
+        loading_condition: &LoadingCondition,
+        water_density: f64,
+    ) -> Result<StrengthReport, StrErr> {
+        let dbgid = DbgId(format!("{}.longitudinal_strength", self.dbgid));
+        let strength = LongitudinalStrength::new(self.strength_conf.clone());
+        if strength.stations().len() < 2 {
+            return Err(StrErr(format!(
+                "{} | Expected at least two stations, found {}",
+                dbgid,
+                strength.stations().len()
+            )));
+        }
+        let loading_condition = self.resolve_tanks(loading_condition, 0.0, 0.0)?;
+        let position = self.floating_position(&loading_condition, water_density)?;
+        let waterline = Waterline::new(
+            self.model_tree.elements(&self.hull_keys),
+            Waterline::create_face(&dbgid, self.waterline_position)?,
+        );
+        let buoyancy = Vec::from_iter(
+            waterline
+                .sections(
+                    &dbgid,
+                    [position.heel, position.trim, position.draught],
+                    strength.stations(),
+                )?
+                .into_iter()
+                .filter_map(|section| {
+                    section
+                        .center()
+                        .map(|center| (center[0], section.volume * water_density))
+                }),
+        );
+        Ok(strength.report(&strength.weights(&loading_condition), &buoyancy))
+    }
+    ///
+    /// Returns the floating position cache and the upright draught of `volume` found by it.
+    fn upright_draught(
+        &self,
+        dbgid: &DbgId,
+        volume: f64,
+    ) -> Result<(&dyn LocalCache, f64), StrErr> {
+        let cache = self
+            .caches
+            .get(&CacheKey::FloatingPostion)
+            .ok_or_else(|| StrErr(format!("{} | No floating position cache found", dbgid)))?;
+        let draught = cache
+            .solve(&[Some(0.0), Some(0.0), None, Some(volume)], 2)
+            .map_err(|err| StrErr(format!("{} | {}", dbgid, err)))?
+            .first()
+            .map(|row| row[2])
+            .ok_or_else(|| {
+                StrErr(format!(
+                    "{} | No draught found for volume={}",
+                    dbgid, volume
+                ))
+            })?;
+        Ok((cache.as_ref(), draught))
+    }
+    ///
+    /// Returns the submerged volume at `position` (heel, trim, and draught)
+    /// approximated by the floating position `cache`.
+    fn cached_submerged(
+        cache: &dyn LocalCache,
+        [heel, trim, draught]: [f64; 3],
+    ) -> Result<Submerged, StrErr> {
+        let rows = cache
+            .get(&[Some(heel), Some(trim), Some(draught)])
+            .map_err(|err| StrErr(err.to_string()))?;
+        match rows.first().map(Vec::as_slice) {
+            Some([.., volume, lcb, tcb, vcb]) => Ok(Submerged {
+                volume: *volume,
+                moment: [lcb, tcb, vcb].map(|coord| coord * volume),
+            }),
+            _ => Err(StrErr(format!(
+                "No cached row for heel={}, trim={}, draught={}",
+                heel, trim, draught
+            ))),
+        }
+    }
+    ///
+    /// Returns approximated values of the cache pointed by `cache_key`.
+    ///
+    /// # Errors
+    /// The error returns if there is no cache for `cache_key`
+    /// or the cache fails to approximate the values (see [CacheError]).
+    ///
+    /// # Examples
+    /// ```
+    /// fn explaination(ship_model: &ShipModel<()>) -> Result<(), StrErr> {
+    ///     // heel, trim, and draught
+    ///     let query = [Some(0.0), Some(1.0), Some(5.0)];
+    ///     let _ = ship_model.get_cached(CacheKey::FloatingPostion, &query)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [CacheError]: crate::common::cache::error::CacheError
+    pub fn get_cached(
+        &self,
+        cache_key: CacheKey,
+        approx_vals: &[Option<f64>],
+    ) -> Result<Vec<Vec<f64>>, StrErr> {
+        let dbgid = DbgId(format!("{}.get_cached", self.dbgid));
+        let cache = self
+            .caches
+            .get(&cache_key)
+            .ok_or_else(|| StrErr(format!("{} | No cache found for the key", dbgid)))?;
+        cache
+            .get(approx_vals)
+            .map_err(|err| StrErr(format!("{} | {}", dbgid, err)))
+    }
 }
@@ -0,0 +1,181 @@
+//!
+//! Damage stability by the lost buoyancy method:
+//! the flooded compartments don't belong to the ship anymore,
+//! so their submerged volume multiplied by the permeability is lost,
+//! whereas the displacement and the center of gravity remain intact.
+//
+pub mod damage_conf;
+#[cfg(test)]
+#[path = "../../tests/models/ship_model/damage_test.rs"]
+mod tests;
+//
+use super::{
+    equilibrium::{equilibrium_conf::EquilibriumConf, Equilibrium, FloatingPosition},
+    waterline::{self, Submerged},
+};
+use crate::models::stability::gz_curve::GzCurve;
+use sal_sync::services::entity::{dbg_id::DbgId, error::str_err::StrErr};
+///
+/// Compartment open to the sea.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FloodedCompartment {
+    ///
+    /// Key of the model element making the compartment.
+    pub key: String,
+    ///
+    /// Part of the compartment volume the water can fill, from 0 to 1
+    /// (e. g. 0.95 for accommodation, 0.6 for stores).
+    pub permeability: f64,
+}
+///
+/// Set of compartments flooded together.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct DamageCase {
+    ///
+    /// Human readable name, e. g. "Engine room and DB tank 3".
+    pub name: String,
+    pub compartments: Vec<FloodedCompartment>,
+}
+//
+//
+impl DamageCase {
+    ///
+    /// Creates a new instance without compartments.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            compartments: vec![],
+        }
+    }
+    ///
+    /// Returns the instance with the compartment of `key` and `permeability` appended.
+    pub fn with_compartment(mut self, key: impl Into<String>, permeability: f64) -> Self {
+        self.compartments.push(FloodedCompartment {
+            key: key.into(),
+            permeability,
+        });
+        self
+    }
+}
+///
+/// Result of [super::ShipModel::damage_stability].
+#[derive(Clone, PartialEq, Debug)]
+pub struct DamageStability {
+    ///
+    /// Name of the damage case (see [DamageCase::name]).
+    pub case: String,
+    ///
+    /// Floating position after flooding,
+    /// the center of buoyancy is the one of the remaining intact volume.
+    pub position: FloatingPosition,
+    ///
+    /// Residual righting levers at the heel steps (see [damage_conf::DamageConf::heel_steps]),
+    /// the steps the position isn't found at are skipped.
+    pub gz_curve: GzCurve,
+}
+///
+/// Returns `intact` submerged volume with the `flooded` parts lost,
+/// each flooded part is given with its permeability.
+pub(super) fn lost_buoyancy(
+    intact: Submerged,
+    flooded: impl IntoIterator<Item = (Submerged, f64)>,
+) -> Submerged {
+    flooded
+        .into_iter()
+        .fold(intact, |mut remaining, (flooded, permeability)| {
+            remaining.volume -= permeability * flooded.volume;
+            for (moment, flooded) in remaining.moment.iter_mut().zip(flooded.moment) {
+                *moment -= permeability * flooded;
+            }
+            remaining
+        })
+}
+///
+/// Solver of the floating position and the residual GZ curve of the damaged ship.
+pub(super) struct LostBuoyancy {
+    dbgid: DbgId,
+    conf: EquilibriumConf,
+    volume: f64,
+    center_of_gravity: [f64; 3],
+}
+//
+//
+impl LostBuoyancy {
+    ///
+    /// Creates a new instance.
+    /// - volume - displaced volume of the intact ship
+    /// - center_of_gravity - LCG, TCG, and VCG in the model coordinate system
+    pub(super) fn new(
+        parent: &DbgId,
+        conf: EquilibriumConf,
+        volume: f64,
+        center_of_gravity: [f64; 3],
+    ) -> Self {
+        Self {
+            dbgid: DbgId::with_parent(parent, "LostBuoyancy"),
+            conf,
+            volume,
+            center_of_gravity,
+        }
+    }
+    ///
+    /// Returns the floating position of the damaged ship found starting from `initial`
+    /// heel, trim, and draught, and its GZ curve at `heel_steps`.
+    /// - submerged - returns the remaining intact part of the model under the waterline
+    ///   at given heel, trim, and draught (see [lost_buoyancy])
+    ///
+    /// Each point of the curve is the lever of the buoyancy at the heel, which is kept,
+    /// whereas the trim and the draught are free.
+    ///
+    /// # Errors
+    /// The error returns if the floating position isn't found (see [Equilibrium::solve]).
+    pub(super) fn solve(
+        &self,
+        case: &str,
+        initial: [f64; 3],
+        heel_steps: &[f64],
+        submerged: impl Fn([f64; 3]) -> Result<Submerged, StrErr>,
+    ) -> Result<DamageStability, StrErr> {
+        let dbgid = DbgId(format!("{}.solve", self.dbgid));
+        let position = Equilibrium::new(
+            &dbgid,
+            self.conf.clone(),
+            self.volume,
+            self.center_of_gravity,
+        )
+        .solve(initial, &submerged)
+        .map_err(|err| StrErr(format!("{} | case='{}': {}", dbgid, case, err)))?;
+        let equilibrium = Equilibrium::new(
+            &dbgid,
+            self.conf.clone(),
+            self.volume,
+            self.center_of_gravity,
+        )
+        .with_fixed_heel();
+        let points = heel_steps.iter().filter_map(|&heel| {
+            match equilibrium.solve([heel, position.trim, position.draught], &submerged) {
+                Ok(inclined) => {
+                    let [_, loc_y, _] = waterline::axes(inclined.heel, inclined.trim);
+                    let lever = [0, 1, 2]
+                        .map(|i| inclined.center_of_buoyancy[i] - self.center_of_gravity[i]);
+                    Some((heel, waterline::dot(lever, loc_y)))
+                }
+                Err(err) => {
+                    log::warn!(
+                        "{} | case='{}', heel={} skipped: {}",
+                        dbgid,
+                        case,
+                        heel,
+                        err
+                    );
+                    None
+                }
+            }
+        });
+        Ok(DamageStability {
+            case: case.to_owned(),
+            position,
+            gz_curve: GzCurve::new(Vec::from_iter(points)),
+        })
+    }
+}
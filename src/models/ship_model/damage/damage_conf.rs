@@ -0,0 +1,12 @@
+use super::DamageCase;
+///
+/// [super::super::ShipModel::damage_stability] configuration.
+#[derive(Clone, Debug, Default)]
+pub struct DamageConf {
+    ///
+    /// Damage cases evaluated by default (see [super::super::ShipModel::damage_cases]).
+    pub cases: Vec<DamageCase>,
+    ///
+    /// Angles in degrees the residual GZ curve is calculated at.
+    pub heel_steps: Vec<f64>,
+}
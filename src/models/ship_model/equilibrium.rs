@@ -0,0 +1,268 @@
+//!
+//! Floating position of the ship in equilibrium, where buoyancy balances weight.
+//
+pub mod equilibrium_conf;
+#[cfg(test)]
+#[path = "../../tests/models/ship_model/equilibrium_test.rs"]
+mod tests;
+//
+use super::waterline::{self, Submerged};
+use equilibrium_conf::EquilibriumConf;
+use sal_sync::services::entity::{dbg_id::DbgId, error::str_err::StrErr};
+///
+/// Floating position found by [super::ShipModel::equilibrium].
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct FloatingPosition {
+    ///
+    /// Angle in degrees.
+    pub heel: f64,
+    ///
+    /// Angle in degrees.
+    pub trim: f64,
+    ///
+    /// Offset of the waterline from its initial position (see [super::ShipModel::equilibrium]).
+    pub draught: f64,
+    ///
+    /// Submerged volume.
+    pub volume: f64,
+    ///
+    /// Center of buoyancy (LCB, TCB, VCB) in the model coordinate system.
+    pub center_of_buoyancy: [f64; 3],
+    ///
+    /// Number of iterations done to find the position.
+    pub iterations: usize,
+}
+///
+/// Solver of the floating position, at which the submerged volume is the required one
+/// and the center of buoyancy lies on the normal to the waterplane passing through the center of gravity.
+///
+/// The position is found by Newton's method, the Jacobian is taken by finite differences.
+/// Each step is limited by [EquilibriumConf::max_angle_step] and halved while it doesn't reduce the residual.
+///
+/// Heel and trim can be fixed (see [Equilibrium::with_fixed_heel] and [Equilibrium::with_fixed_trim]),
+/// then the center of buoyancy is aligned with the center of gravity only along the free angles.
+pub(super) struct Equilibrium {
+    dbgid: DbgId,
+    conf: EquilibriumConf,
+    volume: f64,
+    center_of_gravity: [f64; 3],
+    ///
+    /// Flags of heel, trim, and draught kept as given.
+    fixed: [bool; 3],
+}
+//
+//
+impl Equilibrium {
+    ///
+    /// Maximum number of step halvings per iteration.
+    const BACKTRACKING: usize = 8;
+    ///
+    /// Creates a new instance.
+    /// - volume - required submerged volume
+    /// - center_of_gravity - LCG, TCG, and VCG in the model coordinate system
+    pub(super) fn new(
+        parent: &DbgId,
+        conf: EquilibriumConf,
+        volume: f64,
+        center_of_gravity: [f64; 3],
+    ) -> Self {
+        Self {
+            dbgid: DbgId::with_parent(parent, "Equilibrium"),
+            conf,
+            volume,
+            center_of_gravity,
+            fixed: [false; 3],
+        }
+    }
+    ///
+    /// Returns the instance keeping the initial heel.
+    pub(super) fn with_fixed_heel(mut self) -> Self {
+        self.fixed[0] = true;
+        self
+    }
+    ///
+    /// Returns the instance keeping the initial trim.
+    pub(super) fn with_fixed_trim(mut self) -> Self {
+        self.fixed[1] = true;
+        self
+    }
+    ///
+    /// Returns the floating position found starting from `initial` heel, trim, and draught.
+    /// - submerged - returns the model part under the waterline at given heel, trim, and draught
+    ///
+    /// # Errors
+    /// The error returns if `submerged` fails, there is no submerged volume,
+    /// or the solution isn't found in [EquilibriumConf::max_iterations].
+    pub(super) fn solve(
+        &self,
+        initial: [f64; 3],
+        submerged: impl Fn([f64; 3]) -> Result<Submerged, StrErr>,
+    ) -> Result<FloatingPosition, StrErr> {
+        let dbgid = DbgId(format!("{}.solve", self.dbgid));
+        let deltas = [
+            self.conf.angle_delta,
+            self.conf.angle_delta,
+            self.conf.draught_delta,
+        ];
+        let mut position = initial;
+        let (mut residual, mut current) = self
+            .residual(position, &submerged)
+            .map_err(|err| StrErr(format!("{} | {}", dbgid, err)))?;
+        for iteration in 0..=self.conf.max_iterations {
+            if self.is_converged(residual) {
+                let [heel, trim, draught] = position;
+                return Ok(FloatingPosition {
+                    heel,
+                    trim,
+                    draught,
+                    volume: current.volume,
+                    center_of_buoyancy: current.center().unwrap_or_default(),
+                    iterations: iteration,
+                });
+            }
+            if iteration == self.conf.max_iterations {
+                break;
+            }
+            let mut jacobian = [[0.0; 3]; 3];
+            for (j, delta) in deltas.into_iter().enumerate() {
+                // the residual of the fixed value is zero, so is its step
+                if self.fixed[j] {
+                    jacobian[j][j] = 1.0;
+                    continue;
+                }
+                let mut shifted = position;
+                shifted[j] += delta;
+                let (shifted_residual, _) = self
+                    .residual(shifted, &submerged)
+                    .map_err(|err| StrErr(format!("{} | {}", dbgid, err)))?;
+                for i in 0..3 {
+                    jacobian[i][j] = (shifted_residual[i] - residual[i]) / delta;
+                }
+            }
+            let mut step = solve_linear(jacobian, residual.map(|r| -r)).ok_or_else(|| {
+                StrErr(format!(
+                    "{} | Singular Jacobian at position={:?}",
+                    dbgid, position
+                ))
+            })?;
+            let angle_step = step[0].abs().max(step[1].abs());
+            if angle_step > self.conf.max_angle_step {
+                step = step.map(|s| s * self.conf.max_angle_step / angle_step);
+            }
+            // halve the step until the residual is reduced,
+            // a position out of the model or the cache range is considered as not reducing
+            let norm = self.norm(residual);
+            let mut best: Option<(f64, [f64; 3], [f64; 3], Submerged)> = None;
+            let mut last_err = None;
+            let mut factor = 1.0;
+            for _ in 0..Self::BACKTRACKING {
+                let trial = [0, 1, 2].map(|i| position[i] + factor * step[i]);
+                match self.residual(trial, &submerged) {
+                    Ok((trial_residual, trial_submerged)) => {
+                        let trial_norm = self.norm(trial_residual);
+                        if best
+                            .as_ref()
+                            .is_none_or(|(best_norm, ..)| trial_norm < *best_norm)
+                        {
+                            best = Some((trial_norm, trial, trial_residual, trial_submerged));
+                        }
+                        if trial_norm < norm {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        log::debug!("{} | Step skipped: {}", dbgid, err);
+                        last_err = Some(err);
+                    }
+                }
+                factor *= 0.5;
+            }
+            match best {
+                Some((_, trial, trial_residual, trial_submerged)) => {
+                    position = trial;
+                    residual = trial_residual;
+                    current = trial_submerged;
+                }
+                None => {
+                    return Err(StrErr(format!(
+                        "{} | Failed stepping from position={:?}: {}",
+                        dbgid,
+                        position,
+                        last_err.map_or_else(String::new, |err| err.0)
+                    )))
+                }
+            }
+        }
+        Err(StrErr(format!(
+            "{} | No solution found in {} iterations, position={:?}, residual={:?}",
+            dbgid, self.conf.max_iterations, position, residual
+        )))
+    }
+    ///
+    /// Returns the residual of the equilibrium at `position` and the model part submerged there.
+    ///
+    /// The residual is the lever between the centers of buoyancy and gravity
+    /// along the transverse and the longitudinal axes of the waterplane,
+    /// and the excess of the submerged volume relative to the required one.
+    /// The lever along the axis of the fixed angle is zero.
+    fn residual(
+        &self,
+        position: [f64; 3],
+        submerged: &impl Fn([f64; 3]) -> Result<Submerged, StrErr>,
+    ) -> Result<([f64; 3], Submerged), StrErr> {
+        let current = submerged(position)?;
+        let center = current
+            .center()
+            .ok_or_else(|| StrErr(format!("No submerged volume at position={:?}", position)))?;
+        let [heel, trim, _] = position;
+        let [loc_x, loc_y, _] = waterline::axes(heel, trim);
+        let lever = [0, 1, 2].map(|i| center[i] - self.center_of_gravity[i]);
+        let residual = [
+            waterline::dot(lever, loc_y),
+            waterline::dot(lever, loc_x),
+            (current.volume - self.volume) / self.volume,
+        ];
+        Ok((
+            [0, 1, 2].map(|i| match self.fixed[i] {
+                true => 0.0,
+                false => residual[i],
+            }),
+            current,
+        ))
+    }
+    ///
+    /// Returns _true_ if `residual` is within the tolerances.
+    fn is_converged(&self, [transverse, longitudinal, volume]: [f64; 3]) -> bool {
+        transverse.abs() <= self.conf.lever_tolerance
+            && longitudinal.abs() <= self.conf.lever_tolerance
+            && volume.abs() <= self.conf.volume_tolerance
+    }
+    ///
+    /// Returns the norm of `residual`, the levers are scaled by the size of the required volume.
+    fn norm(&self, [transverse, longitudinal, volume]: [f64; 3]) -> f64 {
+        let size = self.volume.cbrt();
+        ((transverse / size).powi(2) + (longitudinal / size).powi(2) + volume.powi(2)).sqrt()
+    }
+}
+///
+/// Returns the solution of the linear system `a * x = b` by Cramer's rule,
+/// [None] if the system is singular.
+fn solve_linear(a: [[f64; 3]; 3], b: [f64; 3]) -> Option<[f64; 3]> {
+    let det = |m: [[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let det_a = det(a);
+    if det_a == 0.0 || !det_a.is_finite() {
+        return None;
+    }
+    let x = [0, 1, 2].map(|j| {
+        let mut m = a;
+        for i in 0..3 {
+            m[i][j] = b[i];
+        }
+        det(m) / det_a
+    });
+    x.iter().all(|x| x.is_finite()).then_some(x)
+}
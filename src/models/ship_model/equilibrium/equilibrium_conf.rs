@@ -0,0 +1,45 @@
+///
+/// [super::Equilibrium] configuration.
+#[derive(Clone, Debug)]
+pub struct EquilibriumConf {
+    ///
+    /// Allowed difference of the submerged volume and the required one relative to the latter.
+    pub volume_tolerance: f64,
+    ///
+    /// Allowed distance in meters between the centers of buoyancy and gravity
+    /// measured along the waterplane.
+    pub lever_tolerance: f64,
+    ///
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+    ///
+    /// Maximum change of heel and trim in degrees per iteration.
+    pub max_angle_step: f64,
+    ///
+    /// Step of draught in meters used for finite differences.
+    pub draught_delta: f64,
+    ///
+    /// Step of heel and trim in degrees used for finite differences.
+    pub angle_delta: f64,
+    ///
+    /// Refine the position found by the floating position cache
+    /// calculating the submerged volume of the model directly.
+    ///
+    /// It's much slower, but free of the interpolation error.
+    pub refine: bool,
+}
+//
+//
+impl Default for EquilibriumConf {
+    fn default() -> Self {
+        Self {
+            volume_tolerance: 1e-4,
+            lever_tolerance: 1e-3,
+            max_iterations: 50,
+            max_angle_step: 5.0,
+            draught_delta: 1e-3,
+            angle_delta: 0.1,
+            refine: false,
+        }
+    }
+}
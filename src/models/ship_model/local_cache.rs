@@ -5,34 +5,211 @@
 //! The triat provides an interface to
 //! - calculate and store the dataset into configured file,
 //! - reload the stored dataset for the current cache,
-//! - calculate and get rows for given approximated values.
+//! - calculate and get rows for given approximated values,
+//! - solve rows for given target values.
+//! - check whether the stored dataset is up to date.
 //
 pub(super) mod cache_key;
+mod calculated_grid;
+pub mod cross_curves_cache;
 pub mod floating_position_cache;
+pub mod hydrostatics_cache;
+pub mod progress;
+pub mod tank_cache;
 //
-use crate::common::cache::Cache;
+use crate::common::cache::{
+    error::CacheError, extrapolation::Extrapolation, header::Header, interpolation::Interpolation,
+    Cache,
+};
+use indexmap::IndexMap;
+use progress::Progress;
 use sal_sync::services::{
-    entity::error::str_err::StrErr, service::service_handles::ServiceHandles,
+    entity::{dbg_id::DbgId, error::str_err::StrErr},
+    service::service_handles::ServiceHandles,
+};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    sync::{atomic::AtomicBool, Arc, OnceLock},
 };
-use std::sync::{atomic::AtomicBool, Arc};
 ///
 /// A common trait for caches, which work with file systems.
 pub(super) trait LocalCache {
     ///
     /// Builds and stores the cache dataset.
     ///
-    /// This method spawns a worker thread internally and returns its handler.
-    /// Setting `exit` to _true_ at the caller side stops the worker.
+    /// This method spawns worker threads internally and returns their handlers.
+    /// Setting `exit` to _true_ at the caller side stops the workers.
+    /// The workers report how far the job has gone to `progress`.
     fn calculate(
         &self,
         exit: Arc<AtomicBool>,
+        progress: Arc<Progress>,
     ) -> Result<ServiceHandles<Result<(), StrErr>>, StrErr>;
     ///
     /// Returns approximated values based on given set.
-    fn get(&self, approx_vals: &[Option<f64>]) -> Option<Vec<Vec<f64>>>;
+    ///
+    /// # Errors
+    /// See [Cache::try_get] for details.
+    fn get(&self, approx_vals: &[Option<f64>]) -> Result<Vec<Vec<f64>>, CacheError>;
+    ///
+    /// Returns rows reaching given values along the column `unknown`.
+    ///
+    /// # Errors
+    /// See [Cache::solve] for details.
+    fn solve(
+        &self,
+        fixed_vals: &[Option<f64>],
+        unknown: usize,
+    ) -> Result<Vec<Vec<f64>>, CacheError>;
     ///
     /// Reloads caches.
     ///
     /// Typicaly, calling of this method should follow a call of [LocalCache::calculate].
     fn reload(&mut self);
+    ///
+    /// Returns the error if the stored dataset is missing, broken,
+    /// or calculated for another model or configuration (stale).
+    ///
+    /// The dataset is considered to be ready for use if the result is _Ok_.
+    fn check(&self) -> Result<(), CacheError>;
+}
+///
+/// The file storing the dataset of [LocalCache].
+///
+/// The stored dataset is up to date if its header matches the fingerprint
+/// (see [CacheFile::fingerprint]).
+pub(super) struct CacheFile {
+    dbgid: DbgId,
+    path: PathBuf,
+    columns: &'static [&'static str],
+    axes: &'static [&'static str],
+    units: &'static [&'static str],
+    model_path: PathBuf,
+    ///
+    /// Hash of the model file (see [Header::hash_file]),
+    /// the error if the model file is unreadable.
+    model_hash: Result<String, CacheError>,
+    ///
+    /// Configuration the dataset is calculated with (see [Header::meta]).
+    meta: IndexMap<String, String>,
+    interpolation: Interpolation,
+    extrapolation: Extrapolation,
+    ///
+    /// Dataset read from `self.path` on the first access.
+    cache: OnceLock<Result<Cache<f64>, CacheError>>,
+}
+//
+//
+impl CacheFile {
+    ///
+    /// Creates a new instance of the file at `path` storing `columns` measured in `units`.
+    /// - axes - names of the columns being the grid axes (see [Header::axes])
+    /// - model_path, model_hash - the model file the dataset is calculated for and its hash
+    pub(super) fn new(
+        parent: &DbgId,
+        path: PathBuf,
+        columns: &'static [&'static str],
+        axes: &'static [&'static str],
+        units: &'static [&'static str],
+        model_path: &Path,
+        model_hash: Result<String, CacheError>,
+    ) -> Self {
+        Self {
+            dbgid: DbgId::with_parent(parent, "CacheFile"),
+            path,
+            columns,
+            axes,
+            units,
+            model_path: model_path.to_owned(),
+            model_hash,
+            meta: IndexMap::new(),
+            interpolation: Interpolation::default(),
+            extrapolation: Extrapolation::default(),
+            cache: OnceLock::new(),
+        }
+    }
+    ///
+    /// Returns the instance with the configuration entry (see [Header::with_meta]).
+    pub(super) fn with_meta(mut self, key: impl Into<String>, value: impl fmt::Display) -> Self {
+        self.meta.insert(key.into(), value.to_string());
+        self
+    }
+    ///
+    /// Returns the instance, which dataset uses `interpolation` (see [Cache::with_interpolation]).
+    pub(super) fn with_interpolation(self, interpolation: Interpolation) -> Self {
+        Self {
+            interpolation,
+            ..self
+        }
+    }
+    ///
+    /// Returns the instance, which dataset uses `extrapolation` (see [Cache::with_extrapolation]).
+    pub(super) fn with_extrapolation(self, extrapolation: Extrapolation) -> Self {
+        Self {
+            extrapolation,
+            ..self
+        }
+    }
+    ///
+    /// Returns the path of the file.
+    pub(super) fn path(&self) -> &Path {
+        &self.path
+    }
+    ///
+    /// Returns the header entries identifying the model and the configuration
+    /// the dataset is calculated for: columns, grid axes, model hash, and configuration entries.
+    ///
+    /// Returns the error if the model hash is not available,
+    /// so the dataset is never taken as up to date for the unknown model.
+    pub(super) fn fingerprint(&self) -> Result<Header, CacheError> {
+        Ok(Header {
+            columns: Vec::from_iter(self.columns.iter().map(|s| s.to_string())),
+            axes: Vec::from_iter(self.axes.iter().map(|s| s.to_string())),
+            model_hash: self.model_hash.clone()?,
+            meta: self.meta.clone(),
+            ..Header::default()
+        })
+    }
+    ///
+    /// Creates the header of the file to be calculated:
+    /// the fingerprint (see [CacheFile::fingerprint]) completed with
+    /// units, model path, generator, and creation time.
+    pub(super) fn create_header(&self) -> Result<Header, StrErr> {
+        let fingerprint = self.fingerprint()?;
+        Ok(Header {
+            meta: fingerprint.meta,
+            ..Header::new(self.columns, self.units)
+                .with_axes(self.axes)
+                .with_model(&self.model_path, fingerprint.model_hash)
+        })
+    }
+    ///
+    /// Returns the dataset, which header must match the fingerprint
+    /// (see [CacheFile::fingerprint]).
+    ///
+    /// The file is read on the first access (see [Cache::new]).
+    pub(super) fn cache(&self) -> Result<&Cache<f64>, CacheError> {
+        self.cache
+            .get_or_init(|| {
+                let expected = self.fingerprint()?;
+                Ok(Cache::new(&self.dbgid, &self.path)
+                    .with_interpolation(self.interpolation)
+                    .with_extrapolation(self.extrapolation)
+                    .with_expected_header(expected))
+            })
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+    ///
+    /// Drops the dataset read, so the next access reads the file again.
+    pub(super) fn reload(&mut self) {
+        self.cache = OnceLock::new();
+    }
+    ///
+    /// Returns the error if the stored dataset is missing, broken,
+    /// or its header doesn't match the fingerprint (see [CacheFile::fingerprint]).
+    pub(super) fn check(&self) -> Result<(), CacheError> {
+        self.cache()?.header().map(|_| ())
+    }
 }
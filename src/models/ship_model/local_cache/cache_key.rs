@@ -11,11 +11,28 @@ use strum_macros::EnumIter;
 /// Cache keys of [ShipModel] caches.
 ///
 /// [ShipModel]: super::super::ShipModel
-#[derive(Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, EnumIter)]
 pub enum CacheKey {
     ///
     /// Points to [FloatingPositionCache].
     ///
     /// [FloatingPositionCache]: super::floating_position_cache::FloatingPositionCache
     FloatingPostion,
+    ///
+    /// Points to [HydrostaticsCache].
+    ///
+    /// [HydrostaticsCache]: super::hydrostatics_cache::HydrostaticsCache
+    Hydrostatics,
+    ///
+    /// Points to [CrossCurvesCache].
+    ///
+    /// [CrossCurvesCache]: super::cross_curves_cache::CrossCurvesCache
+    CrossCurves,
+    ///
+    /// Points to [TankCache] of the tank at the index in the ship model configuration
+    /// (see [ShipModelConf::tank_cache_confs]).
+    ///
+    /// [TankCache]: super::tank_cache::TankCache
+    /// [ShipModelConf::tank_cache_confs]: super::super::ship_model_conf::ShipModelConf::tank_cache_confs
+    Tank(usize),
 }
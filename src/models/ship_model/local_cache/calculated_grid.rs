@@ -0,0 +1,438 @@
+#[cfg(test)]
+#[path = "../../../tests/models/ship_model/local_cache/calculated_grid_test.rs"]
+mod tests;
+//
+use super::{super::waterline::Waterline, progress::Progress};
+use crate::common::cache::header::Header;
+use sal_sync::services::{
+    entity::{dbg_id::DbgId, error::str_err::StrErr},
+    service::service_handles::ServiceHandles,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+///
+/// State of the calculation shared between the workers.
+struct Job {
+    ///
+    /// Rows calculated by the workers, a slot per chunk of the grid.
+    ///
+    /// The slot is _None_ until its worker finishes the chunk.
+    chunks: Mutex<Vec<Option<Vec<Vec<f64>>>>>,
+    ///
+    /// Rows restored from the partial file (see [CalculatedGrid::restore]).
+    restored: Vec<Vec<f64>>,
+    ///
+    /// Partial file each calculated row is appended to, closed once the job is done.
+    partial: Mutex<Option<File>>,
+}
+///
+/// Returns values of the model submerged by [Waterline] at the grid point
+/// (e. g. heel, trim, and draught).
+pub(super) type Function<A> =
+    Arc<dyn Fn(&Waterline<A>, [f64; 3]) -> Result<Vec<f64>, StrErr> + Send + Sync>;
+///
+/// Provides logic to calculate and store the dataset of the cache defined on the grid
+/// of waterline positions (e. g. [super::floating_position_cache::FloatingPositionCache]).
+///
+/// The grid has three axes, the points go in order of the file rows:
+/// the last axis is the outermost one, then the first and the second axes.
+///
+/// Each row of the dataset is the grid point followed by the values of `function` at this point.
+/// The grid is split between the worker threads.
+///
+/// The target file is never partially filled. While calculating, the rows are appended
+/// to the partial file next to the target one (see [CalculatedGrid::partial_path]).
+/// Once all rows are calculated, the target file is replaced at once.
+#[derive(Clone)]
+pub(super) struct CalculatedGrid<A> {
+    dbgid: DbgId,
+    file_path: PathBuf,
+    waterline: Waterline<A>,
+    ///
+    /// Calculates values of each row.
+    function: Function<A>,
+    ///
+    /// Written to the beginning of the file.
+    header: Header,
+    ///
+    /// Steps of the grid axes (e. g. heel, trim, and draught).
+    steps: [Vec<f64>; 3],
+    ///
+    /// Number of worker threads, zero means the number of available CPUs.
+    workers: usize,
+    ///
+    /// Continue the interrupted calculation (see [CalculatedGrid::restore]).
+    resume: bool,
+    ///
+    /// Used to stop started worker threads.
+    ///
+    /// See [CalculatedGrid::calculate] for details.
+    exit: Arc<AtomicBool>,
+    ///
+    /// Updated by the workers on each point.
+    progress: Arc<Progress>,
+}
+//
+//
+impl<A: Clone> CalculatedGrid<A> {
+    ///
+    /// Crates a new instance.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        parent: &DbgId,
+        file_path: PathBuf,
+        waterline: Waterline<A>,
+        function: Function<A>,
+        header: Header,
+        steps: [Vec<f64>; 3],
+        workers: usize,
+        resume: bool,
+        exit: Arc<AtomicBool>,
+        progress: Arc<Progress>,
+    ) -> Self {
+        Self {
+            dbgid: DbgId::with_parent(parent, "CalculatedGrid"),
+            file_path,
+            waterline,
+            function,
+            header,
+            steps,
+            workers,
+            resume,
+            exit,
+            progress,
+        }
+    }
+    ///
+    /// Creates and starts workers calculating the dataset (see [super::LocalCache::calculate]).
+    ///
+    /// In resume mode, the points restored from the partial file are skipped.
+    /// The rest points of the grid are split into contiguous chunks of equal size,
+    /// one per worker, so there are no more workers than points.
+    /// If some worker fails to start or to calculate, the rest ones are stopped via `self.exit`.
+    pub(super) fn build(self) -> Result<ServiceHandles<Result<(), StrErr>>, StrErr>
+    where
+        A: Send + 'static,
+    {
+        let dbgid = DbgId(format!("{}.build", self.dbgid));
+        log::info!("{} | Starting...", dbgid);
+        let points = self.points();
+        let restored = match self.resume {
+            true => self.restore(&points),
+            false => vec![],
+        };
+        let partial = self
+            .open_partial(&restored)
+            .map_err(|err| StrErr(format!("{} | {}", dbgid, err)))?;
+        self.progress.start(points.len(), restored.len());
+        let points = match restored.is_empty() {
+            true => points,
+            false => {
+                let done = HashSet::<_>::from_iter(restored.iter().map(|row| Self::key(row)));
+                Vec::from_iter(
+                    points
+                        .into_iter()
+                        .filter(|point| !done.contains(&Self::key(point))),
+                )
+            }
+        };
+        let workers = match self.workers {
+            0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            workers => workers,
+        }
+        .clamp(1, points.len().max(1));
+        let chunk_len = points.len().div_ceil(workers).max(1);
+        let mut chunks = Vec::from_iter(points.chunks(chunk_len).map(<[_]>::to_vec));
+        // the empty grid still needs a worker to write the file
+        if chunks.is_empty() {
+            chunks.push(vec![]);
+        }
+        let job = Arc::new(Job {
+            chunks: Mutex::new(vec![None; chunks.len()]),
+            restored,
+            partial: Mutex::new(Some(partial)),
+        });
+        let mut handles = vec![];
+        for (chunk_id, chunk) in chunks.into_iter().enumerate() {
+            let worker = self.clone();
+            let job = job.clone();
+            let name = format!("{}.worker_{}", self.dbgid, chunk_id);
+            match thread::Builder::new().name(name.clone()).spawn(move || {
+                let (exit, progress) = (worker.exit.clone(), worker.progress.clone());
+                worker.calculate(chunk_id, chunk, job).inspect_err(|err| {
+                    // the dataset can't be completed, so the rest workers are useless
+                    exit.store(true, Ordering::SeqCst);
+                    progress.fail(err);
+                })
+            }) {
+                Ok(handle) => handles.push((name, handle)),
+                Err(why) => {
+                    self.exit.store(true, Ordering::SeqCst);
+                    let err_msg = format!("{} | Starting worker={} - FAILED: {}", dbgid, name, why);
+                    log::warn!("{}", err_msg);
+                    let err = StrErr(err_msg);
+                    self.progress.fail(&err);
+                    return Err(err);
+                }
+            }
+        }
+        log::info!("{} | Starting {} workers - OK", dbgid, handles.len());
+        Ok(ServiceHandles::new(handles))
+    }
+    ///
+    /// Returns points of the grid in order of the file rows.
+    fn points(&self) -> Vec<[f64; 3]> {
+        let [first, second, last] = &self.steps;
+        let mut points = Vec::with_capacity(first.len() * second.len() * last.len());
+        for &z in last {
+            for &x in first {
+                for &y in second {
+                    points.push([x, y, z]);
+                }
+            }
+        }
+        points
+    }
+    ///
+    /// Returns the key identifying the grid point of `row`.
+    fn key(row: &[f64]) -> [u64; 3] {
+        [row[0].to_bits(), row[1].to_bits(), row[2].to_bits()]
+    }
+    ///
+    /// Returns path of the partial file, which is kept until the calculation is done.
+    fn partial_path(&self) -> PathBuf {
+        with_suffix(&self.file_path, ".partial")
+    }
+    ///
+    /// Returns rows of `points` calculated by the interrupted job and stored in the partial file.
+    ///
+    /// The partial file is ignored if its header doesn't match `self.header`
+    /// (e. g. the model or the steps have changed since).
+    /// Rows out of `points` and the last line cut off by the interruption are ignored too.
+    fn restore(&self, points: &[[f64; 3]]) -> Vec<Vec<f64>> {
+        let dbgid = DbgId(format!("{}.restore", self.dbgid));
+        let path = self.partial_path();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) => {
+                if err.kind() != io::ErrorKind::NotFound {
+                    log::warn!("{} | Reading file='{}': {}", dbgid, path.display(), err);
+                }
+                return vec![];
+            }
+        };
+        match Header::parse(&text).map(|header| header.map(|header| header.check(&self.header))) {
+            Ok(Some(Ok(_))) => {}
+            Ok(Some(Err(err))) | Err(err) => {
+                log::warn!(
+                    "{} | Starting over, file='{}' doesn't match: {}",
+                    dbgid,
+                    path.display(),
+                    err
+                );
+                return vec![];
+            }
+            Ok(None) => {
+                log::warn!(
+                    "{} | Starting over, file='{}' has no header",
+                    dbgid,
+                    path.display()
+                );
+                return vec![];
+            }
+        }
+        let grid = HashSet::<_>::from_iter(points.iter().map(|point| Self::key(point)));
+        let mut rows = HashMap::new();
+        for line in text
+            .split_inclusive('\n')
+            .filter(|line| !Header::is_header_line(line))
+        {
+            // the last line may be cut off by the interruption
+            if !line.ends_with('\n') {
+                break;
+            }
+            let vals =
+                Result::<Vec<f64>, _>::from_iter(line.split_ascii_whitespace().map(str::parse));
+            match vals {
+                Ok(row)
+                    if row.len() == self.header.columns.len()
+                        && grid.contains(&Self::key(&row)) =>
+                {
+                    rows.insert(Self::key(&row), row);
+                }
+                _ => log::warn!("{} | Skipped line='{}'", dbgid, line.trim_end()),
+            }
+        }
+        log::info!(
+            "{} | Restored {} of {} points from file='{}'",
+            dbgid,
+            rows.len(),
+            points.len(),
+            path.display()
+        );
+        Vec::from_iter(rows.into_values())
+    }
+    ///
+    /// Creates the partial file containing `self.header` and `restored` rows,
+    /// and returns it opened for appending.
+    fn open_partial(&self, restored: &[Vec<f64>]) -> Result<File, StrErr> {
+        let path = self.partial_path();
+        self.store(&path, restored.iter())?;
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .map_err(|err| StrErr(format!("Opening file='{}': {}", path.display(), err)))
+    }
+    ///
+    /// Calculates rows of `points` being the chunk `chunk_id` of the grid,
+    /// and puts them into the slot of `job`.
+    ///
+    /// Each row is appended to the partial file as soon as it is calculated,
+    /// so the calculation can be resumed after the interruption.
+    /// The caller can stop executing by setting `self.exit` to _true_.
+    /// In this case the chunk is dropped, and the target file is left untouched.
+    /// Each point is reported to `self.progress`.
+    ///
+    /// Each row has format "{point...} {values...}" (see [CalculatedGrid::function]).
+    /// The worker finishing the last chunk stores all rows in order of the grid
+    /// into `self.file_path` (see [CalculatedGrid::finish]),
+    /// so the file is the same regardless of the number of workers and interruptions.
+    fn calculate(
+        self,
+        chunk_id: usize,
+        points: Vec<[f64; 3]>,
+        job: Arc<Job>,
+    ) -> Result<(), StrErr> {
+        let dbgid = DbgId(format!("{}.calculate_{}", self.dbgid, chunk_id));
+        let mut rows = Vec::with_capacity(points.len());
+        for point in points {
+            // _true_ if the caller has requisted to exit.
+            if self.exit.load(Ordering::SeqCst) {
+                log::warn!("{} | Interrupted: `exit` has got true", dbgid);
+                return Ok(());
+            }
+            self.progress.current(&point);
+            let mut row = point.to_vec();
+            row.extend((self.function)(&self.waterline, point)?);
+            self.append(&job, &row)
+                .map_err(|err| StrErr(format!("{} | {}", dbgid, err)))?;
+            rows.push(row);
+            self.progress.step();
+        }
+        let mut chunks = job
+            .chunks
+            .lock()
+            .map_err(|err| StrErr(format!("{} | Locking chunks: {}", dbgid, err)))?;
+        chunks[chunk_id] = Some(rows);
+        if chunks.iter().all(Option::is_some) {
+            log::debug!("{} | All {} chunks are calculated", dbgid, chunks.len());
+            self.finish(&job, chunks.iter().flatten().flatten())
+                .map_err(|err| StrErr(format!("{} | {}", dbgid, err)))?;
+        }
+        Ok(())
+    }
+    ///
+    /// Appends `row` to the partial file of `job`.
+    fn append(&self, job: &Job, row: &[f64]) -> Result<(), StrErr> {
+        let mut partial = job
+            .partial
+            .lock()
+            .map_err(|err| StrErr(format!("Locking partial file: {}", err)))?;
+        match partial.as_mut() {
+            Some(file) => file.write_all(Self::line(row).as_bytes()).map_err(|err| {
+                StrErr(format!(
+                    "Writing to file='{}': {}",
+                    self.partial_path().display(),
+                    err
+                ))
+            }),
+            None => Ok(()),
+        }
+    }
+    ///
+    /// Stores restored rows of `job` and `calculated` ones in order of the grid into `self.file_path`,
+    /// then removes the partial file.
+    fn finish<'a>(
+        &self,
+        job: &'a Job,
+        calculated: impl Iterator<Item = &'a Vec<f64>>,
+    ) -> Result<(), StrErr> {
+        let rows = HashMap::<_, _>::from_iter(
+            job.restored
+                .iter()
+                .chain(calculated)
+                .map(|row| (Self::key(row), row)),
+        );
+        let rows = Result::<Vec<_>, _>::from_iter(self.points().iter().map(|point| {
+            rows.get(&Self::key(point))
+                .copied()
+                .ok_or_else(|| StrErr(format!("Missing row of point={:?}", point)))
+        }))?;
+        self.store(&self.file_path, rows.into_iter())?;
+        // close the partial file before removing
+        if let Ok(mut partial) = job.partial.lock() {
+            partial.take();
+        }
+        let path = self.partial_path();
+        if let Err(err) = fs::remove_file(&path) {
+            log::warn!(
+                "{}.finish | Removing file='{}': {}",
+                self.dbgid,
+                path.display(),
+                err
+            );
+        }
+        Ok(())
+    }
+    ///
+    /// Stores `self.header` followed by `rows` into `path`.
+    ///
+    /// The file at `path` is replaced atomically: the content is written to the temporary file,
+    /// which is renamed to `path` on success.
+    fn store<'a>(
+        &self,
+        path: &Path,
+        rows: impl Iterator<Item = &'a Vec<f64>>,
+    ) -> Result<(), StrErr> {
+        let tmp_path = with_suffix(path, ".tmp");
+        let err = |action: &str, path: &Path, err: io::Error| {
+            StrErr(format!("{} file='{}': {}", action, path.display(), err))
+        };
+        let out_f = File::create(&tmp_path).map_err(|e| err("Creating", &tmp_path, e))?;
+        let mut out = BufWriter::new(out_f);
+        write!(out, "{}", self.header).map_err(|e| err("Writing header to", &tmp_path, e))?;
+        for row in rows {
+            out.write_all(Self::line(row).as_bytes())
+                .map_err(|e| err("Writing to", &tmp_path, e))?;
+        }
+        out.into_inner()
+            .map_err(|e| err("Writing to", &tmp_path, e.into_error()))?
+            .sync_all()
+            .map_err(|e| err("Syncing", &tmp_path, e))?;
+        fs::rename(&tmp_path, path).map_err(|e| err("Renaming to", path, e))
+    }
+    ///
+    /// Returns `row` formatted as a line of the file.
+    fn line(row: &[f64]) -> String {
+        let mut line = Vec::from_iter(row.iter().map(f64::to_string)).join(" ");
+        line.push('\n');
+        line
+    }
+}
+///
+/// Returns `path` with `suffix` appended to its file name.
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    path.into()
+}
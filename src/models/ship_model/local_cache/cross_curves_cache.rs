@@ -0,0 +1,177 @@
+mod calculated_cross_curves_cache;
+pub mod cross_curves_cache_conf;
+#[cfg(test)]
+#[path = "../../../tests/models/ship_model/local_cache/cross_curves_cache_test.rs"]
+mod tests;
+//
+use super::{
+    super::{waterline::Waterline, ModelTree},
+    progress::Progress,
+    CacheFile, LocalCache,
+};
+use crate::common::cache::error::CacheError;
+use calculated_cross_curves_cache::CalculatedCrossCurvesCache;
+use cross_curves_cache_conf::CrossCurvesCacheConf;
+use sal_sync::services::{
+    entity::{dbg_id::DbgId, error::str_err::StrErr},
+    service::service_handles::ServiceHandles,
+};
+use std::{
+    path::Path,
+    sync::{atomic::AtomicBool, Arc},
+};
+///
+/// Pre-calculated cross curves of stability: KN levers for each heel and displacement.
+///
+/// See [CrossCurvesCacheConf] for more details about the configuration,
+/// and [CrossCurvesCache::COLUMNS] for the stored values.
+pub(in super::super) struct CrossCurvesCache<A> {
+    dbgid: DbgId,
+    conf: CrossCurvesCacheConf,
+    ///
+    /// Model representation used for cache calculation.
+    model_tree: ModelTree<A>,
+    ///
+    /// File of the cache, which configuration entries are model keys, waterline position,
+    /// steps, water density, the reference point, and the equilibrium tolerances and steps.
+    file: CacheFile,
+}
+//
+//
+impl<A> CrossCurvesCache<A> {
+    //
+    //
+    const KEY: &'static str = "cross_curves_cache";
+    ///
+    /// Names of the cache columns:
+    /// - heel, trim (the fixed one, see [CrossCurvesCacheConf::trim]), and displacement,
+    /// - KN at the fixed trim,
+    /// - KN at the free trim and the free trim itself.
+    ///
+    /// KN is measured from [CrossCurvesCacheConf::reference_point].
+    pub(in super::super) const COLUMNS: &'static [&'static str] = &[
+        "heel",
+        "trim",
+        "displacement",
+        "kn_fixed",
+        "kn_free",
+        "free_trim",
+    ];
+    ///
+    /// Grid axes of the cache: heel and displacement steps combined at the fixed trim.
+    const AXES: &'static [&'static str] = &["heel", "trim", "displacement"];
+    ///
+    /// Units of the cache columns.
+    const UNITS: &'static [&'static str] = &["deg", "deg", "t", "m", "m", "deg"];
+    ///
+    /// Creates a new instance.
+    /// - path - folder contains all cache files
+    /// - model_hash - hash of the model file (see [CacheFile::new])
+    pub(in super::super) fn new(
+        parent: &DbgId,
+        model_tree: ModelTree<A>,
+        model_hash: Result<String, CacheError>,
+        path: impl AsRef<Path>,
+        conf: CrossCurvesCacheConf,
+    ) -> Self {
+        let dbgid = DbgId::with_parent(parent, "CrossCurvesCache");
+        let join = |vals: &[f64]| Vec::from_iter(vals.iter().map(f64::to_string)).join(" ");
+        let file = CacheFile::new(
+            &dbgid,
+            path.as_ref().join(Self::KEY),
+            Self::COLUMNS,
+            Self::AXES,
+            Self::UNITS,
+            model_tree.path(),
+            model_hash,
+        )
+        .with_meta("model_keys", conf.model_keys.join(" "))
+        .with_meta("waterline_position", join(&conf.waterline_position))
+        .with_meta("heel_steps", join(&conf.heel_steps))
+        .with_meta("trim", conf.trim)
+        .with_meta("displacement_steps", join(&conf.displacement_steps))
+        .with_meta("water_density", conf.water_density)
+        .with_meta("reference_point", join(&conf.reference_point))
+        .with_meta("equilibrium_conf", {
+            let conf = &conf.equilibrium_conf;
+            join(&[
+                conf.volume_tolerance,
+                conf.lever_tolerance,
+                conf.max_iterations as f64,
+                conf.max_angle_step,
+                conf.draught_delta,
+                conf.angle_delta,
+            ])
+        })
+        .with_interpolation(conf.interpolation)
+        .with_extrapolation(conf.extrapolation);
+        Self {
+            model_tree,
+            conf,
+            file,
+            dbgid,
+        }
+    }
+}
+//
+//
+impl<A: Clone + Send + 'static> LocalCache for CrossCurvesCache<A> {
+    ///
+    /// See [CalculatedCrossCurvesCache] for details.
+    fn calculate(
+        &self,
+        exit: Arc<AtomicBool>,
+        progress: Arc<Progress>,
+    ) -> Result<ServiceHandles<Result<(), StrErr>>, StrErr> {
+        let model_keys = &self.conf.model_keys;
+        let elements = self
+            .model_tree
+            .iter()
+            .filter_map(|(shape_key, shape)| {
+                (model_keys.is_empty() || model_keys.contains(shape_key)).then_some(shape)
+            })
+            .cloned()
+            .collect();
+        CalculatedCrossCurvesCache::new(
+            &self.dbgid,
+            self.file.path().to_owned(),
+            Waterline::new(
+                elements,
+                Waterline::create_face(&self.dbgid, self.conf.waterline_position)?,
+            ),
+            self.file.create_header()?,
+            self.conf.clone(),
+            exit,
+            progress,
+        )
+        .build()
+    }
+    ///
+    /// See [Cache::try_get] for details.
+    ///
+    /// [Cache::try_get]: crate::common::cache::Cache::try_get
+    fn get(&self, approx_vals: &[Option<f64>]) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.file.cache()?.try_get(approx_vals)
+    }
+    ///
+    /// See [Cache::solve] for details.
+    ///
+    /// [Cache::solve]: crate::common::cache::Cache::solve
+    fn solve(
+        &self,
+        fixed_vals: &[Option<f64>],
+        unknown: usize,
+    ) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.file.cache()?.solve(fixed_vals, unknown)
+    }
+    //
+    //
+    fn reload(&mut self) {
+        self.file.reload();
+    }
+    ///
+    /// See [CacheFile::check] for details.
+    fn check(&self) -> Result<(), CacheError> {
+        self.file.check()
+    }
+}
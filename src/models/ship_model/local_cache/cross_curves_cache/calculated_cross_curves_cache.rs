@@ -0,0 +1,130 @@
+use super::{
+    super::{
+        super::{
+            equilibrium::{equilibrium_conf::EquilibriumConf, Equilibrium, FloatingPosition},
+            waterline::{self, Waterline},
+        },
+        calculated_grid::CalculatedGrid,
+        progress::Progress,
+    },
+    CrossCurvesCacheConf,
+};
+use crate::common::cache::header::Header;
+use sal_sync::services::{
+    entity::{dbg_id::DbgId, error::str_err::StrErr},
+    service::service_handles::ServiceHandles,
+};
+use std::{
+    path::PathBuf,
+    sync::{atomic::AtomicBool, Arc},
+};
+///
+/// Provides logic to calculate and store cache used by [super::CrossCurvesCache].
+///
+/// For each heel and displacement the waterline is placed to submerge the required volume:
+/// - at the fixed trim (see [CrossCurvesCacheConf::trim]),
+/// - at the trim, which aligns the center of buoyancy with the reference point longitudinally
+///   (see [CrossCurvesCacheConf::reference_point]).
+///
+/// KN is the lever of the center of buoyancy about the reference point
+/// along the transverse axis of the waterplane.
+///
+/// The calculation itself is done by [CalculatedGrid], which is parallel, atomic, and resumable.
+pub(super) struct CalculatedCrossCurvesCache<A> {
+    grid: CalculatedGrid<A>,
+}
+//
+//
+impl<A: Clone + Send + 'static> CalculatedCrossCurvesCache<A> {
+    ///
+    /// Crates a new instance.
+    pub(super) fn new(
+        parent: &DbgId,
+        file_path: PathBuf,
+        waterline: Waterline<A>,
+        header: Header,
+        conf: CrossCurvesCacheConf,
+        exit: Arc<AtomicBool>,
+        progress: Arc<Progress>,
+    ) -> Self {
+        let dbgid = DbgId::with_parent(parent, "CalculatedCrossCurvesCache");
+        let water_density = conf.water_density;
+        let reference_point = conf.reference_point;
+        let equilibrium_conf = conf.equilibrium_conf;
+        Self {
+            grid: CalculatedGrid::new(
+                &dbgid,
+                file_path,
+                waterline,
+                Arc::new({
+                    let dbgid = dbgid.clone();
+                    move |waterline, point| {
+                        Self::cross_curves(
+                            &dbgid,
+                            &equilibrium_conf,
+                            waterline,
+                            point,
+                            water_density,
+                            reference_point,
+                        )
+                    }
+                }),
+                header,
+                [conf.heel_steps, vec![conf.trim], conf.displacement_steps],
+                conf.workers,
+                conf.resume,
+                exit,
+                progress,
+            ),
+        }
+    }
+    ///
+    /// Creates and starts workers for [CrossCurvesCache::calculate].
+    ///
+    /// Each row of the file has the values of [CrossCurvesCache::COLUMNS],
+    /// the rows follow the header.
+    pub(super) fn build(self) -> Result<ServiceHandles<Result<(), StrErr>>, StrErr> {
+        self.grid.build()
+    }
+    ///
+    /// Returns KN at the fixed trim, KN at the free trim, and the free trim
+    /// of the model submerged by `waterline` at `heel` and `trim` (in degrees),
+    /// and displacing `displacement` tons of water of `water_density`.
+    ///
+    /// The waterline is placed by [Equilibrium] configured with `equilibrium_conf`.
+    fn cross_curves(
+        parent: &DbgId,
+        equilibrium_conf: &EquilibriumConf,
+        waterline: &Waterline<A>,
+        [heel, trim, displacement]: [f64; 3],
+        water_density: f64,
+        reference_point: [f64; 3],
+    ) -> Result<Vec<f64>, StrErr> {
+        let volume = displacement / water_density;
+        let submerged = |position| waterline.submerged(position, None);
+        let equilibrium =
+            || Equilibrium::new(parent, equilibrium_conf.clone(), volume, reference_point);
+        let fixed = equilibrium()
+            .with_fixed_heel()
+            .with_fixed_trim()
+            .solve([heel, trim, 0.0], submerged)?;
+        let free = equilibrium()
+            .with_fixed_heel()
+            .solve([heel, trim, fixed.draught], submerged)?;
+        Ok(vec![
+            Self::kn(&fixed, reference_point),
+            Self::kn(&free, reference_point),
+            free.trim,
+        ])
+    }
+    ///
+    /// Returns the lever of the center of buoyancy at `position` about `reference_point`
+    /// along the transverse axis of the waterplane.
+    fn kn(position: &FloatingPosition, reference_point: [f64; 3]) -> f64 {
+        let [_, loc_y, _] = waterline::axes(position.heel, position.trim);
+        waterline::dot(
+            [0, 1, 2].map(|i| position.center_of_buoyancy[i] - reference_point[i]),
+            loc_y,
+        )
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    common::cache::{extrapolation::Extrapolation, interpolation::Interpolation},
+    models::ship_model::equilibrium::equilibrium_conf::EquilibriumConf,
+};
+///
+/// [super::CrossCurvesCache] configuration.
+#[derive(Clone, Debug)]
+pub struct CrossCurvesCacheConf {
+    ///
+    /// Keys of the model elements making the hull, all elements are used if empty.
+    pub model_keys: Vec<String>,
+    ///
+    /// Waterline initial position in 3D space.
+    pub waterline_position: [f64; 3],
+    ///
+    /// Angle in degrees, it must be less than 90.
+    pub heel_steps: Vec<f64>,
+    ///
+    /// Angle in degrees the fixed-trim cross curves are calculated at.
+    pub trim: f64,
+    ///
+    /// Displacement in tons.
+    pub displacement_steps: Vec<f64>,
+    ///
+    /// Density of water in t/m3, used to get volume from displacement.
+    pub water_density: f64,
+    ///
+    /// Point the KN levers are measured from, usually at the keel in the center plane.
+    ///
+    /// The free-trim cross curves are balanced against its longitudinal position,
+    /// so it's supposed to be placed at the longitudinal center of gravity
+    /// (the free trim GZ curve is refused for other LCG, see [ShipModel::gz_curve]).
+    ///
+    /// [ShipModel::gz_curve]: crate::models::ship_model::ShipModel::gz_curve
+    pub reference_point: [f64; 3],
+    ///
+    /// Tolerances and steps of the search for the waterline submerging the required volume
+    /// at each point, [EquilibriumConf::refine] isn't used.
+    pub equilibrium_conf: EquilibriumConf,
+    ///
+    /// Method used to approximate values in between the steps.
+    pub interpolation: Interpolation,
+    ///
+    /// Policy used for values out of the steps range.
+    pub extrapolation: Extrapolation,
+    ///
+    /// Number of worker threads calculating the dataset.
+    ///
+    /// Zero means the number of available CPUs.
+    pub workers: usize,
+    ///
+    /// Continue the interrupted calculation from its partial file instead of starting over.
+    pub resume: bool,
+}
+//
+//
+impl Default for CrossCurvesCacheConf {
+    fn default() -> Self {
+        Self {
+            model_keys: vec![],
+            waterline_position: [0.0; 3],
+            heel_steps: vec![],
+            trim: 0.0,
+            displacement_steps: vec![],
+            water_density: 1.025,
+            reference_point: [0.0; 3],
+            equilibrium_conf: EquilibriumConf::default(),
+            interpolation: Interpolation::default(),
+            extrapolation: Extrapolation::default(),
+            workers: 0,
+            resume: false,
+        }
+    }
+}
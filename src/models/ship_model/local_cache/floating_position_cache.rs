@@ -1,20 +1,24 @@
 mod calculated_floating_position_cache;
 pub mod floating_position_cache_conf;
+#[cfg(test)]
+#[path = "../../../tests/models/ship_model/local_cache/floating_position_cache_test.rs"]
+mod tests;
 //
-use super::{super::ModelTree, Cache, LocalCache};
+use super::{
+    super::{waterline::Waterline, ModelTree},
+    progress::Progress,
+    CacheFile, LocalCache,
+};
+use crate::common::cache::error::CacheError;
 use calculated_floating_position_cache::CalculatedFloatingPositionCache;
 use floating_position_cache_conf::FloatingPositionCacheConf;
-use sal_3dlib::topology::shape::{
-    face::Face,
-    vertex::Vertex,
-    wire::{Polygon, Wire},
-};
+use sal_3dlib::topology::shape::face::Face;
 use sal_sync::services::{
     entity::{dbg_id::DbgId, error::str_err::StrErr},
     service::service_handles::ServiceHandles,
 };
 use std::{
-    path::{Path, PathBuf},
+    path::Path,
     sync::{atomic::AtomicBool, Arc},
 };
 ///
@@ -23,18 +27,20 @@ use std::{
 /// See [FloatingPositionCacheConf] for more details about the fields.
 pub(in super::super) struct FloatingPositionCache<A> {
     dbgid: DbgId,
-    file_path: PathBuf,
     model_keys: Vec<String>,
     waterline_position: [f64; 3],
     heel_steps: Vec<f64>,
     trim_steps: Vec<f64>,
     draught_steps: Vec<f64>,
+    workers: usize,
+    resume: bool,
     ///
     /// Model representation used for cache calculation.
     model_tree: ModelTree<A>,
     ///
-    /// Cache read from `self.file_path`.
-    cache: Cache<f64>,
+    /// File of the cache, which configuration entries are model keys,
+    /// waterline position, and steps.
+    file: CacheFile,
 }
 //
 //
@@ -43,25 +49,59 @@ impl<A> FloatingPositionCache<A> {
     //
     const KEY: &'static str = "floating_position_cache";
     ///
+    /// Names of the cache columns:
+    /// - waterline position: heel, trim, and draught,
+    /// - submerged volume,
+    /// - center of buoyancy: longitudinal (LCB), transverse (TCB), and vertical (VCB).
+    ///
+    /// Coordinates are given in the model coordinate system.
+    const COLUMNS: &'static [&'static str] =
+        &["heel", "trim", "draught", "volume", "lcb", "tcb", "vcb"];
+    ///
+    /// Grid axes of the cache, the configured steps of which are all combined.
+    const AXES: &'static [&'static str] = &["heel", "trim", "draught"];
+    ///
+    /// Units of the cache columns.
+    const UNITS: &'static [&'static str] = &["deg", "deg", "m", "m3", "m", "m", "m"];
+    ///
     /// Creates a new instance.
     /// - path - folder contains all cache files
+    /// - model_hash - hash of the model file (see [CacheFile::new])
     pub(in super::super) fn new(
         parent: &DbgId,
         model_tree: ModelTree<A>,
+        model_hash: Result<String, CacheError>,
         path: impl AsRef<Path>,
         conf: FloatingPositionCacheConf,
     ) -> Self {
         let dbgid = DbgId::with_parent(parent, "FloatingPositionCache");
-        let file_path = path.as_ref().join(Self::KEY);
+        let join = |vals: &[f64]| Vec::from_iter(vals.iter().map(f64::to_string)).join(" ");
+        let file = CacheFile::new(
+            &dbgid,
+            path.as_ref().join(Self::KEY),
+            Self::COLUMNS,
+            Self::AXES,
+            Self::UNITS,
+            model_tree.path(),
+            model_hash,
+        )
+        .with_meta("model_keys", conf.model_keys.join(" "))
+        .with_meta("waterline_position", join(&conf.waterline_position))
+        .with_meta("heel_steps", join(&conf.heel_steps))
+        .with_meta("trim_steps", join(&conf.trim_steps))
+        .with_meta("draught_steps", join(&conf.draught_steps))
+        .with_interpolation(conf.interpolation)
+        .with_extrapolation(conf.extrapolation);
         Self {
             model_tree,
-            model_keys: vec![],
+            model_keys: conf.model_keys,
             heel_steps: conf.heel_steps,
             waterline_position: conf.waterline_position,
             trim_steps: conf.trim_steps,
             draught_steps: conf.draught_steps,
-            cache: Cache::new(&dbgid, &file_path),
-            file_path,
+            workers: conf.workers,
+            resume: conf.resume,
+            file,
             dbgid,
         }
     }
@@ -69,34 +109,8 @@ impl<A> FloatingPositionCache<A> {
     /// Creates a waterline object in 3D space centered at `self.waterline_position`.
     ///
     /// The result object is used for calculating cache algorithm (see [FloatingPositionCache::calculate]).
-    fn create_waterline<T>(&self) -> Result<Face<T>, StrErr> {
-        let dbgid = DbgId(format!("{}.create_waterline_model", self.dbgid));
-        let [x, y, z] = self.waterline_position;
-        // dynamic range could be built based on bounding box of target element behind self.model_keys,
-        // but now reserve big enough offsets, which should work with most elements
-        let dx = 1000.0;
-        let dy = 1000.0;
-        //
-        match Wire::polygon(
-            [
-                Vertex::new([x + dx, y + dy, z]),
-                Vertex::new([x - dx, y + dy, z]),
-                Vertex::new([x - dx, y - dy, z]),
-                Vertex::new([x + dx, y - dy, z]),
-            ],
-            true,
-        ) {
-            Ok(ref polygon) => Face::try_from(polygon).map_err(|why| {
-                StrErr(format!(
-                    "{} | Failed creating Face from *polygon*: {}",
-                    dbgid, why
-                ))
-            }),
-            Err(why) => Err(StrErr(format!(
-                "{} | Failed creating *polygon* from Wire: {}",
-                dbgid, why
-            ))),
-        }
+    fn create_waterline<T: Clone>(&self) -> Result<Face<T>, StrErr> {
+        Waterline::create_face(&self.dbgid, self.waterline_position)
     }
 }
 //
@@ -107,33 +121,50 @@ impl<A: Clone + Send + 'static> LocalCache for FloatingPositionCache<A> {
     fn calculate(
         &self,
         exit: Arc<AtomicBool>,
+        progress: Arc<Progress>,
     ) -> Result<ServiceHandles<Result<(), StrErr>>, StrErr> {
         CalculatedFloatingPositionCache::new(
             &self.dbgid,
-            self.file_path.clone(),
-            self.model_tree
-                .iter()
-                .filter_map(|(shape_key, shape)| {
-                    self.model_keys.contains(shape_key).then_some(shape)
-                })
-                .cloned()
-                .collect(),
+            self.file.path().to_owned(),
+            self.model_tree.elements(&self.model_keys),
             self.create_waterline()?,
+            self.file.create_header()?,
             self.heel_steps.clone(),
             self.trim_steps.clone(),
             self.draught_steps.clone(),
+            self.workers,
+            self.resume,
             exit,
+            progress,
         )
         .build()
     }
     ///
-    /// See [Cache::get] for details.
-    fn get(&self, approx_vals: &[Option<f64>]) -> Option<Vec<Vec<f64>>> {
-        self.cache.get(approx_vals)
+    /// See [Cache::try_get] for details.
+    ///
+    /// [Cache::try_get]: crate::common::cache::Cache::try_get
+    fn get(&self, approx_vals: &[Option<f64>]) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.file.cache()?.try_get(approx_vals)
+    }
+    ///
+    /// See [Cache::solve] for details.
+    ///
+    /// [Cache::solve]: crate::common::cache::Cache::solve
+    fn solve(
+        &self,
+        fixed_vals: &[Option<f64>],
+        unknown: usize,
+    ) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.file.cache()?.solve(fixed_vals, unknown)
     }
     //
     //
     fn reload(&mut self) {
-        self.cache = Cache::new(&self.dbgid, &self.file_path);
+        self.file.reload();
+    }
+    ///
+    /// See [CacheFile::check] for details.
+    fn check(&self) -> Result<(), CacheError> {
+        self.file.check()
     }
 }
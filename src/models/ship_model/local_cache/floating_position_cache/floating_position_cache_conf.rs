@@ -1,7 +1,13 @@
+use crate::common::cache::{extrapolation::Extrapolation, interpolation::Interpolation};
 ///
 /// [super::FloatingPositionCache] configuration.
 #[derive(Default)]
 pub struct FloatingPositionCacheConf {
+    ///
+    /// Keys of the model elements making the hull, all elements are used if empty.
+    ///
+    /// The other elements (e. g. tanks and compartments) don't give buoyancy.
+    pub model_keys: Vec<String>,
     ///
     /// Waterline initial position in 3D space.
     pub waterline_position: [f64; 3],
@@ -14,4 +20,20 @@ pub struct FloatingPositionCacheConf {
     ///
     /// TODO: clarify units.
     pub draught_steps: Vec<f64>,
+    ///
+    /// Method used to approximate values in between the steps.
+    pub interpolation: Interpolation,
+    ///
+    /// Policy used for values out of the steps range.
+    pub extrapolation: Extrapolation,
+    ///
+    /// Number of worker threads calculating the dataset.
+    ///
+    /// Zero means the number of available CPUs.
+    pub workers: usize,
+    ///
+    /// Continue the interrupted calculation from its partial file instead of starting over.
+    ///
+    /// The partial file is used only if it's calculated for the same model and steps.
+    pub resume: bool,
 }
@@ -0,0 +1,181 @@
+mod calculated_hydrostatics_cache;
+pub mod hydrostatics_cache_conf;
+#[cfg(test)]
+#[path = "../../../tests/models/ship_model/local_cache/hydrostatics_cache_test.rs"]
+mod tests;
+//
+use super::{
+    super::{waterline::Waterline, ModelTree},
+    progress::Progress,
+    CacheFile, LocalCache,
+};
+use crate::common::cache::error::CacheError;
+use calculated_hydrostatics_cache::CalculatedHydrostaticsCache;
+use hydrostatics_cache_conf::HydrostaticsCacheConf;
+use sal_sync::services::{
+    entity::{dbg_id::DbgId, error::str_err::StrErr},
+    service::service_handles::ServiceHandles,
+};
+use std::{
+    path::Path,
+    sync::{atomic::AtomicBool, Arc},
+};
+///
+/// Pre-calculated hydrostatics of the hull for each waterline position.
+///
+/// See [HydrostaticsCacheConf] for more details about the configuration,
+/// and [HydrostaticsCache::COLUMNS] for the stored values.
+pub(in super::super) struct HydrostaticsCache<A> {
+    dbgid: DbgId,
+    conf: HydrostaticsCacheConf,
+    ///
+    /// Model representation used for cache calculation.
+    model_tree: ModelTree<A>,
+    ///
+    /// File of the cache, which configuration entries are model keys, waterline position,
+    /// steps, water density, and steps of finite differences.
+    file: CacheFile,
+}
+//
+//
+impl<A> HydrostaticsCache<A> {
+    //
+    //
+    const KEY: &'static str = "hydrostatics_cache";
+    ///
+    /// Names of the cache columns:
+    /// - waterline position: heel, trim, and draught,
+    /// - submerged volume and displacement,
+    /// - center of buoyancy: longitudinal (LCB), transverse (TCB), and vertical (VCB),
+    /// - waterplane area (AWP) and its centroid, the center of flotation (LCF, TCF),
+    /// - transverse and longitudinal moments of inertia of the waterplane (IT, IL),
+    /// - transverse and longitudinal metacentric radii (BMT = IT / volume, BML = IL / volume).
+    ///
+    /// Coordinates are given in the model coordinate system.
+    pub(in super::super) const COLUMNS: &'static [&'static str] = &[
+        "heel",
+        "trim",
+        "draught",
+        "volume",
+        "displacement",
+        "lcb",
+        "tcb",
+        "vcb",
+        "awp",
+        "lcf",
+        "tcf",
+        "it",
+        "il",
+        "bmt",
+        "bml",
+    ];
+    ///
+    /// Grid axes of the cache, the configured steps of which are all combined.
+    const AXES: &'static [&'static str] = &["heel", "trim", "draught"];
+    ///
+    /// Units of the cache columns.
+    const UNITS: &'static [&'static str] = &[
+        "deg", "deg", "m", "m3", "t", "m", "m", "m", "m2", "m", "m", "m4", "m4", "m", "m",
+    ];
+    ///
+    /// Creates a new instance.
+    /// - path - folder contains all cache files
+    /// - model_hash - hash of the model file (see [CacheFile::new])
+    pub(in super::super) fn new(
+        parent: &DbgId,
+        model_tree: ModelTree<A>,
+        model_hash: Result<String, CacheError>,
+        path: impl AsRef<Path>,
+        conf: HydrostaticsCacheConf,
+    ) -> Self {
+        let dbgid = DbgId::with_parent(parent, "HydrostaticsCache");
+        let join = |vals: &[f64]| Vec::from_iter(vals.iter().map(f64::to_string)).join(" ");
+        let file = CacheFile::new(
+            &dbgid,
+            path.as_ref().join(Self::KEY),
+            Self::COLUMNS,
+            Self::AXES,
+            Self::UNITS,
+            model_tree.path(),
+            model_hash,
+        )
+        .with_meta("model_keys", conf.model_keys.join(" "))
+        .with_meta("waterline_position", join(&conf.waterline_position))
+        .with_meta("heel_steps", join(&conf.heel_steps))
+        .with_meta("trim_steps", join(&conf.trim_steps))
+        .with_meta("draught_steps", join(&conf.draught_steps))
+        .with_meta("water_density", conf.water_density)
+        .with_meta("draught_delta", conf.draught_delta)
+        .with_meta("angle_delta", conf.angle_delta)
+        .with_interpolation(conf.interpolation)
+        .with_extrapolation(conf.extrapolation);
+        Self {
+            model_tree,
+            conf,
+            file,
+            dbgid,
+        }
+    }
+}
+//
+//
+impl<A: Clone + Send + 'static> LocalCache for HydrostaticsCache<A> {
+    ///
+    /// See [CalculatedHydrostaticsCache] for details.
+    fn calculate(
+        &self,
+        exit: Arc<AtomicBool>,
+        progress: Arc<Progress>,
+    ) -> Result<ServiceHandles<Result<(), StrErr>>, StrErr> {
+        let model_keys = &self.conf.model_keys;
+        let elements = self
+            .model_tree
+            .iter()
+            .filter_map(|(shape_key, shape)| {
+                (model_keys.is_empty() || model_keys.contains(shape_key)).then_some(shape)
+            })
+            .cloned()
+            .collect();
+        CalculatedHydrostaticsCache::new(
+            &self.dbgid,
+            self.file.path().to_owned(),
+            Waterline::new(
+                elements,
+                Waterline::create_face(&self.dbgid, self.conf.waterline_position)?,
+            ),
+            self.file.create_header()?,
+            self.conf.clone(),
+            exit,
+            progress,
+        )
+        .build()
+    }
+    ///
+    /// See [Cache::try_get] for details.
+    ///
+    /// [Cache::try_get]: crate::common::cache::Cache::try_get
+    fn get(&self, approx_vals: &[Option<f64>]) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.file.cache()?.try_get(approx_vals)
+    }
+    ///
+    /// See [Cache::solve] for details.
+    ///
+    /// [Cache::solve]: crate::common::cache::Cache::solve
+    fn solve(
+        &self,
+        fixed_vals: &[Option<f64>],
+        unknown: usize,
+    ) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.file.cache()?.solve(fixed_vals, unknown)
+    }
+    //
+    //
+    fn reload(&mut self) {
+        self.file.reload();
+    }
+    ///
+    /// See [CacheFile::check] for details.
+    fn check(&self) -> Result<(), CacheError> {
+        self.file.check()
+    }
+}
@@ -0,0 +1,118 @@
+use super::{
+    super::{
+        super::waterline::{Waterline, Waterplane},
+        calculated_grid::CalculatedGrid,
+        progress::Progress,
+    },
+    HydrostaticsCacheConf,
+};
+use crate::common::cache::header::Header;
+use sal_sync::services::{
+    entity::{dbg_id::DbgId, error::str_err::StrErr},
+    service::service_handles::ServiceHandles,
+};
+use std::{
+    path::PathBuf,
+    sync::{atomic::AtomicBool, Arc},
+};
+///
+/// Provides logic to calculate and store cache used by [super::HydrostaticsCache].
+///
+/// Besides the submerged volume, waterplane properties are required,
+/// which are got by finite differences of the submerged volume (see [Waterline::waterplane]).
+///
+/// The calculation itself is done by [CalculatedGrid], which is parallel, atomic, and resumable.
+pub(super) struct CalculatedHydrostaticsCache<A> {
+    grid: CalculatedGrid<A>,
+}
+///
+/// Steps of finite differences and water density (see [HydrostaticsCacheConf]).
+#[derive(Clone, Copy)]
+struct Deltas {
+    water_density: f64,
+    draught: f64,
+    angle: f64,
+}
+//
+//
+impl<A: Clone + Send + 'static> CalculatedHydrostaticsCache<A> {
+    ///
+    /// Crates a new instance.
+    pub(super) fn new(
+        parent: &DbgId,
+        file_path: PathBuf,
+        waterline: Waterline<A>,
+        header: Header,
+        conf: HydrostaticsCacheConf,
+        exit: Arc<AtomicBool>,
+        progress: Arc<Progress>,
+    ) -> Self {
+        let dbgid = DbgId::with_parent(parent, "CalculatedHydrostaticsCache");
+        let deltas = Deltas {
+            water_density: conf.water_density,
+            draught: conf.draught_delta,
+            angle: conf.angle_delta.to_radians(),
+        };
+        Self {
+            grid: CalculatedGrid::new(
+                &dbgid,
+                file_path,
+                waterline,
+                Arc::new(move |waterline, point| Self::hydrostatics(waterline, point, deltas)),
+                header,
+                [conf.heel_steps, conf.trim_steps, conf.draught_steps],
+                conf.workers,
+                conf.resume,
+                exit,
+                progress,
+            ),
+        }
+    }
+    ///
+    /// Creates and starts workers for [HydrostaticsCache::calculate].
+    ///
+    /// Each row of the file has the values of [HydrostaticsCache::COLUMNS],
+    /// the rows follow the header.
+    pub(super) fn build(self) -> Result<ServiceHandles<Result<(), StrErr>>, StrErr> {
+        self.grid.build()
+    }
+    ///
+    /// Returns hydrostatics of the model submerged by `waterline` at `point`:
+    /// volume, displacement, center of buoyancy (x, y, z), waterplane area,
+    /// waterplane centroid (x, y), transverse and longitudinal moments of inertia
+    /// of the waterplane, transverse and longitudinal metacentric radii.
+    ///
+    /// Values, which can't be defined (e. g. center of buoyancy of no volume), are zero.
+    fn hydrostatics(
+        waterline: &Waterline<A>,
+        point: [f64; 3],
+        deltas: Deltas,
+    ) -> Result<Vec<f64>, StrErr> {
+        let submerged = waterline.submerged(point, None)?;
+        let volume = submerged.volume;
+        let buoyancy = submerged.center().unwrap_or_default();
+        let Waterplane {
+            area,
+            centroid: flotation,
+            inertia: [inertia_x, inertia_y],
+        } = waterline.waterplane(point, deltas.draught, deltas.angle)?;
+        let radius = |inertia: f64| match volume > f64::EPSILON {
+            true => inertia / volume,
+            false => 0.0,
+        };
+        Ok(vec![
+            volume,
+            volume * deltas.water_density,
+            buoyancy[0],
+            buoyancy[1],
+            buoyancy[2],
+            area,
+            flotation[0],
+            flotation[1],
+            inertia_x,
+            inertia_y,
+            radius(inertia_x),
+            radius(inertia_y),
+        ])
+    }
+}
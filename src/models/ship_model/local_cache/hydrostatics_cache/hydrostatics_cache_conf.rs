@@ -0,0 +1,64 @@
+use crate::common::cache::{extrapolation::Extrapolation, interpolation::Interpolation};
+///
+/// [super::HydrostaticsCache] configuration.
+#[derive(Clone, Debug)]
+pub struct HydrostaticsCacheConf {
+    ///
+    /// Keys of the model elements making the hull, all elements are used if empty.
+    pub model_keys: Vec<String>,
+    ///
+    /// Waterline initial position in 3D space.
+    pub waterline_position: [f64; 3],
+    ///
+    /// Angle in degrees.
+    pub heel_steps: Vec<f64>,
+    ///
+    /// Angle in degrees.
+    pub trim_steps: Vec<f64>,
+    ///
+    /// Offset of the waterline down from its initial position.
+    pub draught_steps: Vec<f64>,
+    ///
+    /// Density of water in t/m3, used to get displacement from volume.
+    pub water_density: f64,
+    ///
+    /// Draught offset used to get waterplane properties by finite differences.
+    pub draught_delta: f64,
+    ///
+    /// Angle in degrees used to get moments of inertia of the waterplane by finite differences.
+    pub angle_delta: f64,
+    ///
+    /// Method used to approximate values in between the steps.
+    pub interpolation: Interpolation,
+    ///
+    /// Policy used for values out of the steps range.
+    pub extrapolation: Extrapolation,
+    ///
+    /// Number of worker threads calculating the dataset.
+    ///
+    /// Zero means the number of available CPUs.
+    pub workers: usize,
+    ///
+    /// Continue the interrupted calculation from its partial file instead of starting over.
+    pub resume: bool,
+}
+//
+//
+impl Default for HydrostaticsCacheConf {
+    fn default() -> Self {
+        Self {
+            model_keys: vec![],
+            waterline_position: [0.0; 3],
+            heel_steps: vec![],
+            trim_steps: vec![],
+            draught_steps: vec![],
+            water_density: 1.025,
+            draught_delta: 0.01,
+            angle_delta: 0.5,
+            interpolation: Interpolation::default(),
+            extrapolation: Extrapolation::default(),
+            workers: 0,
+            resume: false,
+        }
+    }
+}
@@ -0,0 +1,173 @@
+#[cfg(test)]
+#[path = "../../../tests/models/ship_model/local_cache/progress_test.rs"]
+mod tests;
+//
+use sal_sync::services::entity::error::str_err::StrErr;
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, MutexGuard,
+    },
+    time::{Duration, Instant},
+};
+///
+/// Progress of [super::LocalCache::calculate] job shared between the workers and observers.
+///
+/// Workers update it while calculating, whereas observers
+/// (e. g. an operator interface polling [super::super::ShipModel::progress])
+/// take snapshots by [Progress::report] at any time.
+#[derive(Debug, Default)]
+pub struct Progress {
+    total: AtomicUsize,
+    done: AtomicUsize,
+    state: Mutex<State>,
+}
+///
+/// Part of [Progress] updated under the lock.
+#[derive(Debug, Default)]
+struct State {
+    started: Option<Instant>,
+    ///
+    /// Number of points done before the start, excluded from ETA estimation.
+    skipped: usize,
+    current: Vec<f64>,
+    last_error: Option<String>,
+}
+//
+//
+impl Progress {
+    ///
+    /// Creates a new instance of not started job.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///
+    /// Marks the job of `total` points as started now, `done` of them are already calculated
+    /// (e. g. restored after the interruption).
+    ///
+    /// The previous state is dropped.
+    pub(super) fn start(&self, total: usize, done: usize) {
+        let mut state = self.state();
+        self.total.store(total, Ordering::SeqCst);
+        self.done.store(done, Ordering::SeqCst);
+        *state = State {
+            started: Some(Instant::now()),
+            skipped: done,
+            ..State::default()
+        };
+    }
+    ///
+    /// Marks the point with `values` (e. g. heel, trim, draught) as being calculated.
+    pub(super) fn current(&self, values: &[f64]) {
+        self.state().current = values.to_vec();
+    }
+    ///
+    /// Increases the number of calculated points by one.
+    pub(super) fn step(&self) {
+        self.done.fetch_add(1, Ordering::SeqCst);
+    }
+    ///
+    /// Stores `err` as the last error of the job.
+    pub(super) fn fail(&self, err: &StrErr) {
+        self.state().last_error = Some(err.0.clone());
+    }
+    ///
+    /// Returns a snapshot of the progress.
+    pub fn report(&self) -> ProgressReport {
+        let state = self.state();
+        let total = self.total.load(Ordering::SeqCst);
+        let done = self.done.load(Ordering::SeqCst).min(total);
+        let elapsed = state
+            .started
+            .map(|started| started.elapsed())
+            .unwrap_or_default();
+        ProgressReport {
+            started: state.started.is_some(),
+            done,
+            total,
+            current: state.current.clone(),
+            elapsed,
+            eta: state.started.and_then(|_| {
+                ProgressReport::eta(done - done.min(state.skipped), total - done, elapsed)
+            }),
+            last_error: state.last_error.clone(),
+        }
+    }
+    ///
+    /// Returns the locked state, the poisoned lock is considered to be valid,
+    /// as the state is always consistent.
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+///
+/// Snapshot of [Progress].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ProgressReport {
+    ///
+    /// _true_ if the job has started.
+    pub started: bool,
+    ///
+    /// Number of calculated points, including the ones done before the start.
+    pub done: usize,
+    ///
+    /// Number of all points of the job, zero if the job is not started.
+    pub total: usize,
+    ///
+    /// Values of the point being calculated (e. g. heel, trim, draught).
+    pub current: Vec<f64>,
+    ///
+    /// Time since the job has started.
+    pub elapsed: Duration,
+    ///
+    /// Estimated time remaining, [None] until the first point is calculated.
+    ///
+    /// Points done before the start aren't taken into account.
+    pub eta: Option<Duration>,
+    ///
+    /// Error of the last failed worker.
+    pub last_error: Option<String>,
+}
+//
+//
+impl ProgressReport {
+    ///
+    /// Returns _true_ if the job has started and all points are calculated,
+    /// so the job of no points is finished once started.
+    pub fn is_finished(&self) -> bool {
+        self.started && self.done == self.total
+    }
+    ///
+    /// Returns time remaining to calculate `rest` points
+    /// assuming they take as long as `done` ones in `elapsed` time.
+    fn eta(done: usize, rest: usize, elapsed: Duration) -> Option<Duration> {
+        match (done, rest) {
+            (_, 0) => Some(Duration::ZERO),
+            (0, _) => None,
+            _ => Some(elapsed.mul_f64(rest as f64 / done as f64)),
+        }
+    }
+}
+//
+//
+impl fmt::Display for ProgressReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let percent = match self.total {
+            0 => 0.0,
+            total => self.done as f64 / total as f64 * 100.0,
+        };
+        write!(
+            f,
+            "{}/{} ({:.1}%), current={:?}, elapsed={:?}",
+            self.done, self.total, percent, self.current, self.elapsed
+        )?;
+        if let Some(eta) = self.eta {
+            write!(f, ", eta={:?}", eta)?;
+        }
+        if let Some(err) = &self.last_error {
+            write!(f, ", last_error='{}'", err)?;
+        }
+        Ok(())
+    }
+}
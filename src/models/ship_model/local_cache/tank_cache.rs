@@ -0,0 +1,224 @@
+mod calculated_tank_cache;
+pub mod tank_cache_conf;
+#[cfg(test)]
+#[path = "../../../tests/models/ship_model/local_cache/tank_cache_test.rs"]
+mod tests;
+//
+use super::{
+    super::{waterline::Waterline, ModelTree},
+    progress::Progress,
+    CacheFile, LocalCache,
+};
+use crate::common::cache::error::CacheError;
+use calculated_tank_cache::CalculatedTankCache;
+use sal_sync::services::{
+    entity::{dbg_id::DbgId, error::str_err::StrErr},
+    service::service_handles::ServiceHandles,
+};
+use sha2::{Digest, Sha256};
+use std::{
+    path::Path,
+    sync::{atomic::AtomicBool, Arc},
+};
+use tank_cache_conf::TankCacheConf;
+///
+/// Level of the liquid in the tank measured by the crew.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Gauge {
+    ///
+    /// Height of the liquid surface above the sounding point, e. g. by the sounding tape.
+    Sounding(f64),
+    ///
+    /// Depth of the liquid surface below the ullage reference, e. g. by the radar gauge.
+    Ullage(f64),
+}
+///
+/// Liquid in the tank at some level (see [TankCache::COLUMNS]).
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct TankFilling {
+    pub sounding: f64,
+    pub ullage: f64,
+    pub volume: f64,
+    ///
+    /// Centroid of the liquid in the model coordinate system.
+    pub centroid: [f64; 3],
+    ///
+    /// Transverse and longitudinal moments of inertia of the free surface
+    /// around its axes passing through its centroid.
+    pub free_surface_inertia: [f64; 2],
+}
+//
+//
+impl TankFilling {
+    ///
+    /// Returns the instance of the cache row, [None] if the row is too short.
+    /// - ullage_reference - see [TankCacheConf::ullage_reference]
+    pub(in super::super) fn from_row(row: &[f64], ullage_reference: f64) -> Option<Self> {
+        match row {
+            [_, _, sounding, volume, x, y, z, inertia_x, inertia_y, ..] => Some(Self {
+                sounding: *sounding,
+                ullage: ullage_reference - sounding,
+                volume: *volume,
+                centroid: [*x, *y, *z],
+                free_surface_inertia: [*inertia_x, *inertia_y],
+            }),
+            _ => None,
+        }
+    }
+}
+///
+/// Pre-calculated calibration table of the tank:
+/// volume of the liquid and its free surface for each sounding at each heel and trim.
+///
+/// There is a cache per tank, the file is named after the tank.
+/// See [TankCacheConf] for more details about the configuration,
+/// and [TankCache::COLUMNS] for the stored values.
+pub(in super::super) struct TankCache<A> {
+    dbgid: DbgId,
+    conf: TankCacheConf,
+    ///
+    /// Model representation used for cache calculation.
+    model_tree: ModelTree<A>,
+    ///
+    /// File of the cache, which configuration entries are tank, sounding point, steps,
+    /// and steps of finite differences.
+    file: CacheFile,
+}
+//
+//
+impl<A> TankCache<A> {
+    //
+    //
+    const KEY: &'static str = "tank_cache";
+    ///
+    /// Names of the cache columns:
+    /// - heel, trim, and sounding (see [TankCacheConf::sounding_point]),
+    /// - volume of the liquid and its centroid (x, y, z),
+    /// - transverse and longitudinal moments of inertia of the free surface (IT, IL).
+    ///
+    /// Coordinates are given in the model coordinate system.
+    pub(in super::super) const COLUMNS: &'static [&'static str] = &[
+        "heel", "trim", "sounding", "volume", "x", "y", "z", "it", "il",
+    ];
+    ///
+    /// Grid axes of the cache, the configured steps of which are all combined.
+    const AXES: &'static [&'static str] = &["heel", "trim", "sounding"];
+    ///
+    /// Units of the cache columns.
+    const UNITS: &'static [&'static str] = &["deg", "deg", "m", "m3", "m", "m", "m", "m4", "m4"];
+    ///
+    /// Creates a new instance.
+    /// - path - folder contains all cache files
+    /// - model_hash - hash of the model file (see [CacheFile::new])
+    pub(in super::super) fn new(
+        parent: &DbgId,
+        model_tree: ModelTree<A>,
+        model_hash: Result<String, CacheError>,
+        path: impl AsRef<Path>,
+        conf: TankCacheConf,
+    ) -> Self {
+        let dbgid = DbgId::with_parent(parent, "TankCache");
+        let join = |vals: &[f64]| Vec::from_iter(vals.iter().map(f64::to_string)).join(" ");
+        let file = CacheFile::new(
+            &dbgid,
+            path.as_ref().join(Self::file_name(&conf.tank)),
+            Self::COLUMNS,
+            Self::AXES,
+            Self::UNITS,
+            model_tree.path(),
+            model_hash,
+        )
+        .with_meta("tank", &conf.tank)
+        .with_meta("sounding_point", join(&conf.sounding_point))
+        .with_meta("heel_steps", join(&conf.heel_steps))
+        .with_meta("trim_steps", join(&conf.trim_steps))
+        .with_meta("sounding_steps", join(&conf.sounding_steps))
+        .with_meta("sounding_delta", conf.sounding_delta)
+        .with_meta("angle_delta", conf.angle_delta)
+        .with_interpolation(conf.interpolation)
+        .with_extrapolation(conf.extrapolation);
+        Self {
+            model_tree,
+            conf,
+            file,
+            dbgid,
+        }
+    }
+    ///
+    /// Returns the name of the cache file of `tank`.
+    ///
+    /// The model key is a path from the model root,
+    /// so all characters except alphanumerics, '-', and '_' are replaced by '_'.
+    /// The replacement isn't unique (e. g. '/tanks/fo_1p' and '/tanks_fo/1p'),
+    /// so the name ends with the first 8 hex digits of SHA-256 hash of the key.
+    fn file_name(tank: &str) -> String {
+        let name = String::from_iter(tank.trim_start_matches('/').chars().map(|c| {
+            match c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                true => c,
+                false => '_',
+            }
+        }));
+        let hash = Sha256::digest(tank.as_bytes());
+        let hash = String::from_iter(hash[..4].iter().map(|byte| format!("{:02x}", byte)));
+        format!("{}.{}.{}", Self::KEY, name, hash)
+    }
+}
+//
+//
+impl<A: Clone + Send + 'static> LocalCache for TankCache<A> {
+    ///
+    /// See [CalculatedTankCache] for details.
+    fn calculate(
+        &self,
+        exit: Arc<AtomicBool>,
+        progress: Arc<Progress>,
+    ) -> Result<ServiceHandles<Result<(), StrErr>>, StrErr> {
+        let tank = self.model_tree.get(&self.conf.tank).ok_or_else(|| {
+            StrErr(format!(
+                "{}.calculate | No element found for tank='{}'",
+                self.dbgid, self.conf.tank
+            ))
+        })?;
+        CalculatedTankCache::new(
+            &self.dbgid,
+            self.file.path().to_owned(),
+            Waterline::new(
+                vec![tank.clone()],
+                Waterline::create_face(&self.dbgid, self.conf.sounding_point)?,
+            ),
+            self.file.create_header()?,
+            self.conf.clone(),
+            exit,
+            progress,
+        )
+        .build()
+    }
+    ///
+    /// See [Cache::try_get] for details.
+    ///
+    /// [Cache::try_get]: crate::common::cache::Cache::try_get
+    fn get(&self, approx_vals: &[Option<f64>]) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.file.cache()?.try_get(approx_vals)
+    }
+    ///
+    /// See [Cache::solve] for details.
+    ///
+    /// [Cache::solve]: crate::common::cache::Cache::solve
+    fn solve(
+        &self,
+        fixed_vals: &[Option<f64>],
+        unknown: usize,
+    ) -> Result<Vec<Vec<f64>>, CacheError> {
+        self.file.cache()?.solve(fixed_vals, unknown)
+    }
+    //
+    //
+    fn reload(&mut self) {
+        self.file.reload();
+    }
+    ///
+    /// See [CacheFile::check] for details.
+    fn check(&self) -> Result<(), CacheError> {
+        self.file.check()
+    }
+}
@@ -0,0 +1,96 @@
+use super::{
+    super::{
+        super::waterline::{Waterline, Waterplane},
+        calculated_grid::CalculatedGrid,
+        progress::Progress,
+    },
+    TankCacheConf,
+};
+use crate::common::cache::header::Header;
+use sal_sync::services::{
+    entity::{dbg_id::DbgId, error::str_err::StrErr},
+    service::service_handles::ServiceHandles,
+};
+use std::{
+    path::PathBuf,
+    sync::{atomic::AtomicBool, Arc},
+};
+///
+/// Provides logic to calculate and store cache used by [super::TankCache].
+///
+/// The liquid surface is the waterline placed at [TankCacheConf::sounding_point]
+/// and moved up by the sounding, so the liquid is the part of the tank under it.
+/// The free surface properties are got by finite differences (see [Waterline::waterplane]).
+///
+/// The calculation itself is done by [CalculatedGrid], which is parallel, atomic, and resumable.
+pub(super) struct CalculatedTankCache<A> {
+    grid: CalculatedGrid<A>,
+}
+//
+//
+impl<A: Clone + Send + 'static> CalculatedTankCache<A> {
+    ///
+    /// Crates a new instance.
+    pub(super) fn new(
+        parent: &DbgId,
+        file_path: PathBuf,
+        waterline: Waterline<A>,
+        header: Header,
+        conf: TankCacheConf,
+        exit: Arc<AtomicBool>,
+        progress: Arc<Progress>,
+    ) -> Self {
+        let dbgid = DbgId::with_parent(parent, "CalculatedTankCache");
+        let deltas = (conf.sounding_delta, conf.angle_delta.to_radians());
+        Self {
+            grid: CalculatedGrid::new(
+                &dbgid,
+                file_path,
+                waterline,
+                Arc::new(move |waterline, point| Self::calibration(waterline, point, deltas)),
+                header,
+                [conf.heel_steps, conf.trim_steps, conf.sounding_steps],
+                conf.workers,
+                conf.resume,
+                exit,
+                progress,
+            ),
+        }
+    }
+    ///
+    /// Creates and starts workers for [TankCache::calculate].
+    ///
+    /// Each row of the file has the values of [TankCache::COLUMNS],
+    /// the rows follow the header.
+    pub(super) fn build(self) -> Result<ServiceHandles<Result<(), StrErr>>, StrErr> {
+        self.grid.build()
+    }
+    ///
+    /// Returns calibration of the tank filled up to the sounding at `point`:
+    /// volume, its centroid (x, y, z), transverse and longitudinal moments of inertia
+    /// of the free surface.
+    ///
+    /// Values, which can't be defined (e. g. centroid of no volume), are zero.
+    fn calibration(
+        waterline: &Waterline<A>,
+        [heel, trim, sounding]: [f64; 3],
+        (sounding_delta, angle_delta): (f64, f64),
+    ) -> Result<Vec<f64>, StrErr> {
+        // the waterline moves down with draught, whereas the liquid surface goes up with sounding
+        let point = [heel, trim, -sounding];
+        let liquid = waterline.submerged(point, None)?;
+        let centroid = liquid.center().unwrap_or_default();
+        let Waterplane {
+            inertia: [inertia_x, inertia_y],
+            ..
+        } = waterline.waterplane(point, sounding_delta, angle_delta)?;
+        Ok(vec![
+            liquid.volume,
+            centroid[0],
+            centroid[1],
+            centroid[2],
+            inertia_x,
+            inertia_y,
+        ])
+    }
+}
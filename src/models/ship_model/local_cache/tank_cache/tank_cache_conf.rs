@@ -0,0 +1,77 @@
+use crate::common::cache::{extrapolation::Extrapolation, interpolation::Interpolation};
+///
+/// [super::TankCache] configuration.
+#[derive(Clone, Debug)]
+pub struct TankCacheConf {
+    ///
+    /// Key of the model element making the tank, usually a solid.
+    pub tank: String,
+    ///
+    /// Lower end of the sounding pipe in 3D space, where the sounding is zero.
+    ///
+    /// The pipe goes up along the vertical axis of the model,
+    /// the liquid surface crosses it at the sounding above this point.
+    pub sounding_point: [f64; 3],
+    ///
+    /// Height of the ullage reference (e. g. the sounding pipe head or the radar gauge)
+    /// above [TankCacheConf::sounding_point], the ullage is measured down from it.
+    pub ullage_reference: f64,
+    ///
+    /// Longitudinal coordinates [aft, fore] of the tank ends the liquid is spread between
+    /// (see [DeadweightItem::extent]), [None] for the point weight.
+    ///
+    /// It doesn't affect the cache.
+    ///
+    /// [DeadweightItem::extent]: crate::models::loading_condition::DeadweightItem::extent
+    pub extent: Option<[f64; 2]>,
+    ///
+    /// Angle in degrees.
+    pub heel_steps: Vec<f64>,
+    ///
+    /// Angle in degrees.
+    pub trim_steps: Vec<f64>,
+    ///
+    /// Height of the liquid surface above [TankCacheConf::sounding_point] along the pipe.
+    pub sounding_steps: Vec<f64>,
+    ///
+    /// Sounding offset used to get the free surface properties by finite differences.
+    pub sounding_delta: f64,
+    ///
+    /// Angle in degrees used to get moments of inertia of the free surface by finite differences.
+    pub angle_delta: f64,
+    ///
+    /// Method used to approximate values in between the steps.
+    pub interpolation: Interpolation,
+    ///
+    /// Policy used for values out of the steps range.
+    pub extrapolation: Extrapolation,
+    ///
+    /// Number of worker threads calculating the dataset.
+    ///
+    /// Zero means the number of available CPUs.
+    pub workers: usize,
+    ///
+    /// Continue the interrupted calculation from its partial file instead of starting over.
+    pub resume: bool,
+}
+//
+//
+impl Default for TankCacheConf {
+    fn default() -> Self {
+        Self {
+            tank: String::new(),
+            sounding_point: [0.0; 3],
+            ullage_reference: 0.0,
+            extent: None,
+            heel_steps: vec![],
+            trim_steps: vec![],
+            sounding_steps: vec![],
+            sounding_delta: 0.01,
+            angle_delta: 0.5,
+            interpolation: Interpolation::default(),
+            extrapolation: Extrapolation::default(),
+            workers: 0,
+            resume: false,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::common::cache::{error::CacheError, header::Header};
 use indexmap::IndexMap;
 use sal_3dlib::topology::shape::Shape;
 use sal_sync::services::entity::{dbg_id::DbgId, error::str_err::StrErr};
@@ -62,11 +63,43 @@ impl<A> ModelTree<A> {
             })
     }
     ///
+    /// Returns the source file of the model.
+    pub(super) fn path(&self) -> &Path {
+        &self.path
+    }
+    ///
+    /// Returns the hash of the source file (see [Header::hash_file]),
+    /// which identifies the model the caches are calculated for.
+    pub(super) fn hash(&self) -> Result<String, CacheError> {
+        Header::hash_file(&self.path).map_err(|err| CacheError::IoError {
+            kind: err.kind(),
+            message: format!(
+                "{}.hash | Failed hashing model_path='{}': {}",
+                self.dbgid,
+                self.path.display(),
+                err
+            ),
+        })
+    }
+    ///
     /// Return an iterator over the key-value pairs of the map, in their order.
     pub(super) fn iter(&self) -> indexmap::map::Iter<'_, String, Shape<Option<A>>> {
         self.elements.iter()
     }
     ///
+    /// Returns the elements by `keys` in their order in the tree,
+    /// all elements if `keys` is empty.
+    pub(super) fn elements(&self, keys: &[String]) -> Vec<Shape<Option<A>>>
+    where
+        A: Clone,
+    {
+        self.elements
+            .iter()
+            .filter_map(|(key, shape)| (keys.is_empty() || keys.contains(key)).then_some(shape))
+            .cloned()
+            .collect()
+    }
+    ///
     /// Return a reference to the value stored for `key`, if it is present, else `None`.
     pub(super) fn get(&self, key: impl AsRef<str>) -> Option<&Shape<Option<A>>> {
         self.elements.get(key.as_ref())
@@ -1,4 +1,14 @@
-use super::local_cache::floating_position_cache::floating_position_cache_conf::FloatingPositionCacheConf;
+use super::{
+    damage::damage_conf::DamageConf,
+    equilibrium::equilibrium_conf::EquilibriumConf,
+    local_cache::{
+        cross_curves_cache::cross_curves_cache_conf::CrossCurvesCacheConf,
+        floating_position_cache::floating_position_cache_conf::FloatingPositionCacheConf,
+        hydrostatics_cache::hydrostatics_cache_conf::HydrostaticsCacheConf,
+        tank_cache::tank_cache_conf::TankCacheConf,
+    },
+};
+use crate::models::strength::longitudinal_strength::longitudinal_strength_conf::LongitudinalStrengthConf;
 use std::path::PathBuf;
 ///
 /// [super::ShipModel] configuration.
@@ -14,4 +24,37 @@ pub struct ShipModelConf {
     ///
     /// [super::FloatingPositionCache] configuration.
     pub floating_position_cache_conf: FloatingPositionCacheConf,
+    ///
+    /// [super::HydrostaticsCache] configuration.
+    pub hydrostatics_cache_conf: HydrostaticsCacheConf,
+    ///
+    /// [super::CrossCurvesCache] configuration.
+    pub cross_curves_cache_conf: CrossCurvesCacheConf,
+    ///
+    /// [super::TankCache] configuration of each tank, the tank names must be unique.
+    pub tank_cache_confs: Vec<TankCacheConf>,
+    ///
+    /// [super::ShipModel::equilibrium] configuration.
+    pub equilibrium_conf: EquilibriumConf,
+    ///
+    /// [super::ShipModel::damage_stability] configuration.
+    pub damage_conf: DamageConf,
+    ///
+    /// [super::ShipModel::longitudinal_strength] configuration.
+    pub strength_conf: LongitudinalStrengthConf,
+    ///
+    /// What to do with stale caches (see [super::ShipModel::check_caches]).
+    pub stale_cache_policy: StaleCachePolicy,
+}
+///
+/// Action applied to the caches, which don't match the current model or configuration.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum StaleCachePolicy {
+    ///
+    /// Stale caches are reported as the error.
+    #[default]
+    Report,
+    ///
+    /// Stale caches are rebuilt.
+    Rebuild,
 }
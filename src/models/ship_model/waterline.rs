@@ -0,0 +1,340 @@
+//!
+//! Waterline moved over the grid of floating positions
+//! and the part of the model submerged by it.
+//!
+//! Coordinates follow the model: _x_ is longitudinal, _y_ is transverse, _z_ is vertical.
+//! Heel is a rotation around _x_, trim is a rotation around transverse axis of the heeled waterline.
+//
+use sal_3dlib::{
+    gmath::vector::Vector,
+    props::{Center, Volume},
+    topology::shape::{
+        compound::{AlgoMakerVolume, Compound, Solids},
+        face::{Face, Rotate, Translate},
+        vertex::Vertex,
+        wire::{Polygon, Wire},
+        Shape,
+    },
+};
+use sal_sync::services::entity::{dbg_id::DbgId, error::str_err::StrErr};
+///
+/// Volume of the model part placed under the waterline.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub(super) struct Submerged {
+    pub(super) volume: f64,
+    ///
+    /// First moment of the volume about the origin of coordinates (sum of `volume * center`).
+    pub(super) moment: [f64; 3],
+}
+//
+//
+impl Submerged {
+    ///
+    /// Returns the center of the volume, [None] if there is no volume.
+    pub(super) fn center(&self) -> Option<[f64; 3]> {
+        (self.volume > f64::EPSILON).then(|| self.moment.map(|moment| moment / self.volume))
+    }
+}
+///
+/// Area of the model section by the waterline.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub(super) struct Waterplane {
+    pub(super) area: f64,
+    ///
+    /// Centroid of the area, zero if there is no area.
+    pub(super) centroid: [f64; 3],
+    ///
+    /// Moments of inertia of the area around the longitudinal and the transverse axes
+    /// of the waterline passing through the centroid.
+    pub(super) inertia: [f64; 2],
+}
+///
+/// Model elements cut by the waterline.
+#[derive(Clone)]
+pub(super) struct Waterline<A> {
+    elements: Vec<Shape<A>>,
+    ///
+    /// Waterline at the initial position.
+    face: Face<A>,
+}
+//
+//
+impl<A: Clone> Waterline<A> {
+    ///
+    /// Creates a new instance.
+    pub(super) fn new(elements: Vec<Shape<A>>, face: Face<A>) -> Self {
+        Self { elements, face }
+    }
+    ///
+    /// Creates a waterline face in 3D space centered at `position`.
+    ///
+    /// The face is horizontal and big enough to cut most of models.
+    pub(super) fn create_face(parent: &DbgId, position: [f64; 3]) -> Result<Face<A>, StrErr> {
+        let dbgid = DbgId(format!("{}.create_waterline_model", parent));
+        let [x, y, z] = position;
+        // dynamic range could be built based on bounding box of target element behind self.model_keys,
+        // but now reserve big enough offsets, which should work with most elements
+        let dx = 1000.0;
+        let dy = 1000.0;
+        //
+        Self::create_polygon(
+            &dbgid,
+            [
+                [x + dx, y + dy, z],
+                [x - dx, y + dy, z],
+                [x - dx, y - dy, z],
+                [x + dx, y - dy, z],
+            ],
+        )
+    }
+    ///
+    /// Creates a station face in 3D space centered at `position`.
+    ///
+    /// The face is vertical and transverse, i. e. it's normal to the longitudinal axis,
+    /// and big enough to cut most of models.
+    pub(super) fn create_station(parent: &DbgId, position: [f64; 3]) -> Result<Face<A>, StrErr> {
+        let dbgid = DbgId(format!("{}.create_station", parent));
+        let [x, y, z] = position;
+        let dy = 1000.0;
+        let dz = 1000.0;
+        Self::create_polygon(
+            &dbgid,
+            [
+                [x, y + dy, z + dz],
+                [x, y - dy, z + dz],
+                [x, y - dy, z - dz],
+                [x, y + dy, z - dz],
+            ],
+        )
+    }
+    ///
+    /// Creates a plane face bounded by the polygon of `vertices`.
+    fn create_polygon(dbgid: &DbgId, vertices: [[f64; 3]; 4]) -> Result<Face<A>, StrErr> {
+        match Wire::polygon(vertices.map(Vertex::new), true) {
+            Ok(ref polygon) => Face::try_from(polygon).map_err(|why| {
+                StrErr(format!(
+                    "{} | Failed creating Face from *polygon*: {}",
+                    dbgid, why
+                ))
+            }),
+            Err(why) => Err(StrErr(format!(
+                "{} | Failed creating *polygon* from Wire: {}",
+                dbgid, why
+            ))),
+        }
+    }
+    ///
+    /// Returns the center of the waterline at the initial position.
+    pub(super) fn origin(&self) -> [f64; 3] {
+        self.face.center().point()
+    }
+    ///
+    /// Returns the model part placed under the waterline at `heel`, `trim` (in degrees), and `draught`.
+    ///
+    /// If `tilt` is given as (axis, angle in radians), the waterline is additionally rotated
+    /// around the axis lying in its plane and passing through its center.
+    pub(super) fn submerged(
+        &self,
+        [heel, trim, draught]: [f64; 3],
+        tilt: Option<([f64; 3], f64)>,
+    ) -> Result<Submerged, StrErr> {
+        let (w_obj, w_center, normal) = &self.place([heel, trim, draught], tilt);
+        self.elements
+            .iter()
+            .filter_map(|elmnt| {
+                // get compound as result of volume algorithm
+                // applied to waterline and each target element
+                // (taking into account its shape type)
+                Some(match elmnt {
+                    Shape::Face(elmnt) => Compound::build([w_obj, elmnt], [], []),
+                    Shape::Shell(elmnt) => Compound::build([w_obj], [elmnt], []),
+                    Shape::Solid(elmnt) => Compound::build([w_obj], [], [elmnt]),
+                    _ => return None,
+                })
+            })
+            .try_fold(Submerged::default(), |mut submerged, build| {
+                for elmnt in build?.solids() {
+                    let center = elmnt.center().point();
+                    // Only calculate volume if volumed element is below waterline,
+                    // which may be inclined, so the side is defined by its normal.
+                    if dot([0, 1, 2].map(|i| center[i] - w_center[i]), *normal) < 0.0 {
+                        let volume = elmnt.volume();
+                        submerged.volume += volume;
+                        for (moment, coord) in submerged.moment.iter_mut().zip(center) {
+                            *moment += volume * coord;
+                        }
+                    }
+                }
+                Ok(submerged)
+            })
+    }
+    ///
+    /// Returns the model part placed under the waterline at `heel`, `trim` (in degrees), and `draught`
+    /// split by the vertical transverse `stations` (see [Waterline::create_station]),
+    /// a part per space between the neighbouring stations.
+    ///
+    /// Stations are longitudinal coordinates in ascending order,
+    /// the pieces beyond the first and the last ones are added to the end spaces.
+    pub(super) fn sections(
+        &self,
+        parent: &DbgId,
+        point: [f64; 3],
+        stations: &[f64],
+    ) -> Result<Vec<Submerged>, StrErr> {
+        let mut sections = vec![Submerged::default(); stations.len().saturating_sub(1)];
+        if sections.is_empty() {
+            return Ok(sections);
+        }
+        let (w_obj, w_center, normal) = &self.place(point, None);
+        let [_, y, z] = self.origin();
+        let faces = stations
+            .iter()
+            .map(|&x| Self::create_station(parent, [x, y, z]))
+            .collect::<Result<Vec<_>, _>>()?;
+        let cutters = || std::iter::once(w_obj).chain(&faces);
+        for elmnt in &self.elements {
+            let build = match elmnt {
+                Shape::Face(elmnt) => Compound::build(cutters().chain([elmnt]), [], []),
+                Shape::Shell(elmnt) => Compound::build(cutters(), [elmnt], []),
+                Shape::Solid(elmnt) => Compound::build(cutters(), [], [elmnt]),
+                _ => continue,
+            };
+            for piece in build?.solids() {
+                let center = piece.center().point();
+                if dot([0, 1, 2].map(|i| center[i] - w_center[i]), *normal) < 0.0 {
+                    let id = stations
+                        .partition_point(|&x| x < center[0])
+                        .clamp(1, sections.len())
+                        - 1;
+                    let volume = piece.volume();
+                    sections[id].volume += volume;
+                    for (moment, coord) in sections[id].moment.iter_mut().zip(center) {
+                        *moment += volume * coord;
+                    }
+                }
+            }
+        }
+        Ok(sections)
+    }
+    ///
+    /// Returns the waterline moved from the initial position to `heel`, `trim` (in degrees), and `draught`,
+    /// and additionally rotated by `tilt` (see [Waterline::submerged]), its center and its normal.
+    fn place(
+        &self,
+        [heel, trim, draught]: [f64; 3],
+        tilt: Option<([f64; 3], f64)>,
+    ) -> (Face<A>, [f64; 3], [f64; 3]) {
+        // make a clone of origin waterline and transform it
+        // according to heel, trim, and draught values
+        let mut obj = self.face.clone();
+        let origin = self.face.center();
+        let mut loc_y = Vector::unit_y();
+        if 0.0 != heel {
+            let heel_in_rad = heel.to_radians();
+            obj = obj.rotate(origin.clone(), Vector::unit_x(), heel_in_rad);
+            // once a rotation around oX happens, oY needs to get the rotation too,
+            // overwise oY remains global and doesn't match new `obj`'s transformation
+            loc_y = loc_y.rotate(Vector::unit_x(), heel_in_rad);
+        }
+        if 0.0 != trim {
+            obj = obj.rotate(origin.clone(), loc_y, trim.to_radians());
+        }
+        if let Some(([x, y, z], angle)) = tilt {
+            obj = obj.rotate(origin, Vector::new(x, y, z), angle);
+        }
+        if 0.0 != draught {
+            obj = obj.translate(Vector::new(0.0, 0.0, -draught));
+        }
+        let center = obj.center().point();
+        let normal = {
+            let [.., normal] = axes(heel, trim);
+            match tilt {
+                Some((axis, angle)) => rotate(normal, axis, angle),
+                None => normal,
+            }
+        };
+        (obj, center, normal)
+    }
+    ///
+    /// Returns the waterplane at `heel`, `trim` (in degrees), and `draught`.
+    ///
+    /// It's got by finite differences of the submerged volume and its moment:
+    /// - moving the waterline by ±`draught_delta` gives the area and its centroid,
+    ///   since the volume of the thin layer is the area times its thickness;
+    /// - tilting the waterline by ±`angle_delta` (in radians) around its axes gives the moments of inertia,
+    ///   since the moment of the thin wedge is the moment of inertia times the tilt angle.
+    pub(super) fn waterplane(
+        &self,
+        point: [f64; 3],
+        draught_delta: f64,
+        angle_delta: f64,
+    ) -> Result<Waterplane, StrErr> {
+        let [heel, trim, draught] = point;
+        // the waterline moves down with draught, so the upper layer is taken at the lower draught
+        let upper = self.submerged([heel, trim, draught - draught_delta], None)?;
+        let lower = self.submerged([heel, trim, draught + draught_delta], None)?;
+        let layer = derivative(&upper, &lower, [0.0; 3], draught_delta);
+        let [loc_x, loc_y, normal] = axes(heel, trim);
+        // the layer is measured vertically, whereas the waterplane may be inclined
+        let area = layer.volume / normal[2].abs();
+        let Some(centroid) = layer.center() else {
+            return Ok(Waterplane {
+                area,
+                ..Waterplane::default()
+            });
+        };
+        let [x, y, z] = self.origin();
+        let center = [x, y, z - draught];
+        // inertia around the axis through the waterplane centroid
+        let inertia = |axis: [f64; 3], offset: [f64; 3]| -> Result<f64, StrErr> {
+            let pos = self.submerged(point, Some((axis, angle_delta)))?;
+            let neg = self.submerged(point, Some((axis, -angle_delta)))?;
+            let wedge = derivative(&pos, &neg, center, angle_delta);
+            let distance = dot([0, 1, 2].map(|i| centroid[i] - center[i]), offset);
+            Ok((dot(wedge.moment, offset).abs() - area * distance.powi(2)).max(0.0))
+        };
+        Ok(Waterplane {
+            area,
+            centroid,
+            inertia: [inertia(loc_x, loc_y)?, inertia(loc_y, loc_x)?],
+        })
+    }
+}
+///
+/// Returns central difference of `pos` and `neg` taken at ±`delta`,
+/// the moment is taken around `center`.
+fn derivative(pos: &Submerged, neg: &Submerged, center: [f64; 3], delta: f64) -> Submerged {
+    let volume = pos.volume - neg.volume;
+    Submerged {
+        volume: volume / (2.0 * delta),
+        moment: [0, 1, 2]
+            .map(|i| (pos.moment[i] - neg.moment[i] - center[i] * volume) / (2.0 * delta)),
+    }
+}
+///
+/// Returns unit vectors of the waterline placed at `heel` and `trim` (in degrees):
+/// longitudinal and transverse axes lying in its plane, and its normal.
+pub(super) fn axes(heel: f64, trim: f64) -> [[f64; 3]; 3] {
+    let (heel, trim) = (heel.to_radians(), trim.to_radians());
+    let loc_y = rotate([0.0, 1.0, 0.0], [1.0, 0.0, 0.0], heel);
+    let loc_x = rotate([1.0, 0.0, 0.0], loc_y, trim);
+    let normal = rotate(rotate([0.0, 0.0, 1.0], [1.0, 0.0, 0.0], heel), loc_y, trim);
+    [loc_x, loc_y, normal]
+}
+///
+/// Returns `v` rotated around unit `axis` by `angle` in radians following the right-hand rule.
+fn rotate(v: [f64; 3], axis: [f64; 3], angle: f64) -> [f64; 3] {
+    let (sin, cos) = angle.sin_cos();
+    let cross = [
+        axis[1] * v[2] - axis[2] * v[1],
+        axis[2] * v[0] - axis[0] * v[2],
+        axis[0] * v[1] - axis[1] * v[0],
+    ];
+    let dot = dot(axis, v);
+    [0, 1, 2].map(|i| v[i] * cos + cross[i] * sin + axis[i] * dot * (1.0 - cos))
+}
+///
+/// Returns the dot product of `l` and `r`.
+pub(super) fn dot(l: [f64; 3], r: [f64; 3]) -> f64 {
+    l.iter().zip(r).map(|(l, r)| l * r).sum()
+}
@@ -0,0 +1,6 @@
+//!
+//! Stability of the ship in the given loading condition.
+//
+pub mod criterion;
+pub mod gz_curve;
+pub mod intact_stability;
@@ -0,0 +1,126 @@
+use std::fmt;
+///
+/// Kind of the limit the attained value of [Criterion] is compared with.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Limit {
+    ///
+    /// The attained value must be greater than or equal to the required one.
+    Min,
+    ///
+    /// The attained value must be less than or equal to the required one.
+    Max,
+}
+///
+/// Stability criterion checked for the loading condition.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Criterion {
+    ///
+    /// Human readable description of the criterion.
+    pub name: &'static str,
+    ///
+    /// Units of the values.
+    pub unit: &'static str,
+    pub limit: Limit,
+    pub required: f64,
+    ///
+    /// Value attained in the loading condition,
+    /// [None] if it can't be evaluated (e. g. the GZ curve is too short).
+    pub attained: Option<f64>,
+}
+//
+//
+impl Criterion {
+    ///
+    /// Creates a new instance.
+    pub fn new(
+        name: &'static str,
+        unit: &'static str,
+        limit: Limit,
+        required: f64,
+        attained: Option<f64>,
+    ) -> Self {
+        Self {
+            name,
+            unit,
+            limit,
+            required,
+            attained,
+        }
+    }
+    ///
+    /// Returns _true_ if the attained value meets the required one.
+    ///
+    /// The criterion, which can't be evaluated, isn't passed.
+    pub fn passed(&self) -> bool {
+        match (self.attained, self.limit) {
+            (Some(attained), Limit::Min) => attained >= self.required,
+            (Some(attained), Limit::Max) => attained <= self.required,
+            (None, _) => false,
+        }
+    }
+}
+//
+//
+impl fmt::Display for Criterion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = match self.limit {
+            Limit::Min => ">=",
+            Limit::Max => "<=",
+        };
+        let attained = match self.attained {
+            Some(attained) => format!("{:.3}", attained),
+            None => "n/a".to_owned(),
+        };
+        write!(
+            f,
+            "{}: required {} {:.3} {}, attained {} {} - {}",
+            self.name,
+            sign,
+            self.required,
+            self.unit,
+            attained,
+            self.unit,
+            match self.passed() {
+                true => "PASSED",
+                false => "FAILED",
+            }
+        )
+    }
+}
+///
+/// Results of checking the stability criteria.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CriteriaReport {
+    pub criteria: Vec<Criterion>,
+}
+//
+//
+impl CriteriaReport {
+    ///
+    /// Returns _true_ if all criteria are passed.
+    pub fn passed(&self) -> bool {
+        self.criteria.iter().all(Criterion::passed)
+    }
+    ///
+    /// Returns the criteria, which aren't passed.
+    pub fn failed(&self) -> Vec<&Criterion> {
+        Vec::from_iter(self.criteria.iter().filter(|criterion| !criterion.passed()))
+    }
+}
+//
+//
+impl fmt::Display for CriteriaReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for criterion in &self.criteria {
+            writeln!(f, "{}", criterion)?;
+        }
+        write!(
+            f,
+            "{}",
+            match self.passed() {
+                true => "All criteria are PASSED",
+                false => "Some criteria are FAILED",
+            }
+        )
+    }
+}
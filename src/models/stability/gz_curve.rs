@@ -0,0 +1,149 @@
+#[cfg(test)]
+#[path = "../../tests/models/stability/gz_curve_test.rs"]
+mod tests;
+///
+/// Righting lever (GZ) curve: GZ values in meters over heel angles in degrees.
+///
+/// The positive GZ rights the ship back to the upright position.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct GzCurve {
+    ///
+    /// (heel, GZ) points sorted by heel.
+    points: Vec<(f64, f64)>,
+}
+//
+//
+impl GzCurve {
+    ///
+    /// Creates a new instance from (heel, GZ) `points`, which are sorted by heel.
+    ///
+    /// Points with non-comparable values (e. g. _NaN_) are dropped.
+    pub fn new(points: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        let mut points = Vec::from_iter(
+            points
+                .into_iter()
+                .filter(|(heel, gz)| !heel.is_nan() && !gz.is_nan()),
+        );
+        points.sort_by(|(l_heel, _), (r_heel, _)| l_heel.total_cmp(r_heel));
+        Self { points }
+    }
+    ///
+    /// Creates a new instance from the cross curves:
+    /// (heel, KN) points and the height of the center of gravity `kg`
+    /// above the point KN is measured from.
+    ///
+    /// GZ = KN - KG * sin(heel).
+    pub fn from_cross_curves(kn: impl IntoIterator<Item = (f64, f64)>, kg: f64) -> Self {
+        Self::new(
+            kn.into_iter()
+                .map(|(heel, kn)| (heel, kn - kg * heel.to_radians().sin())),
+        )
+    }
+    ///
+    /// Returns the curve corrected for the free surfaces of the liquids:
+    /// GZ - `correction` * sin(heel), where `correction` is the virtual rise
+    /// of the center of gravity (see [LoadingCondition::free_surface_correction]).
+    ///
+    /// [LoadingCondition::free_surface_correction]: crate::models::loading_condition::LoadingCondition::free_surface_correction
+    pub fn with_free_surface(self, correction: f64) -> Self {
+        Self::from_cross_curves(self.points, correction)
+    }
+    ///
+    /// Returns (heel, GZ) points sorted by heel.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+    ///
+    /// Returns GZ at `heel` linearly interpolated between the points,
+    /// [None] if `heel` is out of the curve range.
+    pub fn gz(&self, heel: f64) -> Option<f64> {
+        let id = self
+            .points
+            .partition_point(|(point_heel, _)| *point_heel < heel);
+        match (
+            id.checked_sub(1).map(|id| self.points[id]),
+            self.points.get(id),
+        ) {
+            (_, Some(&(r_heel, r_gz))) if r_heel == heel => Some(r_gz),
+            (Some((l_heel, l_gz)), Some(&(r_heel, r_gz))) => {
+                Some(l_gz + (r_gz - l_gz) * (heel - l_heel) / (r_heel - l_heel))
+            }
+            _ => None,
+        }
+    }
+    ///
+    /// Returns the area under the curve between `from` and `to` heels in m·rad,
+    /// [None] if the range is out of the curve range.
+    pub fn area(&self, from: f64, to: f64) -> Option<f64> {
+        let (from, to, sign) = match from <= to {
+            true => (from, to, 1.0),
+            false => (to, from, -1.0),
+        };
+        let mut nodes = vec![(from, self.gz(from)?)];
+        nodes.extend(
+            self.points
+                .iter()
+                .filter(|(heel, _)| from < *heel && *heel < to),
+        );
+        nodes.push((to, self.gz(to)?));
+        let area: f64 = nodes
+            .windows(2)
+            .map(|pair| {
+                let [(l_heel, l_gz), (r_heel, r_gz)] = [pair[0], pair[1]];
+                (r_heel - l_heel).to_radians() * (l_gz + r_gz) / 2.0
+            })
+            .sum();
+        Some(sign * area)
+    }
+    ///
+    /// Returns (heel, GZ) point of the maximum GZ between `from` and `to` heels,
+    /// [None] if the range is out of the curve range.
+    pub fn max(&self, from: f64, to: f64) -> Option<(f64, f64)> {
+        let (from, to) = (from.min(to), from.max(to));
+        let mut nodes = vec![(from, self.gz(from)?), (to, self.gz(to)?)];
+        nodes.extend(
+            self.points
+                .iter()
+                .filter(|(heel, _)| from < *heel && *heel < to),
+        );
+        nodes
+            .into_iter()
+            .max_by(|(_, l_gz), (_, r_gz)| l_gz.total_cmp(r_gz))
+    }
+    ///
+    /// Returns heels, at which the curve crosses the constant `lever`, in ascending order.
+    pub fn crossings(&self, lever: f64) -> Vec<f64> {
+        let mut crossings = vec![];
+        for pair in self.points.windows(2) {
+            let [(l_heel, l_gz), (r_heel, r_gz)] = [pair[0], pair[1]];
+            let (l_diff, r_diff) = (l_gz - lever, r_gz - lever);
+            if l_diff == 0.0 {
+                crossings.push(l_heel);
+            } else if l_diff * r_diff < 0.0 {
+                crossings.push(l_heel + (r_heel - l_heel) * l_diff / (l_diff - r_diff));
+            }
+        }
+        if let Some(&(heel, gz)) = self.points.last() {
+            if gz == lever && crossings.last() != Some(&heel) {
+                crossings.push(heel);
+            }
+        }
+        crossings
+    }
+    ///
+    /// Returns the curve extended to negative heels as the curve of symmetric ship:
+    /// GZ(-heel) = -GZ(heel).
+    ///
+    /// The points of negative heels present in the curve are kept.
+    pub fn symmetric(&self) -> Self {
+        let first = self.points.first().map_or(0.0, |(heel, _)| *heel);
+        Self::new(
+            self.points.iter().copied().chain(
+                self.points
+                    .iter()
+                    .filter(|(heel, _)| *heel > 0.0 && -heel < first)
+                    .map(|(heel, gz)| (-heel, -gz)),
+            ),
+        )
+    }
+}
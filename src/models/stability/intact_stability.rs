@@ -0,0 +1,186 @@
+//!
+//! General intact stability criteria of IMO 2008 IS Code, Part A, 2.2 and 2.3.
+//
+#[cfg(test)]
+#[path = "../../tests/models/stability/intact_stability_test.rs"]
+mod tests;
+pub mod weather_criterion;
+//
+use super::{
+    criterion::{CriteriaReport, Criterion, Limit},
+    gz_curve::GzCurve,
+};
+use weather_criterion::WeatherCriterion;
+///
+/// Evaluates IS Code criteria for the loading condition given by its GZ curve
+/// and the initial metacentric height.
+///
+/// # Examples
+/// ```
+/// fn explaination(gz_curve: GzCurve, weather: WeatherCriterion) {
+///     let report = IntactStability::new(gz_curve, 0.8)
+///         .with_flooding_angle(45.0)
+///         .with_weather(weather)
+///         .report();
+///     log::info!("Intact stability:\n{}", report);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntactStability {
+    gz_curve: GzCurve,
+    ///
+    /// Initial metacentric height corrected for free surface effects, in meters.
+    gm: f64,
+    ///
+    /// Angle of heel in degrees at which openings, which can't be closed weathertight, immerse.
+    flooding_angle: Option<f64>,
+    ///
+    /// Data of the severe wind and rolling criterion, which is skipped if not given.
+    weather: Option<WeatherCriterion>,
+}
+//
+//
+impl IntactStability {
+    ///
+    /// Creates a new instance.
+    /// - gz_curve - righting levers of the loading condition
+    /// - gm - initial metacentric height corrected for free surface effects
+    pub fn new(gz_curve: GzCurve, gm: f64) -> Self {
+        Self {
+            gz_curve,
+            gm,
+            flooding_angle: None,
+            weather: None,
+        }
+    }
+    ///
+    /// Returns the instance limiting areas under GZ curve by `flooding_angle` in degrees.
+    pub fn with_flooding_angle(self, flooding_angle: f64) -> Self {
+        Self {
+            flooding_angle: Some(flooding_angle),
+            ..self
+        }
+    }
+    ///
+    /// Returns the instance checking the severe wind and rolling criterion too.
+    pub fn with_weather(self, weather: WeatherCriterion) -> Self {
+        Self {
+            weather: Some(weather),
+            ..self
+        }
+    }
+    ///
+    /// Returns results of the general criteria (IS Code, 2.2) followed by
+    /// the severe wind and rolling criterion (IS Code, 2.3) if its data is given.
+    ///
+    /// The criterion, which can't be evaluated (e. g. GZ curve doesn't reach 40 degrees),
+    /// has no attained value and fails.
+    pub fn report(&self) -> CriteriaReport {
+        let gz = &self.gz_curve;
+        let limit_40 = self.flooding_angle.map_or(40.0, |angle| angle.min(40.0));
+        let mut criteria = vec![
+            Criterion::new(
+                "Area under GZ curve up to 30 deg",
+                "m*rad",
+                Limit::Min,
+                0.055,
+                gz.area(0.0, 30.0),
+            ),
+            Criterion::new(
+                "Area under GZ curve up to 40 deg or flooding angle",
+                "m*rad",
+                Limit::Min,
+                0.09,
+                gz.area(0.0, limit_40),
+            ),
+            Criterion::new(
+                "Area under GZ curve between 30 and 40 deg or flooding angle",
+                "m*rad",
+                Limit::Min,
+                0.03,
+                (limit_40 > 30.0).then(|| gz.area(30.0, limit_40)).flatten(),
+            ),
+            Criterion::new(
+                "GZ at heel of 30 deg or more",
+                "m",
+                Limit::Min,
+                0.2,
+                gz.points()
+                    .last()
+                    .and_then(|&(last, _)| gz.max(30.0, last))
+                    .map(|(_, gz)| gz),
+            ),
+            Criterion::new(
+                "Heel of maximum GZ",
+                "deg",
+                Limit::Min,
+                25.0,
+                gz.points()
+                    .last()
+                    .and_then(|&(last, _)| gz.max(0.0, last))
+                    .map(|(heel, _)| heel),
+            ),
+            Criterion::new(
+                "Initial metacentric height",
+                "m",
+                Limit::Min,
+                0.15,
+                Some(self.gm),
+            ),
+        ];
+        if let Some(weather) = &self.weather {
+            criteria.extend(self.weather_criteria(weather));
+        }
+        CriteriaReport { criteria }
+    }
+    ///
+    /// Returns results of the severe wind and rolling criterion:
+    /// - the angle of heel under steady wind (θ0) is limited by [WeatherCriterion::max_wind_heel],
+    /// - the area "b" must be equal to or greater than the area "a".
+    ///
+    /// The ship heeled to θ0 by steady wind rolls to windward by [WeatherCriterion::roll_angle] (θ1)
+    /// and is subjected to gust wind lever lw2. The area "a" is between lw2 and GZ curve
+    /// from θ0 - θ1 up to their first intersection θc. The area "b" is between GZ curve and lw2
+    /// from θc up to 50 degrees, the flooding angle, or their second intersection, whichever is less.
+    ///
+    /// The GZ curve is taken as the curve of symmetric ship at negative heels (see [GzCurve::symmetric]).
+    fn weather_criteria(&self, weather: &WeatherCriterion) -> [Criterion; 2] {
+        let gz = self.gz_curve.symmetric();
+        let wind_lever = weather.wind_lever();
+        let gust_lever = weather.gust_lever();
+        let wind_heel = gz
+            .crossings(wind_lever)
+            .into_iter()
+            .find(|heel| *heel >= 0.0);
+        let areas = wind_heel.and_then(|wind_heel| {
+            let roll_heel = wind_heel - weather.roll_angle(self.gm);
+            let crossings = gz.crossings(gust_lever);
+            let mut crossings = crossings.iter().filter(|heel| **heel > roll_heel);
+            let gust_heel = *crossings.next()?;
+            let limit = crossings
+                .next()
+                .map_or(50.0, |heel| heel.min(50.0))
+                .min(self.flooding_angle.unwrap_or(50.0));
+            let area_a = gust_lever * (gust_heel - roll_heel).to_radians()
+                - gz.area(roll_heel, gust_heel)?;
+            let area_b = gz.area(gust_heel, limit)? - gust_lever * (limit - gust_heel).to_radians();
+            Some((area_a, area_b))
+        });
+        [
+            Criterion::new(
+                "Heel under steady wind",
+                "deg",
+                Limit::Max,
+                weather.max_wind_heel(),
+                wind_heel,
+            ),
+            Criterion::new(
+                "Weather criterion area b (required is area a)",
+                "m*rad",
+                Limit::Min,
+                areas.map_or(f64::NAN, |(area_a, _)| area_a),
+                areas.map(|(_, area_b)| area_b),
+            ),
+        ]
+    }
+}
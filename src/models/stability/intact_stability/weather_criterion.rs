@@ -0,0 +1,175 @@
+///
+/// Ship data required by the severe wind and rolling criterion (weather criterion)
+/// of IMO 2008 IS Code, Part A, 2.3.
+///
+/// All values are given for the loading condition checked.
+#[derive(Clone, Debug)]
+pub struct WeatherCriterion {
+    ///
+    /// Displacement in tons.
+    pub displacement: f64,
+    ///
+    /// Projected lateral area of the ship and deck cargo above the waterline in m2.
+    pub windage_area: f64,
+    ///
+    /// Vertical distance in meters from the center of the windage area
+    /// to the center of the underwater lateral area or approximately to a point at one half the mean draught.
+    pub windage_lever: f64,
+    ///
+    /// Moulded breadth in meters.
+    pub breadth: f64,
+    ///
+    /// Mean moulded draught in meters.
+    pub draught: f64,
+    ///
+    /// Length of the waterline in meters.
+    pub waterline_length: f64,
+    ///
+    /// Block coefficient.
+    pub block_coefficient: f64,
+    ///
+    /// Total overall area of bilge keels in m2, zero if there are no bilge keels.
+    pub bilge_keel_area: f64,
+    ///
+    /// The ship has sharp bilges.
+    pub sharp_bilges: bool,
+    ///
+    /// Height of the center of gravity above the keel in meters.
+    pub kg: f64,
+    ///
+    /// Angle of heel in degrees at which the deck edge is immersed, if known.
+    pub deck_edge_angle: Option<f64>,
+    ///
+    /// Wind pressure in Pa.
+    pub wind_pressure: f64,
+}
+//
+//
+impl WeatherCriterion {
+    ///
+    /// Acceleration due to gravity in m/s2.
+    const GRAVITY: f64 = 9.81;
+    ///
+    /// Factor X1 over the ratio of breadth to draught (IS Code, table 2.3.4-1).
+    const X1: [(f64, f64); 11] = [
+        (2.4, 1.0),
+        (2.5, 0.98),
+        (2.6, 0.96),
+        (2.7, 0.95),
+        (2.8, 0.93),
+        (2.9, 0.91),
+        (3.0, 0.90),
+        (3.1, 0.88),
+        (3.2, 0.86),
+        (3.4, 0.82),
+        (3.5, 0.80),
+    ];
+    ///
+    /// Factor X2 over the block coefficient (IS Code, table 2.3.4-2).
+    const X2: [(f64, f64); 6] = [
+        (0.45, 0.75),
+        (0.50, 0.82),
+        (0.55, 0.89),
+        (0.60, 0.95),
+        (0.65, 0.97),
+        (0.70, 1.0),
+    ];
+    ///
+    /// Factor k over the ratio of bilge keels area in percents of length by breadth
+    /// (IS Code, table 2.3.4-3).
+    const K: [(f64, f64); 8] = [
+        (0.0, 1.0),
+        (1.0, 0.98),
+        (1.5, 0.95),
+        (2.0, 0.88),
+        (2.5, 0.79),
+        (3.0, 0.74),
+        (3.5, 0.72),
+        (4.0, 0.70),
+    ];
+    ///
+    /// Factor s over the rolling period in seconds (IS Code, table 2.3.4-4).
+    const S: [(f64, f64); 8] = [
+        (6.0, 0.100),
+        (7.0, 0.098),
+        (8.0, 0.093),
+        (12.0, 0.065),
+        (14.0, 0.053),
+        (16.0, 0.044),
+        (18.0, 0.038),
+        (20.0, 0.035),
+    ];
+    ///
+    /// Returns the steady wind heeling lever in meters: lw1 = P * A * Z / (1000 * g * displacement).
+    pub fn wind_lever(&self) -> f64 {
+        self.wind_pressure * self.windage_area * self.windage_lever
+            / (1000.0 * Self::GRAVITY * self.displacement)
+    }
+    ///
+    /// Returns the gust wind heeling lever in meters: lw2 = 1.5 * lw1.
+    pub fn gust_lever(&self) -> f64 {
+        1.5 * self.wind_lever()
+    }
+    ///
+    /// Returns the rolling period in seconds of the ship with metacentric height `gm`.
+    pub fn roll_period(&self, gm: f64) -> f64 {
+        let c =
+            0.373 + 0.023 * (self.breadth / self.draught) - 0.043 * (self.waterline_length / 100.0);
+        2.0 * c * self.breadth / gm.sqrt()
+    }
+    ///
+    /// Returns the angle of roll in degrees to windward due to wave action
+    /// of the ship with metacentric height `gm`: 109 * k * X1 * X2 * sqrt(r * s).
+    pub fn roll_angle(&self, gm: f64) -> f64 {
+        let x1 = interpolate(&Self::X1, self.breadth / self.draught);
+        let x2 = interpolate(&Self::X2, self.block_coefficient);
+        let k = match self.sharp_bilges {
+            true => 0.7,
+            false => interpolate(
+                &Self::K,
+                self.bilge_keel_area * 100.0 / (self.waterline_length * self.breadth),
+            ),
+        };
+        let r = 0.73 + 0.6 * (self.kg - self.draught) / self.draught;
+        let s = interpolate(&Self::S, self.roll_period(gm));
+        109.0 * k * x1 * x2 * (r * s).sqrt()
+    }
+    ///
+    /// Returns the maximum angle of heel in degrees under the action of steady wind:
+    /// 16 or 80 % of the deck edge immersion angle, whichever is less.
+    pub fn max_wind_heel(&self) -> f64 {
+        self.deck_edge_angle
+            .map_or(16.0, |deck_edge_angle| (0.8 * deck_edge_angle).min(16.0))
+    }
+}
+//
+//
+impl Default for WeatherCriterion {
+    fn default() -> Self {
+        Self {
+            displacement: 0.0,
+            windage_area: 0.0,
+            windage_lever: 0.0,
+            breadth: 0.0,
+            draught: 0.0,
+            waterline_length: 0.0,
+            block_coefficient: 0.0,
+            bilge_keel_area: 0.0,
+            sharp_bilges: false,
+            kg: 0.0,
+            deck_edge_angle: None,
+            wind_pressure: 504.0,
+        }
+    }
+}
+///
+/// Returns the value of `table` at `x` linearly interpolated,
+/// the values out of the table range are taken at the nearest end.
+fn interpolate(table: &[(f64, f64)], x: f64) -> f64 {
+    let id = table.partition_point(|(node, _)| *node < x);
+    match (id.checked_sub(1).map(|id| table[id]), table.get(id)) {
+        (Some((l_x, l_y)), Some(&(r_x, r_y))) => l_y + (r_y - l_y) * (x - l_x) / (r_x - l_x),
+        (None, Some(&(_, y))) | (Some((_, y)), None) => y,
+        (None, None) => f64::NAN,
+    }
+}
@@ -0,0 +1,4 @@
+//!
+//! Strength of the hull girder in the given loading condition.
+//
+pub mod longitudinal_strength;
@@ -0,0 +1,227 @@
+pub mod longitudinal_strength_conf;
+#[cfg(test)]
+#[path = "../../tests/models/strength/longitudinal_strength_test.rs"]
+mod tests;
+//
+use crate::models::loading_condition::LoadingCondition;
+use longitudinal_strength_conf::LongitudinalStrengthConf;
+use std::fmt;
+///
+/// Permissible still-water shear force (t) and bending moment (t·m) at the station
+/// as [min, max] ranges.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct StationLimits {
+    pub shear_force: [f64; 2],
+    pub bending_moment: [f64; 2],
+}
+///
+/// Still-water shear force and bending moment at the station.
+///
+/// The shear force is the net load (weight minus buoyancy) aft of the station, t.
+/// The bending moment is the moment of this load about the station, t·m,
+/// it's positive if the hull hogs, i. e. the weight prevails at the ends.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct StationStrength {
+    ///
+    /// Longitudinal coordinate of the station.
+    pub x: f64,
+    pub shear_force: f64,
+    pub bending_moment: f64,
+    pub limits: Option<StationLimits>,
+}
+//
+//
+impl StationStrength {
+    ///
+    /// Returns _true_ if the shear force is out of the limits.
+    pub fn shear_force_exceeded(&self) -> bool {
+        self.limits.is_some_and(|limits| {
+            let [min, max] = limits.shear_force;
+            !(min..=max).contains(&self.shear_force)
+        })
+    }
+    ///
+    /// Returns _true_ if the bending moment is out of the limits.
+    pub fn bending_moment_exceeded(&self) -> bool {
+        self.limits.is_some_and(|limits| {
+            let [min, max] = limits.bending_moment;
+            !(min..=max).contains(&self.bending_moment)
+        })
+    }
+}
+///
+/// Shear force and bending moment curves checked against the permissible limits.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct StrengthReport {
+    pub stations: Vec<StationStrength>,
+}
+//
+//
+impl StrengthReport {
+    ///
+    /// Returns the stations, where the shear force or the bending moment exceeds the limits.
+    pub fn exceedances(&self) -> Vec<&StationStrength> {
+        Vec::from_iter(
+            self.stations.iter().filter(|station| {
+                station.shear_force_exceeded() || station.bending_moment_exceeded()
+            }),
+        )
+    }
+    ///
+    /// Returns _true_ if no limits are exceeded.
+    pub fn passed(&self) -> bool {
+        self.exceedances().is_empty()
+    }
+}
+//
+//
+impl fmt::Display for StrengthReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let range = |[min, max]: [f64; 2]| format!("[{:.1}, {:.1}]", min, max);
+        for station in &self.stations {
+            write!(
+                f,
+                "x={:.3} m: SF={:.1} t, BM={:.1} t*m",
+                station.x, station.shear_force, station.bending_moment
+            )?;
+            if let Some(limits) = station.limits {
+                write!(
+                    f,
+                    ", SF limits {}, BM limits {}",
+                    range(limits.shear_force),
+                    range(limits.bending_moment)
+                )?;
+                if station.shear_force_exceeded() {
+                    write!(f, " - SF EXCEEDED")?;
+                }
+                if station.bending_moment_exceeded() {
+                    write!(f, " - BM EXCEEDED")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        write!(
+            f,
+            "{}",
+            match self.passed() {
+                true => "Passed",
+                false => "Failed",
+            }
+        )
+    }
+}
+///
+/// Still-water longitudinal strength of the hull girder.
+///
+/// Loads are (x, mass) pieces: weights act down, buoyancy acts up.
+/// The shear force and the bending moment at each station
+/// are got by summing the pieces aft of the station (see [StationStrength]).
+/// So the distributed load must be split at the stations (see [LongitudinalStrength::distribute]).
+pub struct LongitudinalStrength {
+    conf: LongitudinalStrengthConf,
+}
+//
+//
+impl LongitudinalStrength {
+    ///
+    /// Creates a new instance.
+    pub fn new(conf: LongitudinalStrengthConf) -> Self {
+        Self { conf }
+    }
+    ///
+    /// Returns the stations (see [LongitudinalStrengthConf::stations]).
+    pub fn stations(&self) -> &[f64] {
+        &self.conf.stations
+    }
+    ///
+    /// Returns `mass` spread uniformly between `aft` and `fore` split at the stations,
+    /// each piece is placed at its middle.
+    ///
+    /// The mass is the point weight if `aft` and `fore` are the same.
+    pub fn distribute(&self, aft: f64, fore: f64, mass: f64) -> Vec<(f64, f64)> {
+        let (aft, fore) = (aft.min(fore), aft.max(fore));
+        if fore - aft <= f64::EPSILON {
+            return vec![((aft + fore) / 2.0, mass)];
+        }
+        let mut bounds = vec![aft];
+        bounds.extend(self.conf.stations.iter().filter(|&&x| aft < x && x < fore));
+        bounds.push(fore);
+        Vec::from_iter(bounds.windows(2).map(|bound| {
+            (
+                (bound[0] + bound[1]) / 2.0,
+                mass * (bound[1] - bound[0]) / (fore - aft),
+            )
+        }))
+    }
+    ///
+    /// Returns the weight distribution of `loading_condition`, which keeps the mass and LCG
+    /// of the lightship and each deadweight item:
+    /// - the lightship is spread by [LongitudinalStrengthConf::lightship]
+    ///   scaled to its mass and shifted to its LCG,
+    /// - the item is spread uniformly over the longest range centered at its LCG
+    ///   within its extent (see [DeadweightItem::extent]), it's the point weight
+    ///   if there is no extent or LCG is out of it.
+    ///
+    /// The tanks of the condition are supposed to be resolved beforehand.
+    ///
+    /// [DeadweightItem::extent]: crate::models::loading_condition::DeadweightItem::extent
+    pub fn weights(&self, loading_condition: &LoadingCondition) -> Vec<(f64, f64)> {
+        let lightship = loading_condition.lightship;
+        let lcg = lightship.center_of_gravity[0];
+        let total: f64 = self.conf.lightship.iter().map(|(_, _, mass)| mass).sum();
+        let mut weights = match total > 0.0 {
+            true => {
+                let centroid = self
+                    .conf
+                    .lightship
+                    .iter()
+                    .map(|(aft, fore, mass)| mass * (aft + fore) / 2.0)
+                    .sum::<f64>()
+                    / total;
+                let shift = lcg - centroid;
+                Vec::from_iter(self.conf.lightship.iter().flat_map(|&(aft, fore, mass)| {
+                    self.distribute(aft + shift, fore + shift, mass * lightship.mass / total)
+                }))
+            }
+            false => vec![(lcg, lightship.mass)],
+        };
+        for item in &loading_condition.items {
+            let x = item.weight.center_of_gravity[0];
+            let half = match item.extent {
+                Some([aft, fore]) => (x - aft).min(fore - x).max(0.0),
+                None => 0.0,
+            };
+            weights.extend(self.distribute(x - half, x + half, item.weight.mass));
+        }
+        weights
+    }
+    ///
+    /// Returns the shear force and the bending moment at each station
+    /// of `weights` and `buoyancy` given as (x, mass) pieces, checked against the limits.
+    pub fn report(&self, weights: &[(f64, f64)], buoyancy: &[(f64, f64)]) -> StrengthReport {
+        let loads = Vec::from_iter(
+            weights
+                .iter()
+                .copied()
+                .chain(buoyancy.iter().map(|&(x, mass)| (x, -mass))),
+        );
+        StrengthReport {
+            stations: Vec::from_iter(self.conf.stations.iter().enumerate().map(
+                |(id, &station)| {
+                    let (shear_force, bending_moment) = loads
+                        .iter()
+                        .filter(|(x, _)| *x < station)
+                        .fold((0.0, 0.0), |(shear_force, bending_moment), (x, mass)| {
+                            (shear_force + mass, bending_moment + mass * (station - x))
+                        });
+                    StationStrength {
+                        x: station,
+                        shear_force,
+                        bending_moment,
+                        limits: self.conf.limits.get(id).copied(),
+                    }
+                },
+            )),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use super::StationLimits;
+///
+/// [super::LongitudinalStrength] configuration.
+#[derive(Clone, Debug, Default)]
+pub struct LongitudinalStrengthConf {
+    ///
+    /// Longitudinal coordinates in ascending order, where the shear force
+    /// and the bending moment are calculated, usually from the aft to the fore perpendicular.
+    pub stations: Vec<f64>,
+    ///
+    /// Lightship weight distribution: (aft, fore, mass in t) of the parts
+    /// spread uniformly between aft and fore.
+    ///
+    /// It's scaled to the lightship mass of the loading condition
+    /// and shifted longitudinally to the lightship LCG, so only its shape matters.
+    /// If empty, the lightship is the point weight at its LCG.
+    pub lightship: Vec<(f64, f64, f64)>,
+    ///
+    /// Permissible limits at each station in the order of [LongitudinalStrengthConf::stations],
+    /// no limits are checked if empty.
+    pub limits: Vec<StationLimits>,
+}
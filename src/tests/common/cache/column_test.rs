@@ -170,3 +170,56 @@ fn get_bounds_non_descresing() {
     }
     test_duration.exit();
 }
+///
+/// Analyze [Bound]s of grid nodes.
+#[test]
+fn get_node_bounds() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("get_node_bounds".to_string());
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    // init
+    //                0   1   2   3   4   5   6   7   8
+    let values = vec![0., 1., 2., 0., 1., 2., 0., 1., 2.];
+    let column = Column::new(DbgId::with_parent(&dbgid, "Column_0"), values).into_grid_axis();
+    //                    0   1   2   3   4
+    let not_grid_values = vec![0., 1., 1., 2., 2.];
+    let not_grid_column = Column::new(DbgId::with_parent(&dbgid, "Column_1"), not_grid_values);
+    //
+    ////
+    #[rustfmt::skip]
+    let test_data = [
+        // 0
+        (&column, -0.5, Some(Bound::None), vec![]),
+        (&column, 0.0, Some(Bound::Single(0)), vec![0, 3, 6]),
+        (&column, 0.5, Some(Bound::Range(6, 1)), vec![]),
+        (&column, 1.0, Some(Bound::Single(1)), vec![1, 4, 7]),
+        (&column, 1.5, Some(Bound::Range(7, 2)), vec![]),
+        // 5
+        (&column, 2.0, Some(Bound::Single(2)), vec![2, 5, 8]),
+        (&column, 2.5, Some(Bound::None), vec![]),
+        (&not_grid_column, 1.0, None, vec![]),
+    ];
+    for (step, (column, value, target, target_ids)) in test_data.into_iter().enumerate() {
+        let result = column.get_node_bounds(&value);
+        let result_ids = column.get_node_ids(&value);
+        println!(
+            "step={} value={} result={:?} target={:?} result_ids={:?} target_ids={:?}",
+            step, value, result, target, result_ids, target_ids
+        );
+        assert_eq!(
+            result, target,
+            "step={} value={} result={:?} target={:?}",
+            step, value, result, target
+        );
+        assert_eq!(
+            result_ids, target_ids,
+            "step={} value={} result_ids={:?} target_ids={:?}",
+            step, value, result_ids, target_ids
+        );
+    }
+    test_duration.exit();
+}
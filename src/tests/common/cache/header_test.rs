@@ -0,0 +1,171 @@
+use crate::common::cache::{error::CacheError, header::Header};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Parses lines of `text` into [Header].
+fn parse(text: &str) -> Result<Option<Header>, CacheError> {
+    let mut header = None;
+    for (line, line_id) in text.lines().zip(1..) {
+        Header::parse_line(&mut header, line_id, line)?;
+    }
+    Ok(header)
+}
+///
+/// Test [Header] written is parsed back.
+#[test]
+fn parse_line() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Header".to_string());
+    let callee = "parse_line";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let header = Header::new(
+        &["heel", "trim", "draught", "volume"],
+        &["deg", "deg", "m", "m3"],
+    )
+    .with_axes(&["heel", "trim", "draught"])
+    .with_model("/path/to/model.step", "abcdef")
+    .with_meta("heel_steps", "-10 0 10");
+    let result = parse(&header.to_string());
+    assert_eq!(result, Ok(Some(header.clone())), "{}.{}", dbgid, callee);
+    // the header ends at the first line of the dataset
+    let result = Header::parse(&format!("{}0 0 0 0\n# comment\n", header));
+    assert_eq!(result, Ok(Some(header)), "{}.{}", dbgid, callee);
+    assert_eq!(Header::parse("0 0 0 0"), Ok(None), "{}.{}", dbgid, callee);
+    #[rustfmt::skip]
+    let test_data = [
+        ("0.0 1.0", 1),
+        ("# mdmt-cache v2", 1),
+        ("# another-cache v1", 1),
+        ("# mdmt-cache v1\n# columns a b", 2),
+        ("# mdmt-cache v1\n# columns: a b\n# created: yesterday", 3),
+    ];
+    for (step, (text, target)) in test_data.into_iter().enumerate() {
+        let result = parse(text);
+        assert!(
+            matches!(result, Err(CacheError::Header { line, .. }) if line == target),
+            "{}.{} | step={} text='{}' result={:?}",
+            dbgid,
+            callee,
+            step,
+            text,
+            result
+        );
+    }
+    test_duration.exit();
+}
+///
+/// Test [Header::check] compares only non-empty fields of the expected header.
+#[test]
+fn check() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Header".to_string());
+    let callee = "check";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let header = Header::new(&["heel", "trim"], &["deg", "deg"])
+        .with_axes(&["heel"])
+        .with_model("/path/to/model.step", "abcdef")
+        .with_meta("heel_steps", "-10 0 10");
+    let columns = |columns: &[&str]| Header {
+        columns: Vec::from_iter(columns.iter().map(|s| s.to_string())),
+        ..Header::default()
+    };
+    let mismatch = |key: &str, expected: &str, found: &str| {
+        Err(CacheError::Mismatch {
+            key: key.to_owned(),
+            expected: expected.to_owned(),
+            found: found.to_owned(),
+        })
+    };
+    #[rustfmt::skip]
+    let test_data = [
+        (Header::default(), Ok(())),
+        (columns(&["heel", "trim"]), Ok(())),
+        (columns(&["trim", "heel"]), mismatch("columns", "trim heel", "heel trim")),
+        (Header::default().with_axes(&["heel"]), Ok(())),
+        (Header::default().with_axes(&["heel", "trim"]), mismatch("axes", "heel trim", "heel")),
+        (Header::default().with_model("", "abcdef"), Ok(())),
+        (Header::default().with_model("", "012345"), mismatch("model_hash", "012345", "abcdef")),
+        (Header::default().with_meta("heel_steps", "-10 0 10"), Ok(())),
+        (Header::default().with_meta("trim_steps", "0"), mismatch("trim_steps", "0", "")),
+    ];
+    for (step, (expected, target)) in test_data.into_iter().enumerate() {
+        let result = header.check(&expected);
+        assert_eq!(
+            result, target,
+            "{}.{} | step={} expected={:?}",
+            dbgid, callee, step, expected
+        );
+    }
+    test_duration.exit();
+}
+///
+/// Test [Header::validate] against the dataset width and the declared axes.
+#[test]
+fn validate() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Header".to_string());
+    let callee = "validate";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let header = Header::new(&["heel", "trim", "volume"], &["deg", "deg", "m3"]);
+    #[rustfmt::skip]
+    let test_data = [
+        (header.clone(), 3, Ok(())),
+        (header.clone().with_axes(&["heel", "trim"]), 3, Ok(())),
+        (Header::default(), 3, Ok(())),
+        (
+            header.clone(),
+            4,
+            Err(CacheError::Mismatch { key: "columns".to_owned(), expected: "4".to_owned(), found: "3".to_owned() }),
+        ),
+        (
+            header.clone().with_axes(&["heel", "draught"]),
+            3,
+            Err(CacheError::UnknownColumn { name: "draught".to_owned() }),
+        ),
+        (
+            Header::default().with_axes(&["heel"]),
+            3,
+            Err(CacheError::UnknownColumn { name: "heel".to_owned() }),
+        ),
+    ];
+    for (step, (header, columns, target)) in test_data.into_iter().enumerate() {
+        let result = header.validate(columns);
+        assert_eq!(
+            result, target,
+            "{}.{} | step={} header={:?}",
+            dbgid, callee, step, header
+        );
+    }
+    let is_axis = Vec::from_iter((0..4).map(|id| header.clone().with_axes(&["trim"]).is_axis(id)));
+    assert_eq!(is_axis, [false, true, false, false], "{}.{}", dbgid, callee);
+    test_duration.exit();
+}
@@ -0,0 +1,98 @@
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use std::{sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+
+use crate::common::cache::interpolation::Interpolation;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Values approximated by each method.
+#[test]
+fn apply() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = "apply";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    #[rustfmt::skip]
+    let test_data = [
+        // 0
+        (Interpolation::Nearest, vec![0., 1.], vec![0., 10.], 0.4, 0.0),
+        (Interpolation::Nearest, vec![0., 1.], vec![0., 10.], 0.5, 0.0),
+        (Interpolation::Nearest, vec![0., 1.], vec![0., 10.], 0.6, 10.0),
+        (Interpolation::Linear, vec![0., 1.], vec![0., 10.], 0.25, 2.5),
+        (Interpolation::Linear, vec![1., 0.], vec![10., 0.], 0.25, 2.5),
+        // 5
+        (Interpolation::Linear, vec![0.], vec![7.], 0.25, 7.0),
+        (Interpolation::Spline, vec![0., 1., 2., 3.], vec![0., 2., 4., 6.], 1.5, 3.0),
+        (Interpolation::Spline, vec![0., 1., 2., 3.], vec![0., 1., 4., 9.], 1.5, 2.25),
+        (Interpolation::Spline, vec![3., 2., 1., 0.], vec![9., 4., 1., 0.], 1.5, 2.25),
+        (Interpolation::Spline, vec![0., 1., 2., 3.], vec![0., 0., 1., 1.], 1.5, 0.5),
+        // 10
+        (Interpolation::Spline, vec![0., 1., 2.], vec![0., 1., 4.], 0.5, 0.375),
+        (Interpolation::Spline, vec![0., 1.], vec![0., 10.], 0.25, 2.5),
+    ];
+    for (step, (interpolation, xs, ys, x, target)) in test_data.into_iter().enumerate() {
+        let result = interpolation.apply(&xs, &ys, x);
+        println!(
+            "step={} interpolation={:?} x={} result={} target={}",
+            step, interpolation, x, result, target
+        );
+        assert!(
+            (result - target).abs() < 1e-12,
+            "step={} interpolation={:?} xs={:?} ys={:?} x={} result={} target={}",
+            step,
+            interpolation,
+            xs,
+            ys,
+            x,
+            result,
+            target
+        );
+    }
+    test_duration.exit();
+}
+///
+/// Monotone spline must not overshoot monotonic data.
+#[test]
+fn apply_spline_monotonic() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = "apply_spline_monotonic";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let xs = [0., 1., 2., 3.];
+    let ys = [0., 1., 1.01, 10.];
+    let mut prev = ys[1];
+    for step in 0..=100 {
+        let x = 1.0 + step as f64 / 100.0;
+        let result = Interpolation::Spline.apply(&xs, &ys, x);
+        assert!(
+            (ys[1]..=ys[2]).contains(&result) && result >= prev,
+            "step={} x={} result={} prev={}",
+            step,
+            x,
+            result,
+            prev
+        );
+        prev = result;
+    }
+    test_duration.exit();
+}
@@ -3,7 +3,13 @@ use sal_sync::services::entity::dbg_id::DbgId;
 use std::{sync::Once, time::Duration};
 use testing::stuff::max_test_duration::TestDuration;
 
-use crate::common::cache::{column::Column, table::Table};
+use crate::common::cache::{
+    column::Column,
+    error::CacheError,
+    extrapolation::{Approximation, Extrapolation},
+    interpolation::Interpolation,
+    table::Table,
+};
 //
 //
 static INIT: Once = Once::new();
@@ -53,9 +59,16 @@ fn get_unchecked() {
         });
         let dbgid = DbgId::with_parent(&dbgid, &format!("Column_{}", col_id));
         let column = Column::new(dbgid, values);
-        columns.push(column);
+        // columns 0, 1, and 2 make 2×2×2 grid
+        columns.push(match col_id < 3 {
+            true => column.into_grid_axis(),
+            false => column,
+        });
     }
     let table = Table::new(&dbgid, columns);
+    // weights of the end rows for values placed in between two rows of column 3
+    let t_10_1 = (10.1 - 10.0) / (20.0 - 10.0);
+    let t_21_0 = (21.0 - 21.1) / (11.1 - 21.1);
     //
     ////
     #[rustfmt::skip]
@@ -64,19 +77,18 @@ fn get_unchecked() {
         ([Some(0.0), Some(1.0), Some(1.0)].as_slice(),   vec![vec![0.0, 1.0, 1.0, 11.1]],),
         (&[Some(0.0), Some(1.0), Some(1.0), None],       vec![vec![0.0, 1.0, 1.0, 11.1]],),
         (&[Some(0.0), Some(1.0), Some(1.0), Some(11.1)], vec![vec![0.0, 1.0, 1.0, 11.1]],),
-        (&[Some(0.0), Some(1.0), None, Some(11.1)],      vec![vec![0.0, 1.0, 0.0, 11.0], vec![0.0, 1.0, 1.0, 11.1]],),
-        (&[Some(0.0), None, Some(1.0), Some(11.1)],      vec![vec![0.0, 0.0, 1.0, 10.1], vec![0.0, 1.0, 1.0, 11.1]],),
+        (&[Some(0.0), Some(1.0), None, Some(11.1)],      vec![vec![0.0, 1.0, 1.0, 11.1]],),
+        (&[Some(0.0), None, Some(1.0), Some(11.1)],      vec![vec![0.0, 1.0, 1.0, 11.1]],),
         // 5
         (&[None, Some(1.0), Some(1.0), Some(11.1)], vec![vec![0.0, 1.0, 1.0, 11.1]],),
         (&[Some(0.0), Some(1.0)],                   vec![vec![0.0, 1.0, 0.0, 11.0], vec![0.0, 1.0, 1.0, 11.1]],),
         (&[Some(0.0), None, Some(1.0)],             vec![vec![0.0, 0.0, 1.0, 10.1], vec![0.0, 1.0, 1.0, 11.1]],),
-        (&[Some(0.0), None, None, Some(11.0)],      vec![
-                                                        vec![0.0, 0.0, 0.0, 10.0],
-                                                        vec![0.0, 1.0, 0.0, 11.0],
+        (&[Some(0.0), None, None, Some(11.0)],      vec![vec![0.0, 1.0, 0.0, 11.0]],),
+        (&[None, Some(0.0), None, Some(10.1)],      vec![
+                                                        vec![0.0 + t_10_1 * (1.0 - 0.0), 0.0, 0.0, 10.0 + t_10_1 * (20.0 - 10.0)],
                                                         vec![0.0, 0.0, 1.0, 10.1],
                                                     ],
         ),
-        (&[None, Some(0.0), None, Some(10.1)],      vec![vec![0.0, 0.0, 0.0, 10.0], vec![0.0, 0.0, 1.0, 10.1]],),
         // 10
         (&[None, None, Some(0.0), Some(21.0)], vec![vec![1.0, 1.0, 0.0, 21.0]],),
         (&[Some(0.0)],                         vec![
@@ -88,32 +100,51 @@ fn get_unchecked() {
         ),
         (&[None, Some(0.0)],                   vec![
                                                 vec![0.0, 0.0, 0.0, 10.0],
+                                                vec![1.0, 0.0, 0.0, 20.0],
                                                 vec![0.0, 0.0, 1.0, 10.1],
                                                 vec![1.0, 0.0, 1.0, 20.1],
                                             ]
         ),
         (&[None, None, Some(0.0)],             vec![
                                                 vec![0.0, 0.0, 0.0, 10.0],
+                                                vec![1.0, 0.0, 0.0, 20.0],
+                                                vec![0.0, 1.0, 0.0, 11.0],
                                                 vec![1.0, 1.0, 0.0, 21.0],
                                             ]
         ),
         (&[None, None, None, Some(21.0)],      vec![
                                                 vec![1.0, 1.0, 0.0, 21.0],
-                                                vec![0.5, 1.0, 1.0, 16.1]
+                                                vec![1.0 + t_21_0 * (0.0 - 1.0), 1.0, 1.0, 21.1 + t_21_0 * (11.1 - 21.1)]
                                             ]
         ,),
         // 15
         (&[Some(0.5)], vec![
-                           vec![0.5, 0.0, 0.0, 15.0], 
-                           vec![0.5, 0.5, 0.0, 15.5], 
-                           vec![0.5, 0.5, 0.5, 15.55], 
-                           vec![0.5, 1.0, 1.0, 16.1], 
-                           vec![0.5, 0.5, 1.0, (11.1 + 20.1) / 2.]
+                           vec![0.5, 0.0, 0.0, 0.5 * 10.0 + 0.5 * 20.0],
+                           vec![0.5, 1.0, 0.0, 0.5 * 11.0 + 0.5 * 21.0],
+                           vec![0.5, 0.0, 1.0, 0.5 * 10.1 + 0.5 * 20.1],
+                           vec![0.5, 1.0, 1.0, 0.5 * 11.1 + 0.5 * 21.1],
                        ],
         ),
+        (&[Some(0.25), Some(1.0)], vec![
+                           vec![0.25, 1.0, 0.0, 0.75 * 11.0 + 0.25 * 21.0],
+                           vec![0.25, 1.0, 1.0, 0.75 * 11.1 + 0.25 * 21.1],
+                       ],
+        ),
+        (&[Some(0.5), Some(0.5), Some(0.5)], vec![
+                           vec![
+                               0.5, 0.5, 0.5,
+                               0.125 * 10.0 + 0.125 * 10.1 + 0.125 * 11.0 + 0.125 * 11.1
+                                   + 0.125 * 20.0 + 0.125 * 20.1 + 0.125 * 21.0 + 0.125 * 21.1,
+                           ],
+                       ],
+        ),
+        (&[Some(1.5)], vec![]),
     ];
     for (step, (value, target)) in test_data.into_iter().enumerate() {
-        let result = table.get_unchecked(value);
+        let result = table
+            .get_unchecked(value, Interpolation::Linear, Extrapolation::Error)
+            .map(|approximation| approximation.rows)
+            .unwrap_or_default();
         println!(
             "{}.{} | step={} value={:?} result={:?} target={:?}",
             dbgid, callee, step, value, result, target
@@ -126,3 +157,495 @@ fn get_unchecked() {
     }
     test_duration.exit();
 }
+///
+/// Test each interpolation method over heel×trim×draught grid.
+#[test]
+fn get_unchecked_grid() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Table grid".to_string());
+    let callee = "get_unchecked_grid";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    // init
+    //
+    // the function is linear on each axis,
+    // so multilinear interpolation must give the exact value
+    let func = |heel: f64, trim: f64, draught: f64| {
+        100.0 + 2.0 * heel - 3.0 * trim + 10.0 * draught + heel * trim * draught
+    };
+    // rows are ordered as the floating position cache does
+    let mut values = [vec![], vec![], vec![], vec![]];
+    for draught in [0.0, 0.5, 1.0] {
+        for heel in [-10.0, 0.0, 10.0] {
+            for trim in [-5.0, 0.0, 5.0] {
+                for (col, val) in
+                    values
+                        .iter_mut()
+                        .zip([heel, trim, draught, func(heel, trim, draught)])
+                {
+                    col.push(val);
+                }
+            }
+        }
+    }
+    let columns = Vec::from_iter(values.into_iter().enumerate().map(|(col_id, values)| {
+        let dbgid = DbgId::with_parent(&dbgid, &format!("Column_{}", col_id));
+        match col_id < 3 {
+            true => Column::new(dbgid, values).into_grid_axis(),
+            false => Column::new(dbgid, values),
+        }
+    }));
+    let table = Table::new(&dbgid, columns);
+    //
+    ////
+    //
+    // linear and spline interpolations must give the exact values,
+    // the nearest one gives values of the nearest node
+    #[rustfmt::skip]
+    let test_data = [
+        // 0
+        (Interpolation::Linear, [Some(0.0), Some(0.0), Some(0.5)], vec![[0.0, 0.0, 0.5]]),
+        (Interpolation::Linear, [Some(0.1), Some(0.0), Some(0.5)], vec![[0.1, 0.0, 0.5]]),
+        (Interpolation::Linear, [Some(0.1), Some(2.5), Some(0.0)], vec![[0.1, 2.5, 0.0]]),
+        (Interpolation::Linear, [Some(-7.5), Some(-1.0), Some(0.7)], vec![[-7.5, -1.0, 0.7]]),
+        (Interpolation::Linear, [Some(10.0), Some(5.0), Some(0.99)], vec![[10.0, 5.0, 0.99]]),
+        // 5
+        (Interpolation::Linear, [Some(10.1), Some(5.0), Some(0.99)], vec![]),
+        (Interpolation::Linear, [Some(10.0), Some(5.0), Some(-0.1)], vec![]),
+        (Interpolation::Spline, [Some(0.1), Some(2.5), Some(0.0)], vec![[0.1, 2.5, 0.0]]),
+        (Interpolation::Spline, [Some(-7.5), Some(-1.0), Some(0.7)], vec![[-7.5, -1.0, 0.7]]),
+        (Interpolation::Spline, [Some(10.0), Some(5.0), Some(0.99)], vec![[10.0, 5.0, 0.99]]),
+        // 10
+        (Interpolation::Nearest, [Some(0.1), Some(2.5), Some(0.0)], vec![[0.0, 0.0, 0.0]]),
+        (Interpolation::Nearest, [Some(-7.5), Some(-1.0), Some(0.7)], vec![[-10.0, 0.0, 0.5]]),
+        (Interpolation::Nearest, [Some(10.0), Some(5.0), Some(0.99)], vec![[10.0, 5.0, 1.0]]),
+    ];
+    for (step, (interpolation, value, target)) in test_data.into_iter().enumerate() {
+        let result = table
+            .get_unchecked(&value, interpolation, Extrapolation::Error)
+            .map(|approximation| approximation.rows)
+            .unwrap_or_default();
+        println!(
+            "{}.{} | step={} interpolation={:?} value={:?} result={:?} target={:?}",
+            dbgid, callee, step, interpolation, value, result, target
+        );
+        assert_eq!(
+            target.len(),
+            result.len(),
+            "{}.{} | step={} value={:?} result={:?} target={:?}",
+            dbgid,
+            callee,
+            step,
+            value,
+            result,
+            target
+        );
+        for ([heel, trim, draught], row) in target.into_iter().zip(result) {
+            let target_row = [heel, trim, draught, func(heel, trim, draught)];
+            for (target_val, val) in target_row.into_iter().zip(row) {
+                assert!(
+                    (target_val - val).abs() < 1e-9,
+                    "{}.{} | step={} value={:?} result={:?} target={:?}",
+                    dbgid,
+                    callee,
+                    step,
+                    value,
+                    val,
+                    target_val
+                );
+            }
+        }
+    }
+    test_duration.exit();
+}
+///
+/// Test values out of the table range.
+#[test]
+fn get_unchecked_extrapolation() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Table extrapolation".to_string());
+    let callee = "get_unchecked_extrapolation";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    // init
+    //
+    // the first `axes` columns are grid axes
+    let new_table = |values: [Vec<f64>; 3], axes: usize| {
+        let columns = Vec::from_iter(values.into_iter().enumerate().map(|(col_id, values)| {
+            let dbgid = DbgId::with_parent(&dbgid, &format!("Column_{}", col_id));
+            match col_id < axes {
+                true => Column::new(dbgid, values).into_grid_axis(),
+                false => Column::new(dbgid, values),
+            }
+        }));
+        Table::new(&dbgid, columns)
+    };
+    // 2×3 grid, the function is linear on each axis
+    let func = |x: f64, y: f64| 1.0 + 2.0 * x - y;
+    let mut grid = [vec![], vec![], vec![]];
+    for x in [0.0, 1.0] {
+        for y in [0.0, 1.0, 2.0] {
+            for (col, val) in grid.iter_mut().zip([x, y, func(x, y)]) {
+                col.push(val);
+            }
+        }
+    }
+    let grid = new_table(grid, 2);
+    // the same function, but there is no grid
+    let free_x = vec![0.0, 1.0, 1.0, 0.5];
+    let free_y = vec![0.0, 1.0, 2.0, 3.0];
+    let free_func = Vec::from_iter(free_x.iter().zip(&free_y).map(|(&x, &y)| func(x, y)));
+    let free = new_table([free_x, free_y, free_func], 0);
+    //
+    ////
+    #[rustfmt::skip]
+    let test_data = [
+        // 0
+        (&grid, Extrapolation::Error, [Some(2.0), Some(1.0)], None),
+        (&grid, Extrapolation::Error, [Some(1.0), Some(-1.0)], None),
+        (&grid, Extrapolation::Clamp, [Some(2.0), Some(1.0)], Some((vec![vec![1.0, 1.0, func(1.0, 1.0)]], vec![0]))),
+        (&grid, Extrapolation::Clamp, [Some(-1.0), Some(3.0)], Some((vec![vec![0.0, 2.0, func(0.0, 2.0)]], vec![0, 1]))),
+        (&grid, Extrapolation::Linear, [Some(2.0), Some(1.0)], Some((vec![vec![2.0, 1.0, func(2.0, 1.0)]], vec![]))),
+        // 5
+        (&grid, Extrapolation::Linear, [Some(0.5), Some(-1.0)], Some((vec![vec![0.5, -1.0, func(0.5, -1.0)]], vec![]))),
+        (&grid, Extrapolation::Linear, [Some(-1.0), Some(2.5)], Some((vec![vec![-1.0, 2.5, func(-1.0, 2.5)]], vec![]))),
+        (&free, Extrapolation::Error, [None, Some(4.0)], None),
+        (&free, Extrapolation::Clamp, [None, Some(4.0)], Some((vec![vec![0.5, 3.0, func(0.5, 3.0)]], vec![1]))),
+        (&free, Extrapolation::Linear, [None, Some(4.0)], Some((vec![vec![0.0, 4.0, func(0.0, 4.0)]], vec![]))),
+        // 10
+        (&free, Extrapolation::Linear, [None, Some(-1.0)], Some((vec![vec![-1.0, -1.0, func(-1.0, -1.0)]], vec![]))),
+    ];
+    for (step, (table, extrapolation, value, target)) in test_data.into_iter().enumerate() {
+        let result = table.get_unchecked(&value, Interpolation::Linear, extrapolation);
+        println!(
+            "{}.{} | step={} extrapolation={:?} value={:?} result={:?} target={:?}",
+            dbgid, callee, step, extrapolation, value, result, target
+        );
+        match (result, target) {
+            (Err(_), None) => {}
+            (Ok(Approximation { rows, clamped }), Some((target_rows, target_clamped))) => {
+                assert_eq!(
+                    clamped, target_clamped,
+                    "{}.{} | step={} value={:?} clamped={:?} target={:?}",
+                    dbgid, callee, step, value, clamped, target_clamped
+                );
+                assert_eq!(
+                    rows.len(),
+                    target_rows.len(),
+                    "{}.{} | step={} value={:?} rows={:?} target={:?}",
+                    dbgid,
+                    callee,
+                    step,
+                    value,
+                    rows,
+                    target_rows
+                );
+                for (row, target_row) in rows.into_iter().zip(target_rows) {
+                    for (val, target_val) in row.into_iter().zip(target_row) {
+                        assert!(
+                            (val - target_val).abs() < 1e-9,
+                            "{}.{} | step={} value={:?} result={} target={}",
+                            dbgid,
+                            callee,
+                            step,
+                            value,
+                            val,
+                            target_val
+                        );
+                    }
+                }
+            }
+            (result, target) => panic!(
+                "{}.{} | step={} value={:?} result={:?} target={:?}",
+                dbgid, callee, step, value, result, target
+            ),
+        }
+    }
+    test_duration.exit();
+}
+///
+/// Test inverse lookup of [Table].
+#[test]
+fn solve() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Table solve".to_string());
+    let callee = "solve";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    // init
+    //
+    // the first `axes` columns are grid axes
+    let new_table = |values: [Vec<f64>; 3], axes: usize| {
+        let columns = Vec::from_iter(values.into_iter().enumerate().map(|(col_id, values)| {
+            let dbgid = DbgId::with_parent(&dbgid, &format!("Column_{}", col_id));
+            match col_id < axes {
+                true => Column::new(dbgid, values).into_grid_axis(),
+                false => Column::new(dbgid, values),
+            }
+        }));
+        Table::new(&dbgid, columns)
+    };
+    // 3×4 grid of heel, draught, and volume, which is bilinear
+    let func = |heel: f64, draught: f64| (1.0 + heel) * draught;
+    let mut grid = [vec![], vec![], vec![]];
+    for heel in [0.0, 1.0, 2.0] {
+        for draught in [0.0, 1.0, 2.0, 3.0] {
+            for (col, val) in grid.iter_mut().zip([heel, draught, func(heel, draught)]) {
+                col.push(val);
+            }
+        }
+    }
+    let grid = new_table(grid, 2);
+    // non-monotonic curve y = (x - 2)^2
+    let free_x = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let free_y = Vec::from_iter(free_x.iter().map(|x| (x - 2.0) * (x - 2.0)));
+    let free_z = Vec::from_iter(free_x.iter().map(|x| 10.0 * x));
+    let free = new_table([free_x, free_y, free_z], 0);
+    //
+    ////
+    #[rustfmt::skip]
+    let test_data = [
+        // 0
+        (&grid, [Some(0.5), None, Some(3.0)], 1, Ok(vec![vec![0.5, 2.0, 3.0]])),
+        (&grid, [Some(1.0), None, Some(2.0)], 1, Ok(vec![vec![1.0, 1.0, 2.0]])),
+        (&grid, [None, None, Some(3.0)], 1, Ok(vec![vec![0.0, 3.0, 3.0], vec![1.0, 1.5, 3.0], vec![2.0, 1.0, 3.0]])),
+        (&grid, [None, None, Some(6.0)], 1, Ok(vec![vec![1.0, 3.0, 6.0], vec![2.0, 2.0, 6.0]])),
+        (&grid, [Some(0.0), None, Some(10.0)], 1, Err(CacheError::OutOfRange { column: 2, value: 10.0, min: 0.0, max: 3.0 })),
+        // 5
+        (&grid, [Some(0.0), Some(1.0), Some(1.0)], 1, Err(CacheError::InvalidUnknown { column: 1 })),
+        (&grid, [Some(0.0), None, Some(1.0)], 3, Err(CacheError::InvalidUnknown { column: 3 })),
+        (&free, [None, Some(1.0), None], 0, Ok(vec![vec![1.0, 1.0, 10.0], vec![3.0, 1.0, 30.0]])),
+        (&free, [None, Some(0.25), None], 0, Ok(vec![vec![1.75, 0.25, 17.5], vec![2.25, 0.25, 22.5]])),
+        (&free, [None, Some(f64::NAN), None], 0, Err(CacheError::NonComparableQuery { column: 1 })),
+    ];
+    for (step, (table, value, unknown, target)) in test_data.into_iter().enumerate() {
+        let result = table.solve(&value, unknown, Interpolation::Linear, Extrapolation::Error);
+        println!(
+            "{}.{} | step={} value={:?} unknown={} result={:?} target={:?}",
+            dbgid, callee, step, value, unknown, result, target
+        );
+        match (result, target) {
+            (Err(err), Err(target_err)) => assert_eq!(
+                err, target_err,
+                "{}.{} | step={} value={:?}",
+                dbgid, callee, step, value
+            ),
+            (Ok(rows), Ok(target_rows)) => {
+                assert_eq!(
+                    rows.len(),
+                    target_rows.len(),
+                    "{}.{} | step={} value={:?} rows={:?} target={:?}",
+                    dbgid,
+                    callee,
+                    step,
+                    value,
+                    rows,
+                    target_rows
+                );
+                for (row, target_row) in rows.into_iter().zip(target_rows) {
+                    for (val, target_val) in row.into_iter().zip(target_row) {
+                        assert!(
+                            (val - target_val).abs() < 1e-9,
+                            "{}.{} | step={} value={:?} result={} target={}",
+                            dbgid,
+                            callee,
+                            step,
+                            value,
+                            val,
+                            target_val
+                        );
+                    }
+                }
+            }
+            (result, target) => panic!(
+                "{}.{} | step={} value={:?} result={:?} target={:?}",
+                dbgid, callee, step, value, result, target
+            ),
+        }
+    }
+    test_duration.exit();
+}
+///
+/// Test the column depending on the preceding grid axes only,
+/// which values repeat like grid axis nodes, but it isn't declared as a grid axis.
+#[test]
+fn dependent_column() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Table dependent column".to_string());
+    let callee = "dependent_column";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    // init
+    //
+    // 3×4 grid of heel and draught, volume depends on draught only,
+    // so each its value is repeated for each heel
+    let mut values = [vec![], vec![], vec![]];
+    for heel in [0.0, 1.0, 2.0] {
+        for draught in [0.0, 1.0, 2.0, 3.0] {
+            for (col, val) in values.iter_mut().zip([heel, draught, 10.0 * draught]) {
+                col.push(val);
+            }
+        }
+    }
+    let columns = Vec::from_iter(values.into_iter().enumerate().map(|(col_id, values)| {
+        let dbgid = DbgId::with_parent(&dbgid, &format!("Column_{}", col_id));
+        match col_id < 2 {
+            true => Column::new(dbgid, values).into_grid_axis(),
+            false => Column::new(dbgid, values),
+        }
+    }));
+    let table = Table::new(&dbgid, columns);
+    //
+    ////
+    //
+    #[rustfmt::skip]
+    let test_data = [
+        // 0
+        ([Some(0.5), Some(1.5), None], vec![[0.5, 1.5, 15.0]]),
+        ([Some(2.0), Some(0.25), None], vec![[2.0, 0.25, 2.5]]),
+        ([Some(1.0), None, Some(25.0)], vec![[1.0, 2.5, 25.0]]),
+        ([Some(1.5), Some(3.0), Some(30.0)], vec![[1.5, 3.0, 30.0]]),
+    ];
+    for (step, (value, target)) in test_data.into_iter().enumerate() {
+        let result = table
+            .get_unchecked(&value, Interpolation::Linear, Extrapolation::Error)
+            .map(|approximation| approximation.rows)
+            .unwrap_or_default();
+        assert_eq!(
+            result.len(),
+            target.len(),
+            "{}.{} | step={} value={:?} result={:?} target={:?}",
+            dbgid,
+            callee,
+            step,
+            value,
+            result,
+            target
+        );
+        for (row, target_row) in result.into_iter().zip(target) {
+            for (val, target_val) in row.into_iter().zip(target_row) {
+                assert!(
+                    (val - target_val).abs() < 1e-9,
+                    "{}.{} | step={} value={:?} result={} target={}",
+                    dbgid,
+                    callee,
+                    step,
+                    value,
+                    val,
+                    target_val
+                );
+            }
+        }
+    }
+    // the value is solved along the draught as the volume isn't an axis to split the rows by
+    let result = table.solve(
+        &[Some(0.5), None, Some(15.0)],
+        1,
+        Interpolation::Linear,
+        Extrapolation::Error,
+    );
+    assert!(
+        result
+            .as_ref()
+            .is_ok_and(|rows| rows.len() == 1 && (rows[0][1] - 1.5).abs() < 1e-9),
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    test_duration.exit();
+}
+///
+/// Test the free value interpolated by spline takes the support rows of the same nodes only,
+/// even if the rows of other nodes are within the monotonic segment.
+#[test]
+fn spline_support() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Table spline support".to_string());
+    let callee = "spline_support";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    // init
+    //
+    // 3×4 grid of heel and draught, volume increases through all the rows,
+    // so the whole volume column is a single monotonic segment
+    let mut values = [vec![], vec![], vec![]];
+    for heel in [0.0, 1.0, 2.0] {
+        for draught in [0.0, 1.0, 2.0, 3.0] {
+            for (col, val) in values
+                .iter_mut()
+                .zip([heel, draught, 100.0 * heel + 10.0 * draught])
+            {
+                col.push(val);
+            }
+        }
+    }
+    let columns = Vec::from_iter(values.into_iter().enumerate().map(|(col_id, values)| {
+        let dbgid = DbgId::with_parent(&dbgid, &format!("Column_{}", col_id));
+        match col_id < 2 {
+            true => Column::new(dbgid, values).into_grid_axis(),
+            false => Column::new(dbgid, values),
+        }
+    }));
+    let table = Table::new(&dbgid, columns);
+    //
+    ////
+    //
+    // the rows next to the first and the last rows of the node are of other heel
+    #[rustfmt::skip]
+    let test_data = [
+        // 0
+        (Interpolation::Spline, [Some(1.0), None, Some(105.0)], vec![[1.0, 0.5, 105.0]]),
+        (Interpolation::Spline, [Some(1.0), None, Some(115.0)], vec![[1.0, 1.5, 115.0]]),
+        (Interpolation::Spline, [Some(1.0), None, Some(125.0)], vec![[1.0, 2.5, 125.0]]),
+        (Interpolation::Spline, [Some(0.0), None, Some(25.0)], vec![[0.0, 2.5, 25.0]]),
+        (Interpolation::Linear, [Some(2.0), None, Some(205.0)], vec![[2.0, 0.5, 205.0]]),
+    ];
+    for (step, (interpolation, value, target)) in test_data.into_iter().enumerate() {
+        let result = table
+            .get_unchecked(&value, interpolation, Extrapolation::Error)
+            .map(|approximation| approximation.rows)
+            .unwrap_or_default();
+        assert_eq!(
+            result.len(),
+            target.len(),
+            "{}.{} | step={} value={:?} result={:?} target={:?}",
+            dbgid,
+            callee,
+            step,
+            value,
+            result,
+            target
+        );
+        for (row, target_row) in result.into_iter().zip(target) {
+            for (val, target_val) in row.into_iter().zip(target_row) {
+                assert!(
+                    (val - target_val).abs() < 1e-9,
+                    "{}.{} | step={} value={:?} result={} target={}",
+                    dbgid,
+                    callee,
+                    step,
+                    value,
+                    val,
+                    target_val
+                );
+            }
+        }
+    }
+    test_duration.exit();
+}
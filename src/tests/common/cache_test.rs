@@ -1,4 +1,4 @@
-use crate::common::cache::Cache;
+use crate::common::cache::{error::CacheError, header::Header, Cache};
 use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
 use sal_sync::services::entity::dbg_id::DbgId;
 use std::{sync::Once, time::Duration};
@@ -86,3 +86,280 @@ fn init_cache_table_from_inconsistent_files() {
     }
     test_duration.exit();
 }
+///
+/// Test [Cache::try_new] and [Cache::try_get] return errors instead of panic.
+#[test]
+fn try_get_errors() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let callee = "try_get_errors";
+    let dbgid = DbgId("test Cache".to_string());
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let test_data = [
+        (
+            "src/tests/common/cache/assets/table-inc-row",
+            CacheError::Inconsistent { line: 6 },
+        ),
+        (
+            "src/tests/common/cache/assets/table-inc-col",
+            CacheError::Inconsistent { line: 8 },
+        ),
+        (
+            "src/tests/common/cache/assets/table-parse-err",
+            CacheError::Parse { line: 3, col: 3 },
+        ),
+        (
+            "src/tests/common/cache/assets/table-nan",
+            CacheError::NonComparable { column: 3, row: 2 },
+        ),
+    ];
+    for (path, target) in test_data {
+        let result = Cache::try_new(&dbgid, path).map(|_| ());
+        assert_eq!(
+            result,
+            Err(target.clone()),
+            "{}.{} | path={}",
+            dbgid,
+            callee,
+            path
+        );
+        let result = Cache::new(&dbgid, path).try_get(&[Some(0.0)]);
+        assert_eq!(result, Err(target), "{}.{} | path={}", dbgid, callee, path);
+    }
+    let path = "src/tests/common/cache/assets/table-missed";
+    match Cache::try_new(&dbgid, path).map(|_| ()) {
+        Err(CacheError::IoError { kind, .. }) => assert_eq!(kind, std::io::ErrorKind::NotFound),
+        result => panic!("{}.{} | path={} result={:?}", dbgid, callee, path, result),
+    }
+    let cache = Cache::try_new(&dbgid, "src/tests/common/cache/assets/table-ok").unwrap();
+    #[rustfmt::skip]
+    let test_data = [
+        (vec![Some(f64::NAN)], Err(CacheError::NonComparableQuery { column: 0 })),
+        (vec![None, None, Some(f64::NAN)], Err(CacheError::NonComparableQuery { column: 2 })),
+        (vec![None; 5], Err(CacheError::TooManyValues { given: 5, columns: 4 })),
+        (vec![Some(6.0)], Err(CacheError::OutOfRange { column: 0, value: 6.0, min: 0.0, max: 5.4 })),
+        (vec![Some(0.0), Some(0.0), Some(0.0), Some(10.0)], Ok(vec![vec![0.0, 0.0, 0.0, 10.0]])),
+    ];
+    for (step, (vals, target)) in test_data.into_iter().enumerate() {
+        let result = cache.try_get(&vals);
+        assert_eq!(
+            result, target,
+            "{}.{} | step={} vals={:?} result={:?} target={:?}",
+            dbgid, callee, step, vals, result, target
+        );
+    }
+    // the non-panicking errors of `get` return `None`
+    for vals in [
+        vec![None; 5],
+        vec![Some(6.0)],
+        vec![Some(-1.0), None, Some(0.0)],
+    ] {
+        let result = cache.get(&vals);
+        assert_eq!(
+            result, None,
+            "{}.{} | vals={:?} result={:?}",
+            dbgid, callee, vals, result
+        );
+    }
+    test_duration.exit();
+}
+///
+/// Test [Cache] reading the file with header.
+#[test]
+fn header() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let callee = "header";
+    let dbgid = DbgId("test Cache".to_string());
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let path = "src/tests/common/cache/assets/table-header";
+    let cache = Cache::try_new(&dbgid, path).unwrap();
+    let header = cache.header().unwrap().cloned();
+    assert_eq!(
+        header.map(|header| header.columns),
+        Some(vec!["a".into(), "b".into(), "c".into(), "d".into()]),
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    #[rustfmt::skip]
+    let test_data = [
+        (vec![("a", 2.1), ("d", 20.1)], Ok(vec![vec![2.1, 0.1, 0.1, 20.1]])),
+        (vec![("d", 20.1), ("a", 2.1)], Ok(vec![vec![2.1, 0.1, 0.1, 20.1]])),
+        (vec![("e", 2.1)], Err(CacheError::UnknownColumn { name: "e".into() })),
+    ];
+    for (step, (vals, target)) in test_data.into_iter().enumerate() {
+        let result = cache.try_get_named(&vals);
+        assert_eq!(
+            result, target,
+            "{}.{} | step={} vals={:?}",
+            dbgid, callee, step, vals
+        );
+    }
+    // the file without header doesn't provide names
+    let cache = Cache::try_new(&dbgid, "src/tests/common/cache/assets/table-ok").unwrap();
+    assert_eq!(
+        cache.try_get_named(&[("a", 2.1)]),
+        Err(CacheError::UnknownColumn { name: "a".into() }),
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    // expectations
+    let expected = |columns: &[&str]| Header {
+        columns: Vec::from_iter(columns.iter().map(|s| s.to_string())),
+        ..Header::default()
+    };
+    let result = Cache::new(&dbgid, path)
+        .with_expected_header(expected(&["a", "b", "c", "d"]))
+        .try_get(&[Some(2.1)]);
+    assert!(result.is_ok(), "{}.{} | result={:?}", dbgid, callee, result);
+    let result = Cache::new(&dbgid, path)
+        .with_expected_header(expected(&["b", "a", "c", "d"]))
+        .try_get(&[Some(2.1)]);
+    assert!(
+        matches!(result, Err(CacheError::Mismatch { .. })),
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    let result = Cache::new(&dbgid, "src/tests/common/cache/assets/table-ok")
+        .with_expected_header(expected(&["a", "b", "c", "d"]))
+        .try_get(&[Some(2.1)]);
+    assert!(
+        matches!(result, Err(CacheError::Header { line: 1, .. })),
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    test_duration.exit();
+}
+///
+/// Test converting [Cache] file between text and binary formats.
+#[test]
+fn binary() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let callee = "binary";
+    let dbgid = DbgId("test Cache".to_string());
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let text_path = "src/tests/common/cache/assets/table-header";
+    let tmp_dir = std::env::temp_dir();
+    let binary_path = tmp_dir.join(format!("mdmt-cache-binary-{}", std::process::id()));
+    let text_result_path = tmp_dir.join(format!("mdmt-cache-text-{}", std::process::id()));
+    let truncated_path = tmp_dir.join(format!("mdmt-cache-truncated-{}", std::process::id()));
+    let nan_path = tmp_dir.join(format!("mdmt-cache-nan-{}", std::process::id()));
+    let text = Cache::try_new(&dbgid, text_path).unwrap();
+    text.write_binary(&binary_path).unwrap();
+    let binary = Cache::try_new(&dbgid, &binary_path).unwrap();
+    binary.write_text(&text_result_path).unwrap();
+    let text_result = Cache::try_new(&dbgid, &text_result_path).unwrap();
+    assert_eq!(
+        text.header(),
+        binary.header(),
+        "{}.{} | binary header",
+        dbgid,
+        callee
+    );
+    assert_eq!(
+        text.header(),
+        text_result.header(),
+        "{}.{} | text header",
+        dbgid,
+        callee
+    );
+    #[rustfmt::skip]
+    let test_data = [
+        vec![Some(2.1)],
+        vec![Some(2.5)],
+        vec![None, Some(0.5)],
+        vec![None, None, Some(0.3), Some(30.0)],
+        vec![Some(0.0), Some(0.0), Some(0.0), Some(10.0)],
+        vec![Some(6.0)],
+    ];
+    for (step, vals) in test_data.into_iter().enumerate() {
+        let target = text.try_get(&vals);
+        for (format, cache) in [("binary", &binary), ("text", &text_result)] {
+            let result = cache.try_get(&vals);
+            assert_eq!(
+                result, target,
+                "{}.{} | step={} format={} vals={:?}",
+                dbgid, callee, step, format, vals
+            );
+        }
+    }
+    // truncated file
+    let bytes = std::fs::read(&binary_path).unwrap();
+    std::fs::write(&truncated_path, &bytes[..bytes.len() - 8]).unwrap();
+    let result = Cache::try_new(&dbgid, &truncated_path).map(|_| ());
+    assert!(
+        matches!(result, Err(CacheError::Corrupted { .. })),
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    // non-comparable value in the first row of the first column,
+    // the file written by `write_binary` is checked, so it's not scanned for such values
+    let mut bytes = bytes;
+    let header_len = u64::from_le_bytes(bytes[24..32].try_into().unwrap()) as usize;
+    let data_offset = 32 + header_len.div_ceil(8) * 8 + 32;
+    bytes[data_offset..data_offset + 8].copy_from_slice(&f64::NAN.to_le_bytes());
+    std::fs::write(&nan_path, &bytes).unwrap();
+    let result = Cache::try_new(&dbgid, &nan_path).map(|_| ());
+    assert_eq!(result, Ok(()), "{}.{} | checked", dbgid, callee);
+    bytes[16..24].copy_from_slice(&0u64.to_le_bytes());
+    std::fs::write(&nan_path, &bytes).unwrap();
+    let result = Cache::try_new(&dbgid, &nan_path).map(|_| ());
+    assert_eq!(
+        result,
+        Err(CacheError::NonComparable { column: 0, row: 0 }),
+        "{}.{} | not checked",
+        dbgid,
+        callee
+    );
+    // the first inflection ID of the first column out of the dataset
+    bytes[data_offset..data_offset + 8].copy_from_slice(&0.0f64.to_le_bytes());
+    let rows = u64::from_le_bytes(
+        bytes[data_offset - 24..data_offset - 16]
+            .try_into()
+            .unwrap(),
+    );
+    let ids_offset = data_offset + rows as usize * 8;
+    bytes[ids_offset..ids_offset + 8].copy_from_slice(&rows.to_le_bytes());
+    std::fs::write(&nan_path, &bytes).unwrap();
+    let result = Cache::try_new(&dbgid, &nan_path).map(|_| ());
+    assert_eq!(
+        result,
+        Err(CacheError::Corrupted {
+            offset: ids_offset,
+            message: "Row ID out of the dataset".to_owned()
+        }),
+        "{}.{} | not checked",
+        dbgid,
+        callee
+    );
+    for path in [binary_path, text_result_path, truncated_path, nan_path] {
+        if let Err(err) = std::fs::remove_file(&path) {
+            log::warn!(
+                "{}.{} | Failed removing file='{}': {}",
+                dbgid,
+                callee,
+                path.display(),
+                err
+            );
+        }
+    }
+    test_duration.exit();
+}
@@ -0,0 +1,180 @@
+use crate::models::{
+    loading_condition::{DeadweightItem, LoadingCondition, TankLoad, Weight},
+    ship_model::local_cache::tank_cache::Gauge,
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Returns the loading condition used in the tests.
+fn loading_condition() -> LoadingCondition {
+    LoadingCondition::new("Full load departure", Weight::new(1000.0, [50.0, 0.0, 6.0]))
+        .with_item(
+            DeadweightItem::new("Fuel oil tank 1P", Weight::new(200.0, [40.0, -2.0, 2.0]))
+                .with_free_surface(120.0)
+                .with_extent(35.0, 45.0),
+        )
+        .with_item(DeadweightItem::new(
+            "Stores",
+            Weight::new(50.0, [70.0, 4.0, 8.0]),
+        ))
+        .with_tank(TankLoad::new("/tanks/do 2s", Gauge::Ullage(3.5), 0.85))
+}
+///
+/// Test summation of the weights.
+#[test]
+fn summation() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test LoadingCondition".to_string());
+    let callee = "summation";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    // the tanks aren't resolved, so they aren't summed
+    let loading_condition = loading_condition();
+    assert_eq!(
+        loading_condition.deadweight(),
+        250.0,
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    assert_eq!(
+        loading_condition.displacement(),
+        1250.0,
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    let center_of_gravity = loading_condition.center_of_gravity().unwrap();
+    let target = [
+        (50000.0 + 8000.0 + 3500.0) / 1250.0,
+        (-400.0 + 200.0) / 1250.0,
+        (6000.0 + 400.0 + 400.0) / 1250.0,
+    ];
+    for i in 0..3 {
+        assert!(
+            (center_of_gravity[i] - target[i]).abs() < 1e-12,
+            "{}.{} | result={:?}, target={:?}",
+            dbgid,
+            callee,
+            center_of_gravity,
+            target
+        );
+    }
+    assert_eq!(
+        loading_condition.free_surface_moment(),
+        120.0,
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    assert_eq!(
+        loading_condition.free_surface_correction(),
+        120.0 / 1250.0,
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    // no weight, no center
+    let empty = LoadingCondition::new("Empty", Weight::default());
+    assert_eq!(empty.center_of_gravity(), None, "{}.{}", dbgid, callee);
+    assert_eq!(empty.free_surface_correction(), 0.0, "{}.{}", dbgid, callee);
+    test_duration.exit();
+}
+///
+/// Test writing the loading condition to the file and reading it back.
+#[test]
+fn file() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test LoadingCondition".to_string());
+    let callee = "file";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    let path = std::env::temp_dir().join(format!("mdmt-loading-{}", std::process::id()));
+    let target = loading_condition();
+    target
+        .write(&path)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    let result = LoadingCondition::read(&path);
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(result, Ok(target), "{}.{}", dbgid, callee);
+    // hand written file
+    let text = "# mdmt-loading v1\n\
+                # name: Ballast arrival\n\
+                \n\
+                item 300 45 0 1.5 80 40 50 Water ballast tank 2\n\
+                item 15 70 0 8 - - - Stores\n\
+                lightship 1000 50 0 6\n\
+                tank sounding 1.2 1.025 /tanks/wb_3p\n";
+    let result: LoadingCondition = text
+        .parse()
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    assert_eq!(
+        result,
+        LoadingCondition::new("Ballast arrival", Weight::new(1000.0, [50.0, 0.0, 6.0]))
+            .with_item(
+                DeadweightItem::new("Water ballast tank 2", Weight::new(300.0, [45.0, 0.0, 1.5]))
+                    .with_free_surface(80.0)
+                    .with_extent(40.0, 50.0)
+            )
+            .with_item(DeadweightItem::new(
+                "Stores",
+                Weight::new(15.0, [70.0, 0.0, 8.0])
+            ))
+            .with_tank(TankLoad::new("/tanks/wb_3p", Gauge::Sounding(1.2), 1.025)),
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    // invalid files
+    for text in [
+        "",
+        "# mdmt-loading v2\nlightship 1000 50 0 6\n",
+        "# mdmt-loading v1\n",
+        "# mdmt-loading v1\nlightship 1000 50 0 6\nlightship 1000 50 0 6\n",
+        "# mdmt-loading v1\nlightship -1000 50 0 6\n",
+        "# mdmt-loading v1\nlightship 1000 50 0\n",
+        "# mdmt-loading v1\nlightship 1000 50 0 6\nitem 10 1 2 NaN - - - Stores\n",
+        "# mdmt-loading v1\nlightship 1000 50 0 6\nitem 10 1 2 3 - Stores\n",
+        "# mdmt-loading v1\nlightship 1000 50 0 6\nitem 10 1 2 3 - 5 - Stores\n",
+        "# mdmt-loading v1\nlightship 1000 50 0 6\nitem 10 1 2 3 - 5 0 Stores\n",
+        "# mdmt-loading v1\nlightship 1000 50 0 6\nitem 10 1 2 3\n",
+        "# mdmt-loading v1\nlightship 1000 50 0 6\ncargo 10 1 2 3 -\n",
+        "# mdmt-loading v1\nlightship 1000 50 0 6\ntank level 1.2 1.0 /tanks/wb_3p\n",
+        "# mdmt-loading v1\nlightship 1000 50 0 6\ntank sounding 1.2 0 /tanks/wb_3p\n",
+        "# mdmt-loading v1\nlightship 1000 50 0 6\ntank sounding 1.2 1.0\n",
+    ] {
+        let result = text.parse::<LoadingCondition>();
+        assert!(
+            result.is_err(),
+            "{}.{} | text='{}', result={:?}",
+            dbgid,
+            callee,
+            text,
+            result
+        );
+    }
+    test_duration.exit();
+}
@@ -0,0 +1,180 @@
+use crate::models::seakeeping::{
+    irregular_wave::IrregularWave,
+    spectrum::{Spectrum, TabulatedSpectrum},
+    wave_statistics::WaveStatistics,
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{f64::consts::PI, sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test splitting the constant spectrum into the components.
+#[test]
+fn new() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test IrregularWave".to_string());
+    let callee = "new";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let spectrum = |_| 0.5;
+    let result = IrregularWave::new(&spectrum, [0.5, 1.5], 50, 7)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    let components = result.components();
+    // the density is constant, so the total area is known, and the frequencies are sorted
+    let area: f64 = components
+        .iter()
+        .map(|component| component.amplitude.powi(2) / 2.0)
+        .sum();
+    assert!(
+        components.len() == 50 && (area - 0.5).abs() < 1e-12,
+        "{}.{} | area={} components={:?}",
+        dbgid,
+        callee,
+        area,
+        components
+    );
+    for pair in components.windows(2) {
+        assert!(
+            (0.5..=1.5).contains(&pair[0].frequency)
+                && pair[0].frequency <= pair[1].frequency
+                && (0.0..2.0 * PI).contains(&pair[0].phase),
+            "{}.{} | components={:?}",
+            dbgid,
+            callee,
+            pair
+        );
+    }
+    // the same seed gives the same wave
+    let same = IrregularWave::new(&spectrum, [0.5, 1.5], 50, 7).unwrap();
+    let other = IrregularWave::new(&spectrum, [0.5, 1.5], 50, 8).unwrap();
+    assert!(
+        result == same && result != other,
+        "{}.{} | result={:?} other={:?}",
+        dbgid,
+        callee,
+        result,
+        other
+    );
+    assert_eq!(
+        result.elevation(12.5),
+        components
+            .iter()
+            .map(|c| c.amplitude * (c.frequency * 12.5 + c.phase).cos())
+            .sum::<f64>(),
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    // invalid arguments
+    for result in [
+        IrregularWave::new(&spectrum, [0.5, 1.5], 0, 7),
+        IrregularWave::new(&spectrum, [1.5, 0.5], 10, 7),
+        IrregularWave::new(&spectrum, [0.5, f64::INFINITY], 10, 7),
+        IrregularWave::new(&|_| 0.0, [0.5, 1.5], 10, 7),
+    ] {
+        assert!(
+            result.is_err(),
+            "{}.{} | result={:?}",
+            dbgid,
+            callee,
+            result
+        );
+    }
+    test_duration.exit();
+}
+///
+/// Test the statistics of the time series synthesised from the tabulated spectrum:
+/// the variance of the elevation is the area under the spectrum.
+#[test]
+fn series() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test IrregularWave".to_string());
+    let callee = "series";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    // the triangle of area 1.2
+    let spectrum = TabulatedSpectrum::new([(0.3, 0.0), (0.8, 2.0), (1.5, 0.0)])
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    assert!(
+        (spectrum.density(0.55) - 1.0).abs() < 1e-12
+            && spectrum.density(0.2) == 0.0
+            && spectrum.density(1.6) == 0.0,
+        "{}.{} | spectrum={:?}",
+        dbgid,
+        callee,
+        spectrum
+    );
+    let wave = IrregularWave::new(&spectrum, spectrum.range(), 200, 42)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    let area: f64 = wave
+        .components()
+        .iter()
+        .map(|component| component.amplitude.powi(2) / 2.0)
+        .sum();
+    assert!(
+        (area - 1.2).abs() < 1e-3,
+        "{}.{} | area={}",
+        dbgid,
+        callee,
+        area
+    );
+    let samples = wave.series(0.0, 0.25, 40000);
+    assert!(
+        samples.len() == 40000 && samples[4] == (1.0, wave.elevation(1.0)),
+        "{}.{} | samples={:?}",
+        dbgid,
+        callee,
+        &samples[..5]
+    );
+    let statistics = WaveStatistics::new(&samples)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    let target = 4.0 * area.sqrt();
+    assert!(
+        (statistics.h_m0 - target).abs() < 0.05 * target,
+        "{}.{} | h_m0={} target={}\n{}",
+        dbgid,
+        callee,
+        statistics.h_m0,
+        target,
+        statistics
+    );
+    // invalid tables
+    for points in [
+        vec![(0.3, 0.0)],
+        vec![(0.3, 0.0), (0.3, 1.0)],
+        vec![(0.3, 0.0), (0.8, -1.0)],
+        vec![(0.3, 0.0), (f64::NAN, 1.0)],
+    ] {
+        let result = TabulatedSpectrum::new(points.clone());
+        assert!(
+            result.is_err(),
+            "{}.{} | points={:?} result={:?}",
+            dbgid,
+            callee,
+            points,
+            result
+        );
+    }
+    test_duration.exit();
+}
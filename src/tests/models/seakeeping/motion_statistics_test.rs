@@ -0,0 +1,104 @@
+use crate::models::seakeeping::motion_statistics::MotionStatistics;
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test the statistics of the hand-computed roll series with the list.
+#[test]
+fn new() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test MotionStatistics".to_string());
+    let callee = "new";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let samples = [
+        (0.0, 3.0),
+        (2.0, 8.0),
+        (4.0, -1.0),
+        (5.0, 5.0),
+        (8.0, 0.0),
+        (10.0, 6.0),
+        (12.0, 1.0),
+        (14.0, 3.0),
+    ];
+    let result = MotionStatistics::new(&samples)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    assert!(
+        result.count() == 5
+            && result.amplitudes() == [4.5, 3.0, 2.5, 3.0, 2.5]
+            && result.half_periods() == [2.0, 1.0, 3.0, 2.0, 2.0],
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    // the mean value is 3.125, the up-crossings of it are at 0.05, 4 + 4.125 / 6 and 8 + 3.125 / 3
+    let variance: f64 = 145.0 / 8.0 - 3.125 * 3.125;
+    let targets = [
+        ("y_min", result.y_min, -1.0),
+        ("y_max", result.y_max, 8.0),
+        ("y_av", result.y_av, 3.125),
+        ("a_max", result.a_max, 4.5),
+        ("a_av", result.a_av, 3.1),
+        ("a_rms", result.a_rms, 10.15_f64.sqrt()),
+        ("a_1_3", result.a_1_3, 4.5),
+        ("a_m0", result.a_m0, 4.0 * variance.sqrt()),
+        ("a_s", result.a_s, 4.5),
+        ("t_mean", result.t_mean, 4.0),
+        ("t_s", result.t_s, 4.0),
+        ("t_z", result.t_z, (8.0 + 3.125 / 3.0 - 0.05) / 2.0),
+    ];
+    for (name, result, target) in targets {
+        assert!(
+            (result - target).abs() < 1e-12,
+            "{}.{} | {}: result={} target={}",
+            dbgid,
+            callee,
+            name,
+            result,
+            target
+        );
+    }
+    // the sorted amplitudes are 2.5, 2.5, 3, 3, 4.5
+    for (n, target) in [(0.0, 4.5), (10.0, 3.9), (50.0, 3.0), (100.0, 2.5)] {
+        let result = result.amplitude_of_probability(n);
+        assert!(
+            result.is_some_and(|result| (result - target).abs() < 1e-12),
+            "{}.{} | n={} result={:?} target={}",
+            dbgid,
+            callee,
+            n,
+            result,
+            target
+        );
+    }
+    // the motion without the swings
+    let result = MotionStatistics::new(&[(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]);
+    assert!(
+        result.is_err(),
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    test_duration.exit();
+}
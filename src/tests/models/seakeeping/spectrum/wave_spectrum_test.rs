@@ -0,0 +1,146 @@
+use crate::models::seakeeping::{
+    irregular_wave::IrregularWave,
+    spectrum::{wave_spectrum::WaveSpectrum, SpectralMoments, Spectrum},
+    wave_statistics::WaveStatistics,
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{f64::consts::PI, sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test the spectral moments and the derived parameters against the parameters of the spectra.
+#[test]
+fn moments() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test WaveSpectrum".to_string());
+    let callee = "moments";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    // (spectrum, Hm0, Tz, Tm01), the periods of the Pierson–Moskowitz shape are
+    // Tz = Tp / 1.408 and Tm01 = Tp / 1.296
+    let targets = [
+        (
+            WaveSpectrum::pierson_moskowitz(4.0, 10.0),
+            4.0,
+            Some(10.0 / 1.408),
+            Some(10.0 / 1.296),
+        ),
+        (
+            WaveSpectrum::pierson_moskowitz_tz(4.0, 7.1),
+            4.0,
+            Some(7.1),
+            None,
+        ),
+        (WaveSpectrum::bretschneider(3.0, 9.0), 3.0, None, None),
+        (WaveSpectrum::ittc(5.0, 8.0), 5.0, None, Some(8.0)),
+        (WaveSpectrum::jonswap(4.0, 10.0, 3.3), 4.0, None, None),
+    ];
+    for (spectrum, hm0, tz, tm01) in targets {
+        let moments = SpectralMoments::new(&spectrum, spectrum.range());
+        let results = [
+            ("hm0", Some(moments.hm0()), Some(hm0)),
+            ("tz", tz.map(|_| moments.tz()), tz),
+            ("tm01", tm01.map(|_| moments.tm01()), tm01),
+        ];
+        for (name, result, target) in results {
+            if let (Some(result), Some(target)) = (result, target) {
+                assert!(
+                    (result - target).abs() < 0.01 * target,
+                    "{}.{} | spectrum={:?} {}: result={} target={}",
+                    dbgid,
+                    callee,
+                    spectrum,
+                    name,
+                    result,
+                    target
+                );
+            }
+        }
+        // Tz <= Tm01 since m1² <= m0 * m2, both are shorter than the peak period
+        assert!(
+            moments.tz() < moments.tm01() && moments.tm01() < 2.0 * PI / spectrum.peak_frequency(),
+            "{}.{} | spectrum={:?} moments={:?}",
+            dbgid,
+            callee,
+            spectrum,
+            moments
+        );
+    }
+    // the peak of JONSWAP is the peak of Pierson–Moskowitz enhanced by γ
+    let jonswap = WaveSpectrum::jonswap(4.0, 10.0, 3.3);
+    let pierson_moskowitz = WaveSpectrum::pierson_moskowitz(4.0, 10.0);
+    let peak_frequency = jonswap.peak_frequency();
+    let result = jonswap.density(peak_frequency);
+    let target = (1.0 - 0.287 * 3.3_f64.ln()) * 3.3 * pierson_moskowitz.density(peak_frequency);
+    assert!(
+        (peak_frequency - 2.0 * PI / 10.0).abs() < 1e-12 && (result - target).abs() < 1e-12,
+        "{}.{} | peak_frequency={} result={} target={}",
+        dbgid,
+        callee,
+        peak_frequency,
+        result,
+        target
+    );
+    for spectrum in [pierson_moskowitz, jonswap, WaveSpectrum::ittc(5.0, 8.0)] {
+        let peak_frequency = spectrum.peak_frequency();
+        let peak = spectrum.density(peak_frequency);
+        assert!(
+            peak > spectrum.density(0.98 * peak_frequency)
+                && peak > spectrum.density(1.02 * peak_frequency)
+                && spectrum.density(0.0) == 0.0,
+            "{}.{} | spectrum={:?}",
+            dbgid,
+            callee,
+            spectrum
+        );
+    }
+    test_duration.exit();
+}
+///
+/// Test Hm0 of the time series synthesised from JONSWAP spectrum against 4 * √m0.
+#[test]
+fn synthesis() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test WaveSpectrum".to_string());
+    let callee = "synthesis";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    let spectrum = WaveSpectrum::jonswap(2.5, 8.0, 3.3);
+    let moments = SpectralMoments::new(&spectrum, spectrum.range());
+    let wave = IrregularWave::new(&spectrum, spectrum.range(), 300, 2024)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    let statistics = WaveStatistics::new(&wave.series(0.0, 0.25, 40000))
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    let target = moments.hm0();
+    assert!(
+        (statistics.h_m0 - target).abs() < 0.05 * target,
+        "{}.{} | h_m0={} target={}\n{}",
+        dbgid,
+        callee,
+        statistics.h_m0,
+        target,
+        statistics
+    );
+    test_duration.exit();
+}
@@ -0,0 +1,164 @@
+use crate::models::seakeeping::{
+    irregular_wave::IrregularWave,
+    spectrum::{
+        wave_spectrum::WaveSpectrum,
+        welch::{Detrend, Welch, Window},
+        SpectralMoments,
+    },
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{f64::consts::PI, sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test the spectrum of the harmonic, which period is the whole number of the segment bins:
+/// the spectrum area is the variance of the harmonic and its peak is at the harmonic period.
+#[test]
+fn harmonic() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Welch".to_string());
+    let callee = "harmonic";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    // 16 periods per the segment of 256 samples
+    let (amplitude, period, step) = (2.0, 8.0, 0.5);
+    let samples = Vec::from_iter((0..2048).map(|id| {
+        let time = 100.0 + step * id as f64;
+        (
+            time,
+            3.0 + 0.01 * time + amplitude * (2.0 * PI * time / period).cos(),
+        )
+    }));
+    // (estimation, the offset and the trend are removed), the trend left distorts the spectrum
+    let estimations = [
+        (Welch::new(256).with_detrend(Detrend::Linear), true),
+        (
+            Welch::new(256)
+                .with_detrend(Detrend::Linear)
+                .with_window(Window::Hamming)
+                .with_overlap(0),
+            true,
+        ),
+        // the periodogram
+        (
+            Welch::new(2048)
+                .with_detrend(Detrend::Linear)
+                .with_window(Window::Rectangular),
+            true,
+        ),
+        (Welch::new(256), false),
+        (Welch::new(256).with_detrend(Detrend::None), false),
+    ];
+    for (welch, detrended) in estimations {
+        let result = welch
+            .estimate(&samples)
+            .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+        let target = amplitude * amplitude / 2.0;
+        let m0 = result.moments.m0;
+        assert!(
+            result.spectrum.range() == [0.0, PI / step]
+                && ((result.peak_period - period).abs() < 1e-9
+                    && (m0 - target).abs() < 0.01 * target)
+                    == detrended,
+            "{}.{} | welch={:?} peak_period={} m0={} target={}",
+            dbgid,
+            callee,
+            welch,
+            result.peak_period,
+            m0,
+            target
+        );
+    }
+    // the transform of the segment of any length
+    let result = Welch::new(300)
+        .with_detrend(Detrend::Linear)
+        .estimate(&samples)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    let target = amplitude * amplitude / 2.0;
+    assert!(
+        (result.moments.m0 - target).abs() < 0.05 * target
+            && (result.peak_period - period).abs() < 0.05 * period,
+        "{}.{} | m0={} peak_period={}",
+        dbgid,
+        callee,
+        result.moments.m0,
+        result.peak_period
+    );
+    // invalid estimations
+    let mut uneven = samples.clone();
+    uneven[100].0 += 0.1;
+    for (welch, samples) in [
+        (Welch::new(1), &samples),
+        (Welch::new(256).with_overlap(256), &samples),
+        (Welch::new(4096), &samples),
+        (Welch::new(256), &uneven),
+        (Welch::new(256), &vec![(0.0, 1.0); 512]),
+        (
+            Welch::new(256),
+            &Vec::from_iter((0..512).map(|id| (id as f64, 1.0))),
+        ),
+    ] {
+        let result = welch.estimate(samples);
+        assert!(
+            result.is_err(),
+            "{}.{} | welch={:?} result={:?}",
+            dbgid,
+            callee,
+            welch,
+            result.map(|result| result.moments)
+        );
+    }
+    test_duration.exit();
+}
+///
+/// Test the spectrum estimated from the series synthesised from JONSWAP spectrum
+/// against the theoretical one.
+#[test]
+fn jonswap() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Welch".to_string());
+    let callee = "jonswap";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    let spectrum = WaveSpectrum::jonswap(2.5, 8.0, 3.3);
+    let target = SpectralMoments::new(&spectrum, spectrum.range());
+    let samples = IrregularWave::new(&spectrum, spectrum.range(), 300, 2024)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err))
+        .series(0.0, 0.25, 40000);
+    let result = Welch::new(1024)
+        .estimate(&samples)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    assert!(
+        (result.moments.hm0() - target.hm0()).abs() < 0.05 * target.hm0()
+            && (result.moments.tm01() - target.tm01()).abs() < 0.05 * target.tm01()
+            && (result.peak_period - 8.0).abs() < 0.1 * 8.0,
+        "{}.{} | result={:?} peak_period={} target={:?}",
+        dbgid,
+        callee,
+        result.moments,
+        result.peak_period,
+        target
+    );
+    test_duration.exit();
+}
@@ -0,0 +1,55 @@
+use crate::models::seakeeping::time_series;
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test detecting the extrema including the flat ones.
+#[test]
+fn extrema() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test time_series".to_string());
+    let callee = "extrema";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let samples = [
+        (0.0, 0.0),
+        (1.0, 1.0),
+        (2.0, 1.0),
+        (3.0, 1.0),
+        (4.0, 0.5),
+        (5.0, 0.5),
+        (6.0, -1.0),
+        (7.0, 0.0),
+        (8.0, 0.0),
+    ];
+    let result = time_series::extrema(&samples);
+    let target = [(2.0, 1.0), (6.0, -1.0)];
+    assert_eq!(result, target, "{}.{}", dbgid, callee);
+    // no extrema in the monotonic series
+    assert!(
+        time_series::extrema(&[(0.0, 0.0), (1.0, 1.0), (2.0, 1.0), (3.0, 2.0)]).is_empty(),
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    test_duration.exit();
+}
@@ -0,0 +1,164 @@
+use crate::models::seakeeping::wave_statistics::WaveStatistics;
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{f64::consts::PI, sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test the statistics of the hand-computed series.
+#[test]
+fn new() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test WaveStatistics".to_string());
+    let callee = "new";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let samples = [
+        (0.0, 0.0),
+        (1.0, 1.0),
+        (3.0, -1.0),
+        (4.0, 2.0),
+        (7.0, -2.0),
+        (8.0, 1.0),
+        (9.0, 0.0),
+    ];
+    let result = WaveStatistics::new(&samples)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    assert!(
+        result.count() == 4
+            && result.heights() == [2.0, 3.0, 4.0, 3.0]
+            && result.half_periods() == [2.0, 1.0, 3.0, 1.0],
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    // the mean elevation is 1/7, the up-crossings of it are at 1/7, 3 + 8/21 and 7 + 5/7
+    let variance: f64 = 11.0 / 7.0 - 1.0 / 49.0;
+    let targets = [
+        ("h_max", result.h_max, 4.0),
+        ("h_av", result.h_av, 3.0),
+        ("h_rms", result.h_rms, 9.5_f64.sqrt()),
+        ("h_1_3", result.h_1_3, 4.0),
+        ("h_m0", result.h_m0, 4.0 * variance.sqrt()),
+        ("h_s", result.h_s, 4.0),
+        ("t_mean", result.t_mean, 3.5),
+        ("t_s", result.t_s, 6.0),
+        ("t_z", result.t_z, (7.0 + 5.0 / 7.0 - 1.0 / 7.0) / 2.0),
+    ];
+    for (name, result, target) in targets {
+        assert!(
+            (result - target).abs() < 1e-12,
+            "{}.{} | {}: result={} target={}",
+            dbgid,
+            callee,
+            name,
+            result,
+            target
+        );
+    }
+    // the sorted heights are 2, 3, 3, 4
+    for (n, target) in [
+        (0.0, Some(4.0)),
+        (10.0, Some(3.7)),
+        (50.0, Some(3.0)),
+        (100.0, Some(2.0)),
+    ] {
+        let result = result.height_of_probability(n);
+        assert!(
+            result.is_some_and(|result| (result - target.unwrap()).abs() < 1e-12),
+            "{}.{} | n={} result={:?} target={:?}",
+            dbgid,
+            callee,
+            n,
+            result,
+            target
+        );
+    }
+    assert_eq!(
+        result.height_of_probability(101.0),
+        None,
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    test_duration.exit();
+}
+///
+/// Test the statistics of the regular wave, which are known analytically.
+#[test]
+fn regular() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test WaveStatistics".to_string());
+    let callee = "regular";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let (amplitude, period) = (1.5, 8.0);
+    let samples = Vec::from_iter((0..200).map(|step| {
+        let time = step as f64 * period / 20.0;
+        (time, amplitude * (2.0 * PI * time / period).sin())
+    }));
+    let result = WaveStatistics::new(&samples)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    let targets = [
+        ("h_max", result.h_max, 2.0 * amplitude),
+        ("h_av", result.h_av, 2.0 * amplitude),
+        ("h_rms", result.h_rms, 2.0 * amplitude),
+        ("h_s", result.h_s, 2.0 * amplitude),
+        ("h_m0", result.h_m0, 2.0 * 2.0_f64.sqrt() * amplitude),
+        ("t_mean", result.t_mean, period),
+        ("t_s", result.t_s, period),
+        ("t_z", result.t_z, period),
+    ];
+    for (name, result, target) in targets {
+        assert!(
+            (result - target).abs() < 1e-9,
+            "{}.{} | {}: result={} target={}",
+            dbgid,
+            callee,
+            name,
+            result,
+            target
+        );
+    }
+    assert_eq!(result.count(), 19, "{}.{}", dbgid, callee);
+    // invalid series
+    for samples in [
+        vec![],
+        vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)],
+        vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)],
+        vec![(0.0, 0.0), (2.0, 1.0), (1.0, -1.0), (3.0, 1.0), (4.0, -1.0)],
+        vec![(0.0, 0.0), (1.0, f64::NAN), (2.0, -1.0)],
+    ] {
+        let result = WaveStatistics::new(&samples);
+        assert!(
+            result.is_err(),
+            "{}.{} | samples={:?} result={:?}",
+            dbgid,
+            callee,
+            samples,
+            result
+        );
+    }
+    test_duration.exit();
+}
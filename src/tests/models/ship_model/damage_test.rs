@@ -0,0 +1,189 @@
+use crate::models::ship_model::{
+    damage::{self, LostBuoyancy},
+    equilibrium::equilibrium_conf::EquilibriumConf,
+    waterline::{self, Submerged},
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::{dbg_id::DbgId, error::str_err::StrErr};
+use std::{sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Returns the part of the box `x0`..`x1` × 0..10 × 0..10 under the waterline,
+/// which initial position is at (5, 5, 5).
+///
+/// It's valid while the waterline crosses only the box sides.
+fn submerged(x0: f64, x1: f64, [heel, trim, draught]: [f64; 3]) -> Submerged {
+    let [.., normal] = waterline::axes(heel, trim);
+    // the waterline relative to (5, 5, 5): z = a * u + b * v + c, where u = x - 5, v = y - 5
+    let (a, b, c) = (
+        -normal[0] / normal[2],
+        -normal[1] / normal[2],
+        5.0 - draught,
+    );
+    let (u0, u1) = (x0 - 5.0, x1 - 5.0);
+    // integrals of 1, u, u², and v² over the box base
+    let s = 10.0 * (u1 - u0);
+    let su = 10.0 * (u1 * u1 - u0 * u0) / 2.0;
+    let suu = 10.0 * (u1.powi(3) - u0.powi(3)) / 3.0;
+    let svv = (u1 - u0) * 1e3 / 12.0;
+    let volume = c * s + a * su;
+    Submerged {
+        volume,
+        moment: [
+            5.0 * volume + c * su + a * suu,
+            5.0 * volume + b * svv,
+            (c * c * s + 2.0 * c * a * su + a * a * suu + b * b * svv) / 2.0,
+        ],
+    }
+}
+///
+/// Test taking the flooded volume out of the intact one.
+#[test]
+fn lost_buoyancy() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test LostBuoyancy".to_string());
+    let callee = "lost_buoyancy";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    let intact = Submerged {
+        volume: 100.0,
+        moment: [500.0, 500.0, 250.0],
+    };
+    let flooded = [
+        (
+            Submerged {
+                volume: 10.0,
+                moment: [10.0, 50.0, 25.0],
+            },
+            0.5,
+        ),
+        (
+            Submerged {
+                volume: 20.0,
+                moment: [180.0, 100.0, 50.0],
+            },
+            1.0,
+        ),
+    ];
+    let result = damage::lost_buoyancy(intact, flooded);
+    let target = Submerged {
+        volume: 75.0,
+        moment: [315.0, 375.0, 187.5],
+    };
+    assert_eq!(result, target, "{}.{}", dbgid, callee);
+    assert_eq!(
+        damage::lost_buoyancy(intact, []),
+        intact,
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    test_duration.exit();
+}
+///
+/// Test the floating position and the residual GZ curve of the box
+/// with the flooded compartment amidships, which are known analytically.
+#[test]
+fn solve() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test LostBuoyancy".to_string());
+    let callee = "solve";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    let conf = EquilibriumConf::default();
+    let center_of_gravity = [5.0, 5.0, 3.0];
+    let heel_steps = [0.0, 5.0, 10.0, 20.0];
+    // the compartment 4..6 is flooded, so the intact 400 m3 box sinks by 1 m
+    let damaged = |position| -> Result<Submerged, StrErr> {
+        Ok(damage::lost_buoyancy(
+            submerged(0.0, 10.0, position),
+            [(submerged(4.0, 6.0, position), 1.0)],
+        ))
+    };
+    let result = LostBuoyancy::new(&dbgid, conf.clone(), 400.0, center_of_gravity)
+        .solve("amidships", [0.0, 0.0, 1.0], &heel_steps, damaged)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    assert!(
+        result.case == "amidships"
+            && result.position.heel.abs() < 1e-6
+            && result.position.trim.abs() < 1e-6
+            && result.position.draught.abs() < 1e-3,
+        "{}.{} | position={:?}",
+        dbgid,
+        callee,
+        result.position
+    );
+    // wall-sided formula: GZ = sin(heel) * (GM + BM * tan²(heel) / 2)
+    let bm = 8.0 * 1e3 / 12.0 / 400.0;
+    let gm = 2.5 + bm - 3.0;
+    assert_eq!(
+        result.gz_curve.points().len(),
+        heel_steps.len(),
+        "{}.{} | gz_curve={:?}",
+        dbgid,
+        callee,
+        result.gz_curve
+    );
+    for (step, &(heel, gz)) in result.gz_curve.points().iter().enumerate() {
+        let (sin, tan) = (heel.to_radians().sin(), heel.to_radians().tan());
+        let target = sin * (gm + bm * tan * tan / 2.0);
+        assert!(
+            (gz - target).abs() < 1e-3,
+            "{}.{} | step={} heel={} result={} target={}",
+            dbgid,
+            callee,
+            step,
+            heel,
+            gz,
+            target
+        );
+    }
+    // the compartment at the stern is flooded by half, so the box trims by the stern,
+    // which is the positive trim
+    let damaged = |position| -> Result<Submerged, StrErr> {
+        Ok(damage::lost_buoyancy(
+            submerged(0.0, 10.0, position),
+            [(submerged(0.0, 2.0, position), 0.5)],
+        ))
+    };
+    let result = LostBuoyancy::new(&dbgid, conf.clone(), 400.0, center_of_gravity)
+        .solve("stern", [0.0, 0.0, 1.0], &[], damaged)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    let remaining = damaged([
+        result.position.heel,
+        result.position.trim,
+        result.position.draught,
+    ])
+    .unwrap();
+    assert!(
+        result.position.trim > 0.0
+            && (remaining.volume - 400.0).abs() <= 400.0 * conf.volume_tolerance
+            && result.gz_curve.points().is_empty(),
+        "{}.{} | position={:?}",
+        dbgid,
+        callee,
+        result.position
+    );
+    test_duration.exit();
+}
@@ -0,0 +1,131 @@
+use crate::models::ship_model::{
+    equilibrium::{equilibrium_conf::EquilibriumConf, Equilibrium},
+    waterline::{self, Submerged},
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::{dbg_id::DbgId, error::str_err::StrErr};
+use std::{sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Returns the part of 10×10×10 box placed at the origin under the waterline,
+/// which initial position is the box center.
+///
+/// It's valid while the waterline crosses only the box sides.
+fn submerged([heel, trim, draught]: [f64; 3]) -> Result<Submerged, StrErr> {
+    let [.., normal] = waterline::axes(heel, trim);
+    // the waterline relative to the box center: z = a * x + b * y + c
+    let (a, b, c) = (-normal[0] / normal[2], -normal[1] / normal[2], -draught);
+    let inertia = 10.0 * 10.0_f64.powi(3) / 12.0;
+    let volume = 100.0 * (c + 5.0);
+    let center = [
+        5.0 + a * inertia / volume,
+        5.0 + b * inertia / volume,
+        (a * a * inertia + b * b * inertia + 100.0 * (c + 5.0).powi(2)) / (2.0 * volume),
+    ];
+    Ok(Submerged {
+        volume,
+        moment: center.map(|coord| coord * volume),
+    })
+}
+///
+/// Test solving the floating position of the box.
+#[test]
+fn solve() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Equilibrium".to_string());
+    let callee = "solve";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    let conf = EquilibriumConf::default();
+    // upright, the draught is 1.0 below the center
+    let position = Equilibrium::new(&dbgid, conf.clone(), 400.0, [5.0, 5.0, 3.0])
+        .solve([0.0, 0.0, 0.0], submerged)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    assert!(
+        position.heel.abs() < 1e-6 && position.trim.abs() < 1e-6,
+        "{}.{} | position={:?}",
+        dbgid,
+        callee,
+        position
+    );
+    assert!(
+        (position.draught - 1.0).abs() < 1e-3,
+        "{}.{} | position={:?}",
+        dbgid,
+        callee,
+        position
+    );
+    // the center of gravity is moved aft and to port, so is the center of buoyancy
+    let center_of_gravity = [4.8, 4.9, 3.0];
+    let position = Equilibrium::new(&dbgid, conf.clone(), 400.0, center_of_gravity)
+        .solve([0.0, 0.0, 1.0], submerged)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    assert!(
+        position.heel < 0.0 && position.trim > 0.0 && position.iterations > 0,
+        "{}.{} | position={:?}",
+        dbgid,
+        callee,
+        position
+    );
+    assert!(
+        (position.volume - 400.0).abs() <= 400.0 * conf.volume_tolerance,
+        "{}.{} | position={:?}",
+        dbgid,
+        callee,
+        position
+    );
+    let [loc_x, loc_y, _] = waterline::axes(position.heel, position.trim);
+    let lever = [0, 1, 2].map(|i| position.center_of_buoyancy[i] - center_of_gravity[i]);
+    for axis in [loc_x, loc_y] {
+        assert!(
+            waterline::dot(lever, axis).abs() <= conf.lever_tolerance,
+            "{}.{} | position={:?}",
+            dbgid,
+            callee,
+            position
+        );
+    }
+    // the heel is kept, whereas the trim is free
+    let position = Equilibrium::new(&dbgid, conf.clone(), 400.0, [5.0, 5.0, 3.0])
+        .with_fixed_heel()
+        .solve([5.0, 1.0, 0.0], submerged)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    assert!(
+        position.heel == 5.0
+            && position.trim.abs() < 1e-3
+            && (position.volume - 400.0).abs() <= 400.0 * conf.volume_tolerance,
+        "{}.{} | position={:?}",
+        dbgid,
+        callee,
+        position
+    );
+    // no submerged volume
+    let result =
+        Equilibrium::new(&dbgid, conf, 400.0, center_of_gravity).solve([0.0, 0.0, 5.0], submerged);
+    assert!(
+        result.is_err(),
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    test_duration.exit();
+}
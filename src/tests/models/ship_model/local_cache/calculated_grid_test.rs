@@ -0,0 +1,193 @@
+use crate::{
+    common::cache::header::Header,
+    models::ship_model::{
+        local_cache::{
+            calculated_grid::{CalculatedGrid, Function},
+            progress::Progress,
+        },
+        waterline::Waterline,
+    },
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::{dbg_id::DbgId, error::str_err::StrErr};
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Once,
+    },
+    time::Duration,
+};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Runs [CalculatedGrid] of `function` with `workers` storing the dataset into `file_path`,
+/// returns the errors of the workers.
+fn run(
+    dbgid: &DbgId,
+    file_path: PathBuf,
+    function: Function<()>,
+    workers: usize,
+    exit: Arc<AtomicBool>,
+) -> Vec<StrErr> {
+    let waterline = Waterline::new(
+        vec![],
+        Waterline::create_face(dbgid, [0.0, 0.0, 0.0])
+            .unwrap_or_else(|err| panic!("{} | Failed creating *waterline*: {}", dbgid, err)),
+    );
+    let header = Header::new(
+        &["heel", "trim", "draught", "value"],
+        &["deg", "deg", "m", ""],
+    );
+    let steps = [
+        Vec::from_iter((-3..=3).map(f64::from)),
+        Vec::from_iter((0..5).map(f64::from)),
+        vec![0.0, 0.5, 1.0],
+    ];
+    let handles = CalculatedGrid::new(
+        dbgid,
+        file_path,
+        waterline,
+        function,
+        header,
+        steps,
+        workers,
+        false,
+        exit,
+        Arc::new(Progress::new()),
+    )
+    .build()
+    .unwrap_or_else(|err| panic!("{} | Failed starting workers: {}", dbgid, err));
+    let mut errors = vec![];
+    for (name, handle) in handles {
+        match handle.join() {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => errors.push(err),
+            Err(why) => errors.push(StrErr(format!("Worker={} panicked: {:?}", name, why))),
+        }
+    }
+    errors
+}
+///
+/// Test the dataset doesn't depend on the number of workers.
+#[test]
+fn merge() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test CalculatedGrid".to_string());
+    let callee = "merge";
+    log::debug!("\n{}.{}", dbgid, callee);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    let function: Function<()> =
+        Arc::new(|_, [heel, trim, draught]| Ok(vec![heel * 100.0 + trim * 10.0 + draught]));
+    let tmp_dir = std::env::temp_dir();
+    let mut results = vec![];
+    for workers in [1, 4] {
+        let file_path = tmp_dir.join(format!(
+            "mdmt-calculated-grid-{}-{}",
+            workers,
+            std::process::id()
+        ));
+        let exit = Arc::new(AtomicBool::new(false));
+        let errors = run(
+            &dbgid,
+            file_path.clone(),
+            function.clone(),
+            workers,
+            exit.clone(),
+        );
+        assert!(
+            errors.is_empty() && !exit.load(Ordering::SeqCst),
+            "{}.{} | workers={} errors={:?}",
+            dbgid,
+            callee,
+            workers,
+            errors
+        );
+        let text = fs::read_to_string(&file_path)
+            .unwrap_or_else(|err| panic!("{}.{} | workers={}: {}", dbgid, callee, workers, err));
+        // the creation time may differ between the runs
+        results.push(Vec::from_iter(
+            text.lines()
+                .filter(|line| !Header::is_header_line(line))
+                .map(str::to_owned),
+        ));
+        if let Err(err) = fs::remove_file(&file_path) {
+            log::warn!(
+                "{}.{} | Failed removing file='{}': {}",
+                dbgid,
+                callee,
+                file_path.display(),
+                err
+            );
+        }
+    }
+    assert_eq!(results[0].len(), 7 * 5 * 3, "{}.{}", dbgid, callee);
+    assert_eq!(results[0], results[1], "{}.{}", dbgid, callee);
+    // the last axis is the outermost one
+    assert_eq!(
+        &results[0][..2],
+        ["-3 0 0 -300", "-3 1 0 -290"],
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    test_duration.exit();
+}
+///
+/// Test the first failed worker stops the rest ones and no dataset is stored.
+#[test]
+fn fail() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test CalculatedGrid".to_string());
+    let callee = "fail";
+    log::debug!("\n{}.{}", dbgid, callee);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    let function: Function<()> = Arc::new(|_, [heel, trim, draught]| match heel < 0.0 {
+        true => Err(StrErr(format!("Failed at heel={}", heel))),
+        false => Ok(vec![heel * 100.0 + trim * 10.0 + draught]),
+    });
+    let file_path =
+        std::env::temp_dir().join(format!("mdmt-calculated-grid-fail-{}", std::process::id()));
+    let exit = Arc::new(AtomicBool::new(false));
+    let errors = run(&dbgid, file_path.clone(), function, 2, exit.clone());
+    assert!(
+        !errors.is_empty() && exit.load(Ordering::SeqCst),
+        "{}.{} | errors={:?}",
+        dbgid,
+        callee,
+        errors
+    );
+    assert!(!file_path.exists(), "{}.{}", dbgid, callee);
+    let partial_path = PathBuf::from(format!("{}.partial", file_path.display()));
+    if let Err(err) = fs::remove_file(&partial_path) {
+        log::warn!(
+            "{}.{} | Failed removing file='{}': {}",
+            dbgid,
+            callee,
+            partial_path.display(),
+            err
+        );
+    }
+    test_duration.exit();
+}
@@ -0,0 +1,166 @@
+use crate::{
+    common::cache::error::CacheError,
+    models::ship_model::{
+        equilibrium::equilibrium_conf::EquilibriumConf,
+        local_cache::{
+            cross_curves_cache::{cross_curves_cache_conf::CrossCurvesCacheConf, CrossCurvesCache},
+            progress::Progress,
+            LocalCache,
+        },
+        model_tree::ModelTree,
+    },
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_3dlib::{props::Center, topology::shape::Shape};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{
+    fs,
+    sync::{Arc, Once},
+    time::Duration,
+};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test calculating cross curves of 10×10×10 cube, which are known analytically.
+#[test]
+fn calculate() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test CrossCurvesCache".to_string());
+    let callee = "calculate";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(300));
+    test_duration.run().unwrap();
+    let model_key = "/cube_1_1_1_centered";
+    let model_path =
+        "src/tests/models/ship_model/local_cache/floating_position_cache/assets/cube_1_1_1.step";
+    let cache_dir = std::env::temp_dir().join(format!("mdmt-ccc-calculate-{}", std::process::id()));
+    fs::create_dir_all(&cache_dir).unwrap();
+    let model_tree = ModelTree::<()>::new(&dbgid, model_path)
+        .load()
+        .unwrap_or_else(|err| panic!("Failing building *model_tree*: {}", err));
+    // set waterline init position to the cube center
+    let center @ [x, y, z] = model_tree
+        .get(model_key)
+        .and_then(|shape| match shape {
+            Shape::Solid(model) => Some(model.center().point()),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("Expected Solid by model_key='{}'", model_key));
+    let conf = CrossCurvesCacheConf {
+        waterline_position: center,
+        heel_steps: vec![0.0, 10.0, 20.0],
+        displacement_steps: vec![400.0, 500.0],
+        water_density: 1.0,
+        // the keel point under the center
+        reference_point: [x, y, z - 5.0],
+        workers: 2,
+        ..Default::default()
+    };
+    let mut cross_curves_cache = CrossCurvesCache::new(
+        &dbgid,
+        model_tree.clone(),
+        model_tree.hash(),
+        &cache_dir,
+        conf.clone(),
+    );
+    let progress = Arc::new(Progress::new());
+    let handlers = cross_curves_cache
+        .calculate(Arc::default(), progress.clone())
+        .unwrap_or_else(|err| panic!("Failed creating *handlers*: {}", err));
+    for (id, handler) in handlers {
+        match handler.join() {
+            Err(why) => panic!("Failed preparing thread='{}': {:?}", id, why),
+            Ok(res) => {
+                res.unwrap_or_else(|why| panic!("Failed executing thread='{}': {:?}", id, why))
+            }
+        }
+    }
+    assert!(progress.report().is_finished(), "{}.{}", dbgid, callee);
+    cross_curves_cache.reload();
+    assert_eq!(cross_curves_cache.check(), Ok(()), "{}.{}", dbgid, callee);
+    // the waterline crosses the sides only, so
+    // the center of buoyancy relative to the keel point is known analytically
+    let kn = |heel: f64, volume: f64| {
+        let heel = heel.to_radians();
+        let slope = heel.tan();
+        let inertia = 10.0 * 10.0_f64.powi(3) / 12.0;
+        let depth = volume / 100.0;
+        let tcb = slope * inertia / volume;
+        let vcb = (slope.powi(2) * inertia + 100.0 * depth.powi(2)) / (2.0 * volume);
+        tcb * heel.cos() + vcb * heel.sin()
+    };
+    for heel in [0.0, 10.0, 20.0] {
+        for displacement in [400.0, 500.0] {
+            let rows = cross_curves_cache
+                .get(&[Some(heel), Some(0.0), Some(displacement)])
+                .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+            let target = kn(heel, displacement);
+            match rows.as_slice() {
+                [row] => assert!(
+                    (row[3] - target).abs() < 2e-3
+                        && (row[4] - target).abs() < 2e-3
+                        && row[5].abs() < 0.1,
+                    "{}.{} | heel={} displacement={} result={:?} target={}",
+                    dbgid,
+                    callee,
+                    heel,
+                    displacement,
+                    row,
+                    target
+                ),
+                _ => panic!(
+                    "{}.{} | Expected a single row, got {:?}",
+                    dbgid, callee, rows
+                ),
+            }
+        }
+    }
+    // the levers depend on the tolerances of the waterline search
+    let stale_cache = CrossCurvesCache::new(
+        &dbgid,
+        model_tree.clone(),
+        model_tree.hash(),
+        &cache_dir,
+        CrossCurvesCacheConf {
+            equilibrium_conf: EquilibriumConf {
+                volume_tolerance: 1e-6,
+                ..Default::default()
+            },
+            ..conf
+        },
+    );
+    let result = stale_cache.check();
+    assert!(
+        matches!(&result, Err(CacheError::Mismatch { key, .. }) if key == "equilibrium_conf"),
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    if let Err(err) = fs::remove_dir_all(&cache_dir) {
+        log::warn!(
+            "{}.{} | Failed removing dir='{}': {}",
+            dbgid,
+            callee,
+            cache_dir.display(),
+            err
+        );
+    }
+    test_duration.exit();
+}
@@ -1,7 +1,10 @@
 use crate::models::ship_model::{
-    local_cache::floating_position_cache::{
-        floating_position_cache_conf::FloatingPositionCacheConf, CalculatedFloatingPositionCache,
-        FloatingPositionCache,
+    local_cache::{
+        floating_position_cache::{
+            floating_position_cache_conf::FloatingPositionCacheConf,
+            CalculatedFloatingPositionCache, FloatingPositionCache,
+        },
+        progress::Progress,
     },
     model_tree::ModelTree,
 };
@@ -31,6 +34,29 @@ fn init_once() {
 #[allow(clippy::unused_unit)]
 fn init_each() -> () {}
 ///
+/// Asserts values of `result` line are equal to `target` ones within the precision of the target file.
+fn assert_line(target: &str, result: &str, line_id: usize) {
+    let parse = |line: &str| {
+        Vec::from_iter(line.split_whitespace().map(|val| {
+            val.parse::<f64>().unwrap_or_else(|err| {
+                panic!("line={} | Failed parsing '{}': {}", line_id, val, err)
+            })
+        }))
+    };
+    let (target_vals, result_vals) = (parse(target), parse(result));
+    assert!(
+        target_vals.len() == result_vals.len()
+            && target_vals
+                .iter()
+                .zip(&result_vals)
+                .all(|(target, result)| (target - result).abs() < 1e-8),
+        "line={} target='{}' result='{}'",
+        line_id,
+        target,
+        result
+    );
+}
+///
 /// Test calculating dataset for Floating postion cache.
 ///
 /// # Notes
@@ -70,21 +96,40 @@ fn calculated_floating_position_cache() {
         heel_steps: (-10..=10).step_by(5).map(|n| n as f64).collect(),
         trim_steps: (-10..=10).step_by(5).map(|n| n as f64).collect(),
         draught_steps: vec![0.0, 0.25],
+        ..Default::default()
     };
     let heel_steps = conf.heel_steps.clone();
     let trim_steps = conf.trim_steps.clone();
     let draught_steps = conf.draught_steps.clone();
+    let elements = model_tree.iter().map(|(_, shape)| shape).cloned().collect();
+    let floating_position_cache = FloatingPositionCache::new(
+        &dbgid,
+        model_tree.clone(),
+        model_tree.hash(),
+        result_path,
+        conf,
+    );
+    let header = floating_position_cache
+        .file
+        .create_header()
+        .unwrap_or_else(|err| panic!("Failed creating *header*: {}", err));
+    let points = heel_steps.len() * trim_steps.len() * draught_steps.len();
+    let progress = Arc::new(Progress::new());
     let handlers = CalculatedFloatingPositionCache::new(
         &dbgid,
         result_path.into(),
-        model_tree.iter().map(|(_, shape)| shape).cloned().collect(),
-        FloatingPositionCache::new(&dbgid, model_tree, result_path, conf)
+        elements,
+        floating_position_cache
             .create_waterline()
             .unwrap_or_else(|err| panic!("Failed creating *waterline*: {}", err)),
+        header.clone(),
         heel_steps,
         trim_steps,
         draught_steps,
+        3,
+        false,
         Arc::default(),
+        progress.clone(),
     )
     .build()
     .unwrap_or_else(|err| panic!("Failed creating *handlers*: {}", err));
@@ -104,6 +149,9 @@ fn calculated_floating_position_cache() {
         }
     }
     assert!(errors.is_empty(), "*errors*: {:?}", errors);
+    let report = progress.report();
+    assert!(report.is_finished(), "*progress*: {}", report);
+    assert_eq!(report.total, points, "*progress*: {}", report);
     // read target file
     let mut target_reader = {
         let target_file = File::open(target_path)
@@ -116,6 +164,21 @@ fn calculated_floating_position_cache() {
             .unwrap_or_else(|err| panic!("Failed opening result file='{}': {}", result_path, err));
         BufReader::new(result_file)
     };
+    // check header lines
+    for (target, line_id) in header.to_string().lines().zip(1..) {
+        let mut result = String::new();
+        result_reader
+            .read_line(&mut result)
+            .unwrap_or_else(|err| panic!("line={} | Failed getting result line: {}", line_id, err));
+        assert_eq!(
+            target,
+            result.trim_end(),
+            "line={} target='{}' result='{}'",
+            line_id,
+            target,
+            result
+        );
+    }
     // check files line-by-line
     for ((try_target_line, try_result_line), line_id) in target_reader
         .by_ref()
@@ -127,11 +190,7 @@ fn calculated_floating_position_cache() {
             .unwrap_or_else(|err| panic!("line={} | Failed getting target line: {}", line_id, err));
         let result = try_result_line
             .unwrap_or_else(|err| panic!("line={} | Failed getting result line: {}", line_id, err));
-        assert_eq!(
-            target, result,
-            "line={} target='{}' result='{}'",
-            line_id, target, result
-        );
+        assert_line(&target, &result, line_id);
     }
     // check remaining lines in both files
     let remaining_target_lines = target_reader.lines().count();
@@ -154,3 +213,128 @@ fn calculated_floating_position_cache() {
     }
     test_duration.exit();
 }
+///
+/// Test resuming the interrupted calculation of Floating postion cache.
+///
+/// # Notes
+/// During the test files called `fpc_resumed` and `fpc_resumed.partial` are created in ./tmpdir/.
+/// At the end of the test it tries (safely) remove them.
+#[test]
+fn resume() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test cache Calculated_floating_position_cache resume".to_string());
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(300));
+    test_duration.run().unwrap();
+    let model_key = "/cube_1_1_1_centered";
+    let model_path =
+        "src/tests/models/ship_model/local_cache/floating_position_cache/assets/cube_1_1_1.step";
+    let target_path =
+        "src/tests/models/ship_model/local_cache/floating_position_cache/assets/fpc_target";
+    let result_path =
+        "src/tests/models/ship_model/local_cache/floating_position_cache/tmpdir/fpc_resumed";
+    let partial_path = format!("{}.partial", result_path);
+    let model_tree = ModelTree::<()>::new(&dbgid, model_path)
+        .load()
+        .unwrap_or_else(|err| panic!("Failing building *model_tree*: {}", err));
+    let waterline_position = model_tree
+        .get(model_key)
+        .and_then(|shape| match shape {
+            Shape::Solid(model) => Some(model.center().point()),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("Expected Solid by model_key='{}'", model_key));
+    let conf = FloatingPositionCacheConf {
+        waterline_position,
+        heel_steps: (-10..=10).step_by(5).map(|n| n as f64).collect(),
+        trim_steps: (-10..=10).step_by(5).map(|n| n as f64).collect(),
+        draught_steps: vec![0.0, 0.25],
+        ..Default::default()
+    };
+    let heel_steps = conf.heel_steps.clone();
+    let trim_steps = conf.trim_steps.clone();
+    let draught_steps = conf.draught_steps.clone();
+    let elements = model_tree.iter().map(|(_, shape)| shape).cloned().collect();
+    let floating_position_cache = FloatingPositionCache::new(
+        &dbgid,
+        model_tree.clone(),
+        model_tree.hash(),
+        result_path,
+        conf,
+    );
+    let header = floating_position_cache
+        .file
+        .create_header()
+        .unwrap_or_else(|err| panic!("Failed creating *header*: {}", err));
+    let target = fs::read_to_string(target_path)
+        .unwrap_or_else(|err| panic!("Failed reading target file='{}': {}", target_path, err));
+    let target = Vec::from_iter(target.lines());
+    // imitate the interrupted calculation:
+    // every second row is done, and the last line is cut off
+    let restored = Vec::from_iter(target.iter().step_by(2));
+    let cut_off = &target[1][..target[1].len() / 2];
+    fs::write(
+        &partial_path,
+        format!(
+            "{}{}\n{}",
+            header,
+            Vec::from_iter(restored.iter().map(|line| line.to_string())).join("\n"),
+            cut_off
+        ),
+    )
+    .unwrap_or_else(|err| panic!("Failed writing partial file='{}': {}", partial_path, err));
+    let progress = Arc::new(Progress::new());
+    let handlers = CalculatedFloatingPositionCache::new(
+        &dbgid,
+        result_path.into(),
+        elements,
+        floating_position_cache
+            .create_waterline()
+            .unwrap_or_else(|err| panic!("Failed creating *waterline*: {}", err)),
+        header.clone(),
+        heel_steps,
+        trim_steps,
+        draught_steps,
+        2,
+        true,
+        Arc::default(),
+        progress.clone(),
+    )
+    .build()
+    .unwrap_or_else(|err| panic!("Failed creating *handlers*: {}", err));
+    assert_eq!(progress.report().total, target.len());
+    for (id, handler) in handlers {
+        match handler.join() {
+            Err(why) => panic!("Failed preparing thread='{}': {:?}", id, why),
+            Ok(res) => {
+                res.unwrap_or_else(|why| panic!("Failed executing thread='{}': {:?}", id, why))
+            }
+        }
+    }
+    let report = progress.report();
+    assert!(report.is_finished(), "*progress*: {}", report);
+    // the result has all rows in order of the grid
+    let result = fs::read_to_string(result_path)
+        .unwrap_or_else(|err| panic!("Failed reading result file='{}': {}", result_path, err));
+    let result = Vec::from_iter(result.lines().filter(|line| !line.starts_with('#')));
+    assert_eq!(result.len(), target.len());
+    for ((target, result), line_id) in target.iter().zip(result).zip(1..) {
+        assert_line(target, result, line_id);
+    }
+    assert!(
+        fs::metadata(&partial_path).is_err(),
+        "Expected partial file='{}' removed",
+        partial_path
+    );
+    // clean up
+    if let Err(why) = fs::remove_file(result_path) {
+        log::warn!(
+            "Clean up (optional) | Failed removing result file='{}': {}",
+            result_path,
+            why
+        );
+    }
+    test_duration.exit();
+}
@@ -0,0 +1,183 @@
+use crate::{
+    common::cache::error::CacheError,
+    models::ship_model::{
+        local_cache::{
+            floating_position_cache::{
+                floating_position_cache_conf::FloatingPositionCacheConf, FloatingPositionCache,
+            },
+            LocalCache,
+        },
+        model_tree::ModelTree,
+    },
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{fs, sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test detecting stale dataset of [FloatingPositionCache].
+#[test]
+fn check() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test FloatingPositionCache".to_string());
+    let callee = "check";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let model_path =
+        "src/tests/models/ship_model/local_cache/floating_position_cache/assets/cube_1_1_1.step";
+    let cache_dir = std::env::temp_dir().join(format!("mdmt-fpc-check-{}", std::process::id()));
+    fs::create_dir_all(&cache_dir).unwrap();
+    let model_tree = ModelTree::<()>::new(&dbgid, model_path);
+    let model_hash = model_tree.hash();
+    let conf = || FloatingPositionCacheConf {
+        heel_steps: vec![-5.0, 0.0, 5.0],
+        trim_steps: vec![0.0, 1.0],
+        draught_steps: vec![0.0, 0.5],
+        ..Default::default()
+    };
+    let mut floating_position_cache = FloatingPositionCache::new(
+        &dbgid,
+        model_tree.clone(),
+        model_hash.clone(),
+        &cache_dir,
+        conf(),
+    );
+    // no dataset yet
+    let result = floating_position_cache.check();
+    assert!(
+        matches!(result, Err(CacheError::IoError { .. })),
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    // store the dataset calculated for the current configuration
+    let mut content = floating_position_cache
+        .file
+        .create_header()
+        .unwrap()
+        .to_string();
+    for draught in [0.0, 0.5] {
+        for heel in [-5.0, 0.0, 5.0] {
+            for trim in [0.0, 1.0] {
+                content += &format!(
+                    "{} {} {} {} 5 5 {}\n",
+                    heel,
+                    trim,
+                    draught,
+                    draught * 2.0,
+                    2.5 - draught / 2.0
+                );
+            }
+        }
+    }
+    fs::write(floating_position_cache.file.path(), content).unwrap();
+    floating_position_cache.reload();
+    let result = floating_position_cache.check();
+    assert_eq!(result, Ok(()), "{}.{}", dbgid, callee);
+    let result = floating_position_cache.get(&[Some(0.0), Some(0.5), Some(0.25)]);
+    assert_eq!(
+        result,
+        Ok(vec![vec![0.0, 0.5, 0.25, 0.5, 5.0, 5.0, 2.375]]),
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    // the configuration has changed since the dataset is stored
+    let stale_cache = FloatingPositionCache::new(
+        &dbgid,
+        model_tree.clone(),
+        model_hash.clone(),
+        &cache_dir,
+        FloatingPositionCacheConf {
+            heel_steps: vec![-10.0, 0.0, 10.0],
+            ..conf()
+        },
+    );
+    let result = stale_cache.check();
+    assert!(
+        matches!(&result, Err(CacheError::Mismatch { key, .. }) if key == "heel_steps"),
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    // the hull is made of other elements
+    let result = FloatingPositionCache::new(
+        &dbgid,
+        model_tree,
+        model_hash,
+        &cache_dir,
+        FloatingPositionCacheConf {
+            model_keys: vec!["/hull".to_owned()],
+            ..conf()
+        },
+    )
+    .check();
+    assert!(
+        matches!(&result, Err(CacheError::Mismatch { key, .. }) if key == "model_keys"),
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    let result = stale_cache.get(&[Some(0.0), Some(0.5), Some(0.25)]);
+    assert!(
+        result.is_err(),
+        "{}.{} | result={:?}",
+        dbgid,
+        callee,
+        result
+    );
+    // the model is unreadable, so the stored dataset can't be taken as up to date
+    let missing_tree = ModelTree::<()>::new(&dbgid, cache_dir.join("missing.step"));
+    let unknown_cache = FloatingPositionCache::new(
+        &dbgid,
+        missing_tree.clone(),
+        missing_tree.hash(),
+        &cache_dir,
+        conf(),
+    );
+    for result in [
+        unknown_cache.check(),
+        unknown_cache
+            .get(&[Some(0.0), Some(0.5), Some(0.25)])
+            .map(|_| ()),
+    ] {
+        assert!(
+            matches!(result, Err(CacheError::IoError { .. })),
+            "{}.{} | result={:?}",
+            dbgid,
+            callee,
+            result
+        );
+    }
+    if let Err(err) = fs::remove_dir_all(&cache_dir) {
+        log::warn!(
+            "{}.{} | Failed removing dir='{}': {}",
+            dbgid,
+            callee,
+            cache_dir.display(),
+            err
+        );
+    }
+    test_duration.exit();
+}
@@ -0,0 +1,154 @@
+use crate::models::ship_model::{
+    local_cache::{
+        hydrostatics_cache::{hydrostatics_cache_conf::HydrostaticsCacheConf, HydrostaticsCache},
+        progress::Progress,
+        LocalCache,
+    },
+    model_tree::ModelTree,
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_3dlib::{props::Center, topology::shape::Shape};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{
+    fs,
+    sync::{Arc, Once},
+    time::Duration,
+};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test calculating hydrostatics of 10×10×10 cube, which are known analytically.
+#[test]
+fn calculate() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test HydrostaticsCache".to_string());
+    let callee = "calculate";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(300));
+    test_duration.run().unwrap();
+    let model_key = "/cube_1_1_1_centered";
+    let model_path =
+        "src/tests/models/ship_model/local_cache/floating_position_cache/assets/cube_1_1_1.step";
+    let cache_dir = std::env::temp_dir().join(format!("mdmt-hc-calculate-{}", std::process::id()));
+    fs::create_dir_all(&cache_dir).unwrap();
+    let model_tree = ModelTree::<()>::new(&dbgid, model_path)
+        .load()
+        .unwrap_or_else(|err| panic!("Failing building *model_tree*: {}", err));
+    // set waterline init position to the cube center
+    let center @ [x, y, z] = model_tree
+        .get(model_key)
+        .and_then(|shape| match shape {
+            Shape::Solid(model) => Some(model.center().point()),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("Expected Solid by model_key='{}'", model_key));
+    let conf = HydrostaticsCacheConf {
+        waterline_position: center,
+        heel_steps: vec![0.0, 10.0],
+        trim_steps: vec![0.0, 5.0],
+        draught_steps: vec![0.0, 0.25],
+        water_density: 1.0,
+        workers: 2,
+        ..Default::default()
+    };
+    let mut hydrostatics_cache = HydrostaticsCache::new(
+        &dbgid,
+        model_tree.clone(),
+        model_tree.hash(),
+        &cache_dir,
+        conf,
+    );
+    let progress = Arc::new(Progress::new());
+    let handlers = hydrostatics_cache
+        .calculate(Arc::default(), progress.clone())
+        .unwrap_or_else(|err| panic!("Failed creating *handlers*: {}", err));
+    for (id, handler) in handlers {
+        match handler.join() {
+            Err(why) => panic!("Failed preparing thread='{}': {:?}", id, why),
+            Ok(res) => {
+                res.unwrap_or_else(|why| panic!("Failed executing thread='{}': {:?}", id, why))
+            }
+        }
+    }
+    assert!(progress.report().is_finished(), "{}.{}", dbgid, callee);
+    hydrostatics_cache.reload();
+    assert_eq!(hydrostatics_cache.check(), Ok(()), "{}.{}", dbgid, callee);
+    let cos = 10.0_f64.to_radians().cos();
+    // (heel, trim, draught), then expected (column id, value)
+    let test_data = [
+        (
+            [0.0, 0.0, 0.25],
+            vec![
+                (3, 475.0),
+                (4, 475.0),
+                (5, x),
+                (6, y),
+                (7, z - 2.5 - 0.125),
+                (8, 100.0),
+                (9, x),
+                (10, y),
+                (11, 1e4 / 12.0),
+                (12, 1e4 / 12.0),
+                (13, 1e4 / 12.0 / 475.0),
+                (14, 1e4 / 12.0 / 475.0),
+            ],
+        ),
+        (
+            [10.0, 0.0, 0.0],
+            vec![
+                (3, 500.0),
+                (5, x),
+                (8, 100.0 / cos),
+                (9, x),
+                (10, y),
+                (11, 10.0 * (10.0 / cos).powi(3) / 12.0),
+                (12, 10.0 / cos * 1e3 / 12.0),
+            ],
+        ),
+    ];
+    for (step, ([heel, trim, draught], target)) in test_data.into_iter().enumerate() {
+        let rows = hydrostatics_cache
+            .get(&[Some(heel), Some(trim), Some(draught)])
+            .unwrap_or_else(|err| panic!("{}.{} | step={}: {}", dbgid, callee, step, err));
+        assert_eq!(rows.len(), 1, "{}.{} | step={}", dbgid, callee, step);
+        for (column, value) in target {
+            let result = rows[0][column];
+            assert!(
+                (result - value).abs() <= 1e-3 * value.abs().max(1.0),
+                "{}.{} | step={} column={} result={} target={}",
+                dbgid,
+                callee,
+                step,
+                HydrostaticsCache::<()>::COLUMNS[column],
+                result,
+                value
+            );
+        }
+    }
+    if let Err(err) = fs::remove_dir_all(&cache_dir) {
+        log::warn!(
+            "{}.{} | Failed removing dir='{}': {}",
+            dbgid,
+            callee,
+            cache_dir.display(),
+            err
+        );
+    }
+    test_duration.exit();
+}
@@ -0,0 +1,91 @@
+use crate::models::ship_model::local_cache::progress::{Progress, ProgressReport};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::{dbg_id::DbgId, error::str_err::StrErr};
+use std::{sync::Once, thread, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test reporting [Progress] of the job.
+#[test]
+fn report() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test Progress".to_string());
+    let callee = "report";
+    log::debug!("\n{}.{}", dbgid, callee);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    let progress = Progress::new();
+    // not started
+    assert_eq!(progress.report(), ProgressReport::default());
+    assert!(!progress.report().is_finished());
+    // started, nothing calculated
+    progress.start(4, 0);
+    progress.current(&[-10.0, 0.0, 0.5]);
+    let report = progress.report();
+    assert_eq!((report.done, report.total), (0, 4));
+    assert_eq!(report.current, vec![-10.0, 0.0, 0.5]);
+    assert_eq!(report.eta, None);
+    // half calculated
+    thread::sleep(Duration::from_millis(20));
+    progress.step();
+    progress.current(&[0.0, 0.0, 0.5]);
+    progress.step();
+    let report = progress.report();
+    assert_eq!((report.done, report.total), (2, 4));
+    assert_eq!(report.current, vec![0.0, 0.0, 0.5]);
+    assert!(report.elapsed >= Duration::from_millis(20));
+    // the rest half is supposed to take the same time
+    let eta = report
+        .eta
+        .unwrap_or_else(|| panic!("{}.{} | Expected ETA", dbgid, callee));
+    assert!(
+        eta.abs_diff(report.elapsed) < Duration::from_millis(1),
+        "eta={:?}, elapsed={:?}",
+        eta,
+        report.elapsed
+    );
+    assert!(!report.is_finished());
+    // failed
+    progress.fail(&StrErr("Failed point".to_owned()));
+    assert_eq!(
+        progress.report().last_error.as_deref(),
+        Some("Failed point")
+    );
+    // finished
+    progress.step();
+    progress.step();
+    let report = progress.report();
+    assert!(report.is_finished());
+    assert_eq!(report.eta, Some(Duration::ZERO));
+    // resumed, the restored points don't affect ETA
+    progress.start(4, 2);
+    let report = progress.report();
+    assert_eq!((report.done, report.total, report.last_error), (2, 4, None));
+    assert_eq!(report.eta, None);
+    progress.step();
+    let report = progress.report();
+    assert_eq!(report.done, 3);
+    assert!(report.eta.is_some());
+    // empty job is finished once started
+    progress.start(0, 0);
+    let report = progress.report();
+    assert_eq!((report.done, report.total), (0, 0));
+    assert!(report.is_finished());
+    test_duration.exit();
+}
@@ -0,0 +1,202 @@
+use crate::models::ship_model::{
+    local_cache::{
+        progress::Progress,
+        tank_cache::{tank_cache_conf::TankCacheConf, TankCache, TankFilling},
+        LocalCache,
+    },
+    model_tree::ModelTree,
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_3dlib::{props::Center, topology::shape::Shape};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{
+    fs,
+    sync::{Arc, Once},
+    time::Duration,
+};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test naming the cache file after the tank.
+#[test]
+fn file_name() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test TankCache".to_string());
+    let callee = "file_name";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    let test_data = [
+        ("fo_1p", "tank_cache.fo_1p.2cd6a873"),
+        ("/tanks/fo 1p", "tank_cache.tanks_fo_1p.24cd6bf2"),
+        ("/tanks/WB-2.s", "tank_cache.tanks_WB-2_s.d4270400"),
+        // the same sanitized keys are told apart by the hash
+        ("/tanks/fo_1p", "tank_cache.tanks_fo_1p.61fc5bbd"),
+        ("/tanks_fo/1p", "tank_cache.tanks_fo_1p.a5b356c7"),
+    ];
+    for (step, (tank, target)) in test_data.into_iter().enumerate() {
+        let result = TankCache::<()>::file_name(tank);
+        assert_eq!(result, target, "{}.{} | step={}", dbgid, callee, step);
+    }
+    test_duration.exit();
+}
+///
+/// Test calculating calibration of 10×10×10 cube used as the tank, which is known analytically.
+#[test]
+fn calculate() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test TankCache".to_string());
+    let callee = "calculate";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(300));
+    test_duration.run().unwrap();
+    let model_key = "/cube_1_1_1_centered";
+    let model_path =
+        "src/tests/models/ship_model/local_cache/floating_position_cache/assets/cube_1_1_1.step";
+    let cache_dir = std::env::temp_dir().join(format!("mdmt-tc-calculate-{}", std::process::id()));
+    fs::create_dir_all(&cache_dir).unwrap();
+    let model_tree = ModelTree::<()>::new(&dbgid, model_path)
+        .load()
+        .unwrap_or_else(|err| panic!("Failing building *model_tree*: {}", err));
+    // the sounding pipe goes up from the center of the cube bottom
+    let [x, y, z] = model_tree
+        .get(model_key)
+        .and_then(|shape| match shape {
+            Shape::Solid(model) => Some(model.center().point()),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("Expected Solid by model_key='{}'", model_key));
+    let bottom = z - 5.0;
+    let conf = TankCacheConf {
+        tank: model_key.to_owned(),
+        sounding_point: [x, y, bottom],
+        ullage_reference: 10.0,
+        heel_steps: vec![0.0, 10.0],
+        trim_steps: vec![0.0],
+        sounding_steps: vec![2.0, 3.0, 5.0],
+        workers: 2,
+        ..Default::default()
+    };
+    let mut tank_cache = TankCache::new(
+        &dbgid,
+        model_tree.clone(),
+        model_tree.hash(),
+        &cache_dir,
+        conf,
+    );
+    let progress = Arc::new(Progress::new());
+    let handlers = tank_cache
+        .calculate(Arc::default(), progress.clone())
+        .unwrap_or_else(|err| panic!("Failed creating *handlers*: {}", err));
+    for (id, handler) in handlers {
+        match handler.join() {
+            Err(why) => panic!("Failed preparing thread='{}': {:?}", id, why),
+            Ok(res) => {
+                res.unwrap_or_else(|why| panic!("Failed executing thread='{}': {:?}", id, why))
+            }
+        }
+    }
+    assert!(progress.report().is_finished(), "{}.{}", dbgid, callee);
+    tank_cache.reload();
+    assert_eq!(tank_cache.check(), Ok(()), "{}.{}", dbgid, callee);
+    let (sin, cos) = 10.0_f64.to_radians().sin_cos();
+    let tan = sin / cos;
+    // (heel, trim, sounding), then expected filling
+    let test_data = [
+        (
+            [0.0, 0.0, 3.0],
+            TankFilling {
+                sounding: 3.0,
+                ullage: 7.0,
+                volume: 300.0,
+                centroid: [x, y, bottom + 1.5],
+                free_surface_inertia: [1e4 / 12.0, 1e4 / 12.0],
+            },
+        ),
+        // the free surface passes through the cube center
+        (
+            [10.0, 0.0, 5.0],
+            TankFilling {
+                sounding: 5.0,
+                ullage: 5.0,
+                volume: 500.0,
+                centroid: [
+                    x,
+                    y + tan * 1e4 / 12.0 / 500.0,
+                    bottom + (tan * tan * 1e4 / 12.0 + 100.0 * 25.0) / 1000.0,
+                ],
+                free_surface_inertia: [10.0 * (10.0 / cos).powi(3) / 12.0, 10.0 / cos * 1e3 / 12.0],
+            },
+        ),
+    ];
+    for (step, ([heel, trim, sounding], target)) in test_data.into_iter().enumerate() {
+        let rows = tank_cache
+            .get(&[Some(heel), Some(trim), Some(sounding)])
+            .unwrap_or_else(|err| panic!("{}.{} | step={}: {}", dbgid, callee, step, err));
+        let result = TankFilling::from_row(&rows[0], 10.0)
+            .unwrap_or_else(|| panic!("{}.{} | step={}: No filling", dbgid, callee, step));
+        let close =
+            |result: f64, target: f64| (result - target).abs() <= 1e-3 * target.abs().max(1.0);
+        assert!(
+            close(result.sounding, target.sounding)
+                && close(result.ullage, target.ullage)
+                && close(result.volume, target.volume)
+                && close(result.centroid[0], target.centroid[0])
+                && close(result.centroid[1], target.centroid[1])
+                && close(result.centroid[2], target.centroid[2])
+                && close(
+                    result.free_surface_inertia[0],
+                    target.free_surface_inertia[0]
+                )
+                && close(
+                    result.free_surface_inertia[1],
+                    target.free_surface_inertia[1]
+                ),
+            "{}.{} | step={} result={:?} target={:?}",
+            dbgid,
+            callee,
+            step,
+            result,
+            target
+        );
+    }
+    // the inverse, the sounding of the volume
+    let rows = tank_cache
+        .solve(&[Some(0.0), Some(0.0), None, Some(250.0)], 2)
+        .unwrap_or_else(|err| panic!("{}.{} | {}", dbgid, callee, err));
+    assert!(
+        (rows[0][2] - 2.5).abs() < 1e-3,
+        "{}.{} | rows={:?}",
+        dbgid,
+        callee,
+        rows
+    );
+    if let Err(err) = fs::remove_dir_all(&cache_dir) {
+        log::warn!(
+            "{}.{} | Failed removing dir='{}': {}",
+            dbgid,
+            callee,
+            cache_dir.display(),
+            err
+        );
+    }
+    test_duration.exit();
+}
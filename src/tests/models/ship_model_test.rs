@@ -0,0 +1,97 @@
+use crate::models::{
+    loading_condition::{LoadingCondition, Weight},
+    ship_model::{
+        ship_model_conf::{ShipModelConf, StaleCachePolicy},
+        ShipModel,
+    },
+    stability::gz_curve::GzCurve,
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::{dbg_id::DbgId, error::str_err::StrErr};
+use std::{sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test the free trim GZ curve is refused for LCG off the reference point.
+///
+/// There is neither model nor cache, so the GZ curve isn't found in any case,
+/// but LCG is checked before the cache is used.
+#[test]
+fn gz_curve_free_trim() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test ShipModel".to_string());
+    let callee = "gz_curve_free_trim";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(10));
+    test_duration.run().unwrap();
+    let tmp_dir = std::env::temp_dir().join(format!("mdmt-ship-model-{}", std::process::id()));
+    let mut conf = ShipModelConf {
+        model_path: tmp_dir.join("missed.step"),
+        cache_dir: tmp_dir,
+        floating_position_cache_conf: Default::default(),
+        hydrostatics_cache_conf: Default::default(),
+        cross_curves_cache_conf: Default::default(),
+        tank_cache_confs: vec![],
+        equilibrium_conf: Default::default(),
+        damage_conf: Default::default(),
+        strength_conf: Default::default(),
+        stale_cache_policy: StaleCachePolicy::default(),
+    };
+    conf.cross_curves_cache_conf.reference_point = [50.0, 0.0, 0.0];
+    conf.equilibrium_conf.lever_tolerance = 0.01;
+    let ship_model = ShipModel::<()>::new(&dbgid, conf).unwrap();
+    let is_lcg_err = |result: Result<GzCurve, StrErr>| match result {
+        Ok(_) => panic!("{}.{} | Expected no cache found", dbgid, callee),
+        Err(err) => err.0.contains("Free trim"),
+    };
+    #[rustfmt::skip]
+    let test_data = [
+        // lcg, free_trim, is LCG error
+        (0.0, true, false),
+        (0.005, true, false),
+        (-0.005, true, false),
+        (0.02, true, true),
+        (-1.0, true, true),
+        (1.0, false, false),
+    ];
+    for (step, (lcg, free_trim, target)) in test_data.into_iter().enumerate() {
+        let result = is_lcg_err(ship_model.gz_curve(1000.0, lcg, 4.5, free_trim));
+        assert_eq!(
+            result, target,
+            "{}.{} | step={} lcg={} free_trim={}",
+            dbgid, callee, step, lcg, free_trim
+        );
+    }
+    // LCG of the loading condition is measured from the reference point
+    #[rustfmt::skip]
+    let test_data = [
+        (50.0, false),
+        (49.0, true),
+    ];
+    for (step, (lcg, target)) in test_data.into_iter().enumerate() {
+        let loading_condition = LoadingCondition::new("Test", Weight::new(1000.0, [lcg, 0.0, 6.0]));
+        let result = is_lcg_err(ship_model.loading_gz_curve(&loading_condition, true));
+        assert_eq!(
+            result, target,
+            "{}.{} | step={} lcg={}",
+            dbgid, callee, step, lcg
+        );
+    }
+    test_duration.exit();
+}
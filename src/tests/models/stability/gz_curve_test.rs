@@ -0,0 +1,179 @@
+use crate::models::stability::gz_curve::GzCurve;
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test GZ curve built from the cross curves.
+#[test]
+fn from_cross_curves() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test GzCurve".to_string());
+    let callee = "from_cross_curves";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    // unsorted points are sorted by heel
+    let kn = [(30.0, 2.5), (0.0, 0.0), (10.0, 1.0), (f64::NAN, 1.0)];
+    let kg = 2.0;
+    let gz_curve = GzCurve::from_cross_curves(kn, kg);
+    let target = [
+        (0.0, 0.0),
+        (10.0, 1.0 - kg * 10.0_f64.to_radians().sin()),
+        (30.0, 2.5 - kg * 0.5),
+    ];
+    assert_eq!(
+        gz_curve.points().len(),
+        target.len(),
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    for (step, (&(heel, gz), (target_heel, target_gz))) in
+        gz_curve.points().iter().zip(target).enumerate()
+    {
+        assert!(
+            heel == target_heel && (gz - target_gz).abs() < 1e-12,
+            "{}.{} | step={} result={:?} target={:?}",
+            dbgid,
+            callee,
+            step,
+            (heel, gz),
+            (target_heel, target_gz)
+        );
+    }
+    // interpolation
+    let test_data = [
+        (0.0, Some(0.0)),
+        (5.0, Some(target[1].1 / 2.0)),
+        (30.0, Some(target[2].1)),
+        (-1.0, None),
+        (31.0, None),
+    ];
+    for (step, (heel, target)) in test_data.into_iter().enumerate() {
+        let result = gz_curve.gz(heel);
+        assert!(
+            match (result, target) {
+                (Some(result), Some(target)) => (result - target).abs() < 1e-12,
+                (result, target) => result == target,
+            },
+            "{}.{} | step={} result={:?} target={:?}",
+            dbgid,
+            callee,
+            step,
+            result,
+            target
+        );
+    }
+    // the free surface correction acts as the rise of the center of gravity
+    let result = gz_curve.with_free_surface(0.5);
+    let target = GzCurve::from_cross_curves(kn, kg + 0.5);
+    for (step, (&(heel, gz), &(target_heel, target_gz))) in
+        result.points().iter().zip(target.points()).enumerate()
+    {
+        assert!(
+            heel == target_heel && (gz - target_gz).abs() < 1e-12,
+            "{}.{} | step={} result={:?} target={:?}",
+            dbgid,
+            callee,
+            step,
+            (heel, gz),
+            (target_heel, target_gz)
+        );
+    }
+    test_duration.exit();
+}
+///
+/// Test areas, maximum, and crossings of GZ curve.
+#[test]
+fn area() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test GzCurve".to_string());
+    let callee = "area";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    // GZ grows linearly up to 40 deg, then falls down to zero at 80 deg
+    let gz_curve = GzCurve::new([(0.0, 0.0), (40.0, 0.4), (80.0, 0.0)]);
+    let test_data = [
+        ((0.0, 30.0), Some(0.01 * 30.0 * 30.0 / 2.0)),
+        ((30.0, 0.0), Some(-0.01 * 30.0 * 30.0 / 2.0)),
+        (
+            (30.0, 50.0),
+            Some((0.3 + 0.4) / 2.0 * 10.0 + (0.4 + 0.3) / 2.0 * 10.0),
+        ),
+        ((0.0, 80.0), Some(0.4 * 80.0 / 2.0)),
+        ((0.0, 90.0), None),
+    ];
+    for (step, ((from, to), target)) in test_data.into_iter().enumerate() {
+        // the target is given in m*deg
+        let target = target.map(|target: f64| target.to_radians());
+        let result = gz_curve.area(from, to);
+        assert!(
+            match (result, target) {
+                (Some(result), Some(target)) => (result - target).abs() < 1e-12,
+                (result, target) => result == target,
+            },
+            "{}.{} | step={} result={:?} target={:?}",
+            dbgid,
+            callee,
+            step,
+            result,
+            target
+        );
+    }
+    assert_eq!(
+        gz_curve.max(0.0, 80.0),
+        Some((40.0, 0.4)),
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    assert_eq!(
+        gz_curve.max(60.0, 80.0),
+        Some((60.0, 0.2)),
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    assert_eq!(
+        gz_curve.crossings(0.2),
+        vec![20.0, 60.0],
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    assert_eq!(
+        gz_curve.symmetric().points(),
+        &[
+            (-80.0, -0.0),
+            (-40.0, -0.4),
+            (0.0, 0.0),
+            (40.0, 0.4),
+            (80.0, 0.0)
+        ],
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    test_duration.exit();
+}
@@ -0,0 +1,187 @@
+use crate::models::stability::{
+    gz_curve::GzCurve,
+    intact_stability::{weather_criterion::WeatherCriterion, IntactStability},
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Returns GZ curve of a typical cargo ship.
+fn gz_curve() -> GzCurve {
+    GzCurve::new(
+        [0.0, 0.17, 0.35, 0.52, 0.62, 0.58, 0.45, 0.27, 0.05, -0.15]
+            .into_iter()
+            .enumerate()
+            .map(|(id, gz)| (id as f64 * 10.0, gz)),
+    )
+}
+///
+/// Returns weather data giving the roll angle by the table values of the factors.
+fn weather() -> WeatherCriterion {
+    WeatherCriterion {
+        displacement: 10000.0,
+        windage_area: 1000.0,
+        windage_lever: 8.0,
+        breadth: 12.0,
+        draught: 5.0,
+        waterline_length: 100.0,
+        block_coefficient: 0.7,
+        kg: 5.0,
+        ..Default::default()
+    }
+}
+///
+/// Test the general criteria.
+#[test]
+fn general() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test IntactStability".to_string());
+    let callee = "general";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let report = IntactStability::new(gz_curve(), 1.0)
+        .with_flooding_angle(35.0)
+        .report();
+    log::debug!("{}.{} | report:\n{}", dbgid, callee, report);
+    assert_eq!(report.criteria.len(), 6, "{}.{}", dbgid, callee);
+    assert!(
+        report.passed(),
+        "{}.{} | report:\n{}",
+        dbgid,
+        callee,
+        report
+    );
+    // areas in m*deg by trapezoids
+    let area_30: f64 = (0.17 + 0.35 + 0.52 / 2.0) * 10.0;
+    let area_35 = area_30 + (0.52 + 0.57) / 2.0 * 5.0;
+    let targets = [
+        Some(area_30.to_radians()),
+        Some(area_35.to_radians()),
+        Some((area_35 - area_30).to_radians()),
+        Some(0.62),
+        Some(40.0),
+        Some(1.0),
+    ];
+    for (step, (criterion, target)) in report.criteria.iter().zip(targets).enumerate() {
+        assert!(
+            match (criterion.attained, target) {
+                (Some(result), Some(target)) => (result - target).abs() < 1e-12,
+                (result, target) => result == target,
+            },
+            "{}.{} | step={} result={} target={:?}",
+            dbgid,
+            callee,
+            step,
+            criterion,
+            target
+        );
+    }
+    // low metacentric height and short curve
+    let report = IntactStability::new(GzCurve::new([(0.0, 0.0), (30.0, 0.3)]), 0.1).report();
+    let failed = Vec::from_iter(report.failed().iter().map(|criterion| criterion.name));
+    assert_eq!(
+        failed,
+        vec![
+            "Area under GZ curve up to 40 deg or flooding angle",
+            "Area under GZ curve between 30 and 40 deg or flooding angle",
+            "Initial metacentric height",
+        ],
+        "{}.{} | report:\n{}",
+        dbgid,
+        callee,
+        report
+    );
+    test_duration.exit();
+}
+///
+/// Test the severe wind and rolling criterion.
+#[test]
+fn weather_criterion() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test IntactStability".to_string());
+    let callee = "weather_criterion";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let gm = 1.0;
+    // lw1 = P * A * Z / (1000 * g * displacement)
+    let wind_lever = 504.0 * 1000.0 * 8.0 / (1000.0 * 9.81 * 10000.0);
+    assert!(
+        (weather().wind_lever() - wind_lever).abs() < 1e-12,
+        "{}.{} | wind_lever={}",
+        dbgid,
+        callee,
+        weather().wind_lever()
+    );
+    // B/d = 2.4 => X1 = 1.0, CB = 0.7 => X2 = 1.0, no bilge keels => k = 1.0,
+    // KG = d => r = 0.73, T = 2 * C * B / sqrt(GM), where C = 0.373 + 0.023 * 2.4 - 0.043
+    let period: f64 = 2.0 * (0.373 + 0.023 * 2.4 - 0.043) * 12.0;
+    let s = 0.093 + (0.065 - 0.093) * (period - 8.0) / 4.0;
+    let roll_angle = 109.0 * (0.73 * s).sqrt();
+    assert!(
+        (weather().roll_period(gm) - period).abs() < 1e-12
+            && (weather().roll_angle(gm) - roll_angle).abs() < 1e-9,
+        "{}.{} | roll_period={} roll_angle={}",
+        dbgid,
+        callee,
+        weather().roll_period(gm),
+        weather().roll_angle(gm)
+    );
+    let report = IntactStability::new(gz_curve(), gm)
+        .with_weather(weather())
+        .report();
+    log::debug!("{}.{} | report:\n{}", dbgid, callee, report);
+    assert_eq!(report.criteria.len(), 8, "{}.{}", dbgid, callee);
+    assert!(
+        report.passed(),
+        "{}.{} | report:\n{}",
+        dbgid,
+        callee,
+        report
+    );
+    let wind_heel = &report.criteria[6];
+    assert!(
+        wind_heel.required == 16.0
+            && (wind_heel.attained.unwrap() - 10.0 * wind_lever / 0.17).abs() < 1e-12,
+        "{}.{} | result={}",
+        dbgid,
+        callee,
+        wind_heel
+    );
+    // too much windage
+    let report = IntactStability::new(gz_curve(), gm)
+        .with_weather(WeatherCriterion {
+            windage_area: 20000.0,
+            ..weather()
+        })
+        .report();
+    assert!(
+        !report.criteria[7].passed(),
+        "{}.{} | report:\n{}",
+        dbgid,
+        callee,
+        report
+    );
+    test_duration.exit();
+}
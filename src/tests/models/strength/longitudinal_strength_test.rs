@@ -0,0 +1,209 @@
+use crate::models::{
+    loading_condition::{DeadweightItem, LoadingCondition, Weight},
+    strength::longitudinal_strength::{
+        longitudinal_strength_conf::LongitudinalStrengthConf, LongitudinalStrength, StationLimits,
+    },
+};
+use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+use sal_sync::services::entity::dbg_id::DbgId;
+use std::{sync::Once, time::Duration};
+use testing::stuff::max_test_duration::TestDuration;
+//
+//
+static INIT: Once = Once::new();
+///
+/// Once called initialisation.
+fn init_once() {
+    //
+    // Implement your initialisation code to be called only once for current test file.
+    INIT.call_once(|| {})
+}
+///
+/// Returns:
+///  - ...
+#[allow(clippy::unused_unit)]
+fn init_each() -> () {}
+///
+/// Test the shear force and the bending moment of 100 m barge
+/// with the uniform lightship and the cargo amidships, which are known analytically.
+#[test]
+fn report() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test LongitudinalStrength".to_string());
+    let callee = "report";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let limits = StationLimits {
+        shear_force: [-500.0, 500.0],
+        bending_moment: [-4000.0, 4000.0],
+    };
+    let strength = LongitudinalStrength::new(LongitudinalStrengthConf {
+        stations: vec![0.0, 25.0, 50.0, 75.0, 100.0],
+        // the shape only, it's scaled to the lightship mass
+        lightship: vec![(0.0, 50.0, 150.0), (50.0, 100.0, 150.0)],
+        limits: vec![limits; 5],
+    });
+    // the uniform load is split at the stations
+    let result = strength.distribute(10.0, 60.0, 100.0);
+    let target = [(17.5, 30.0), (37.5, 50.0), (55.0, 20.0)];
+    assert_eq!(result, target, "{}.{}", dbgid, callee);
+    assert_eq!(
+        strength.distribute(30.0, 30.0, 100.0),
+        [(30.0, 100.0)],
+        "{}.{}",
+        dbgid,
+        callee
+    );
+    let loading_condition =
+        LoadingCondition::new("Cargo amidships", Weight::new(600.0, [50.0, 0.0, 5.0])).with_item(
+            DeadweightItem::new("Cargo", Weight::new(400.0, [50.0, 0.0, 3.0])),
+        );
+    let weights = strength.weights(&loading_condition);
+    let mass: f64 = weights.iter().map(|(_, mass)| mass).sum();
+    assert!(
+        (mass - 1000.0).abs() < 1e-9 && weights.len() == 5,
+        "{}.{} | weights={:?}",
+        dbgid,
+        callee,
+        weights
+    );
+    // the buoyancy is uniform, 10 t/m
+    let buoyancy = strength.distribute(0.0, 100.0, 1000.0);
+    let report = strength.report(&weights, &buoyancy);
+    // the net load is -4 t/m and 400 t at 50 m
+    let target = [
+        (0.0, 0.0, 0.0),
+        (25.0, -100.0, -1250.0),
+        (50.0, -200.0, -5000.0),
+        (75.0, 100.0, -1250.0),
+        (100.0, 0.0, 0.0),
+    ];
+    assert_eq!(report.stations.len(), target.len(), "{}.{}", dbgid, callee);
+    for (step, (station, (x, shear_force, bending_moment))) in
+        report.stations.iter().zip(target).enumerate()
+    {
+        assert!(
+            station.x == x
+                && (station.shear_force - shear_force).abs() < 1e-9
+                && (station.bending_moment - bending_moment).abs() < 1e-9,
+            "{}.{} | step={} result={:?} target={:?}",
+            dbgid,
+            callee,
+            step,
+            station,
+            (x, shear_force, bending_moment)
+        );
+    }
+    // sagging amidships exceeds the limit
+    let exceedances = report.exceedances();
+    assert!(
+        !report.passed()
+            && exceedances.len() == 1
+            && exceedances[0].x == 50.0
+            && exceedances[0].bending_moment_exceeded()
+            && !exceedances[0].shear_force_exceeded(),
+        "{}.{} | report:\n{}",
+        dbgid,
+        callee,
+        report
+    );
+    assert!(
+        report.to_string().contains("BM EXCEEDED"),
+        "{}.{} | report:\n{}",
+        dbgid,
+        callee,
+        report
+    );
+    test_duration.exit();
+}
+///
+/// Test the weight distribution keeps the mass and LCG of the lightship and each item.
+#[test]
+fn weights() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    let dbgid = DbgId("test LongitudinalStrength".to_string());
+    let callee = "weights";
+    log::debug!("\n{}", dbgid);
+    let test_duration = TestDuration::new(&dbgid, Duration::from_secs(1));
+    test_duration.run().unwrap();
+    let strength = LongitudinalStrength::new(LongitudinalStrengthConf {
+        stations: vec![0.0, 25.0, 50.0, 75.0, 100.0],
+        // the centroid is at 50 m
+        lightship: vec![(0.0, 50.0, 150.0), (50.0, 100.0, 150.0)],
+        limits: vec![],
+    });
+    let centroid = |weights: &[(f64, f64)]| {
+        let mass: f64 = weights.iter().map(|(_, mass)| mass).sum();
+        let moment: f64 = weights.iter().map(|(x, mass)| x * mass).sum();
+        (mass, moment / mass)
+    };
+    // the lightship is shifted by 2 m to its LCG
+    let lightship = LoadingCondition::new("Lightship", Weight::new(600.0, [52.0, 0.0, 5.0]));
+    let result = strength.weights(&lightship);
+    let target = [
+        (13.5, 138.0),
+        (37.5, 150.0),
+        (51.0, 12.0),
+        (63.5, 138.0),
+        (87.5, 150.0),
+        (101.0, 12.0),
+    ];
+    assert!(
+        result.len() == target.len()
+            && result.iter().zip(target).all(|(result, target)| {
+                (result.0 - target.0).abs() < 1e-9 && (result.1 - target.1).abs() < 1e-9
+            }),
+        "{}.{} | result={:?} target={:?}",
+        dbgid,
+        callee,
+        result,
+        target
+    );
+    // the items are spread around their LCG within the extent
+    for (step, (extent, lcg, target)) in [
+        (None, 45.0, vec![(45.0, 100.0)]),
+        // split by the station
+        (Some([40.0, 70.0]), 50.0, vec![(45.0, 50.0), (55.0, 50.0)]),
+        // within the only station interval
+        (Some([40.0, 70.0]), 60.0, vec![(60.0, 100.0)]),
+        // out of the extent
+        (Some([40.0, 70.0]), 80.0, vec![(80.0, 100.0)]),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let item = DeadweightItem::new("Cargo", Weight::new(100.0, [lcg, 0.0, 3.0]));
+        let item = match extent {
+            Some([aft, fore]) => item.with_extent(aft, fore),
+            None => item,
+        };
+        let loading_condition = lightship.clone().with_item(item);
+        let result = strength.weights(&loading_condition);
+        assert_eq!(
+            result[result.len() - target.len()..],
+            target,
+            "{}.{} | step={}",
+            dbgid,
+            callee,
+            step
+        );
+        let (mass, x) = centroid(&result);
+        let target_x = (600.0 * 52.0 + 100.0 * lcg) / 700.0;
+        assert!(
+            (mass - 700.0).abs() < 1e-9 && (x - target_x).abs() < 1e-9,
+            "{}.{} | step={} mass={} x={} target_x={}",
+            dbgid,
+            callee,
+            step,
+            mass,
+            x,
+            target_x
+        );
+    }
+    test_duration.exit();
+}